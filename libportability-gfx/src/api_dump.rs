@@ -0,0 +1,597 @@
+//! The built-in `VK_LAYER_GFX_api_dump` layer, printing every command
+//! with its parameters.
+//!
+//! Once an instance enables it, `vkGetInstanceProcAddr` and `vkGetDeviceProcAddr`
+//! return the entry points, and `forward!` dumps the calls made with the objects
+//! of this instance: the call and its inputs before it reaches the backend,
+//! so that a crashing call is in the dump, then the result and the outputs.
+//! The parameters given by pointer are followed to the number of elements
+//! given by their count parameter, or to their first element, along with
+//! the `pNext` chain of the structures. The dump goes to the standard output,
+//! or to the `api_dump_file` of the configuration.
+
+use crate::{
+    backend::Backend,
+    config,
+    empty::*,
+    handle::{DispatchHandle, Handle},
+};
+
+use fxhash::FxHashMap;
+
+use lazy_static::lazy_static;
+use log::{error, warn};
+
+use std::{
+    any::TypeId,
+    ffi::CStr,
+    fmt::{self, Write as _},
+    fs::File,
+    io::{self, Write},
+    os::raw::{c_char, c_void},
+    slice,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+pub const LAYER_NAME: &[u8] = b"VK_LAYER_GFX_api_dump\0";
+const LAYER_DESCRIPTION: &str = "Prints the commands with their parameters";
+
+/// Number of instances that enabled the layer, including the ones being created.
+static ENABLED: AtomicUsize = AtomicUsize::new(0);
+
+lazy_static! {
+    /// Instances that enabled the layer and their physical devices,
+    /// mapped to the raw value of the instance. The dispatchable objects
+    /// created from them carry the flag instead.
+    static ref OBJECTS: Mutex<FxHashMap<u64, u64>> = Mutex::new(Default::default());
+    static ref OUTPUT: Mutex<Box<dyn Write + Send>> = Mutex::new(open_output());
+}
+
+fn open_output() -> Box<dyn Write + Send> {
+    match &config::current().api_dump_file {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(file),
+            Err(e) => {
+                error!("Unable to create the API dump {:?}: {}", path, e);
+                Box::new(io::stdout())
+            }
+        },
+        None => Box::new(io::stdout()),
+    }
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed) != 0
+}
+
+/// Enables the dump for an instance about to be created,
+/// to be followed by `add_instance` or `disable`.
+pub fn enable() {
+    ENABLED.fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn disable() {
+    ENABLED.fetch_sub(1, Ordering::Relaxed);
+}
+
+/// Dumps the calls made with an instance, or with one of the backend
+/// instances of the group `instance` stands for.
+pub fn add_instance(raw: u64, instance: u64) {
+    OBJECTS.lock().unwrap().insert(raw, instance);
+}
+
+/// Dumps the calls made with a physical device if its instance enabled the layer.
+pub fn add_adapter(adapter: u64, instance: u64) {
+    let mut objects = OBJECTS.lock().unwrap();
+    if let Some(&instance) = objects.get(&instance) {
+        objects.insert(adapter, instance);
+    }
+}
+
+/// Forgets an instance along with its physical devices,
+/// returning true if it had enabled the layer.
+pub fn remove_instance(instance: u64) -> bool {
+    let mut objects = OBJECTS.lock().unwrap();
+    let dumped = objects.contains_key(&instance);
+    objects.retain(|_, &mut owner| owner != instance);
+    dumped
+}
+
+/// Returns true if the calls made with an instance or a physical device are dumped.
+pub fn is_dumped(raw: u64) -> bool {
+    is_enabled() && OBJECTS.lock().unwrap().contains_key(&raw)
+}
+
+/// The first parameter of a command, telling if the call is dumped.
+pub trait Dumped {
+    fn is_dumped(&self) -> bool;
+}
+
+impl<T> Dumped for Handle<T> {
+    fn is_dumped(&self) -> bool {
+        is_dumped(self.as_raw())
+    }
+}
+
+impl<T> Dumped for DispatchHandle<T> {
+    fn is_dumped(&self) -> bool {
+        DispatchHandle::is_dumped(self)
+    }
+}
+
+// The global commands don't belong to an instance,
+// they are dumped while any instance enables the layer.
+impl<T> Dumped for *const T {
+    fn is_dumped(&self) -> bool {
+        true
+    }
+}
+
+impl<T> Dumped for *mut T {
+    fn is_dumped(&self) -> bool {
+        true
+    }
+}
+
+/// Checks the layers enabled by the application, returning true if the dump is one of them.
+pub unsafe fn check_layers(count: u32, names: *const *const c_char) -> Result<bool, VkResult> {
+    if count == 0 {
+        return Ok(false);
+    }
+    let mut dump = false;
+    for &name in slice::from_raw_parts(names, count as usize) {
+        let name = CStr::from_ptr(name);
+        if name.to_bytes_with_nul() == LAYER_NAME {
+            dump = true;
+        } else {
+            warn!("Layer {:?} is not present", name);
+            return Err(VkResult::VK_ERROR_LAYER_NOT_PRESENT);
+        }
+    }
+    Ok(dump)
+}
+
+/// Implements `vkEnumerateInstanceLayerProperties` and `vkEnumerateDeviceLayerProperties`.
+pub unsafe fn enumerate_layers(
+    pPropertyCount: *mut u32,
+    pProperties: *mut VkLayerProperties,
+) -> VkResult {
+    if pProperties.is_null() {
+        *pPropertyCount = 1;
+        return VkResult::VK_SUCCESS;
+    }
+    if *pPropertyCount == 0 {
+        return VkResult::VK_INCOMPLETE;
+    }
+
+    let mut properties = VkLayerProperties {
+        layerName: [0; 256],
+        specVersion: VK_API_VERSION_1_1 | VK_HEADER_VERSION,
+        implementationVersion: 1,
+        description: [0; 256],
+    };
+    for (dst, &src) in properties.layerName.iter_mut().zip(LAYER_NAME) {
+        *dst = src as c_char;
+    }
+    for (dst, &src) in properties
+        .description
+        .iter_mut()
+        .zip(LAYER_DESCRIPTION.as_bytes())
+    {
+        *dst = src as c_char;
+    }
+    *pProperties = properties;
+    *pPropertyCount = 1;
+    VkResult::VK_SUCCESS
+}
+
+/// Implements the extension enumeration of a layer, the dump having none.
+pub unsafe fn enumerate_layer_extensions(
+    pLayerName: *const c_char,
+    pPropertyCount: *mut u32,
+) -> VkResult {
+    if CStr::from_ptr(pLayerName).to_bytes_with_nul() == LAYER_NAME {
+        *pPropertyCount = 0;
+        VkResult::VK_SUCCESS
+    } else {
+        VkResult::VK_ERROR_LAYER_NOT_PRESENT
+    }
+}
+
+/// A value that can be written to the dump.
+pub trait Dump {
+    fn dump(&self, out: &mut String);
+
+    /// Dumps an array of `count` elements, only the pointers having more than one.
+    fn dump_array(&self, _count: usize, out: &mut String) {
+        self.dump(out);
+    }
+
+    /// Returns the value as the length of an array.
+    fn count(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns true for the pointers the command writes to.
+    fn is_output(&self) -> bool {
+        false
+    }
+}
+
+macro_rules! dump_debug {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Dump for $ty {
+                fn dump(&self, out: &mut String) {
+                    let _ = write!(out, "{:?}", self);
+                }
+            }
+        )*
+    };
+}
+
+dump_debug! {
+    i32,
+    u64,
+    usize,
+    f32,
+    PFN_vkVoidFunction,
+    VkResult,
+    VkDebugReportObjectTypeEXT,
+    VkDebugUtilsMessageSeverityFlagBitsEXT,
+    VkFilter,
+    VkFormat,
+    VkImageLayout,
+    VkImageTiling,
+    VkImageType,
+    VkIndexType,
+    VkPipelineBindPoint,
+    VkPipelineStageFlagBits,
+    VkSampleCountFlagBits,
+    VkSubpassContents,
+    VkSurfaceCounterFlagBitsEXT,
+}
+
+impl Dump for u32 {
+    fn dump(&self, out: &mut String) {
+        let _ = write!(out, "{:?}", self);
+    }
+
+    fn count(&self) -> Option<usize> {
+        Some(*self as usize)
+    }
+}
+
+impl Dump for () {
+    fn dump(&self, _out: &mut String) {}
+}
+
+impl<T: fmt::Debug + 'static> Dump for *const T {
+    fn dump(&self, out: &mut String) {
+        unsafe { dump_pointer(*self, 1, out) }
+    }
+
+    fn dump_array(&self, count: usize, out: &mut String) {
+        unsafe { dump_pointer(*self, count, out) }
+    }
+
+    fn count(&self) -> Option<usize> {
+        unsafe { read_count(*self) }
+    }
+}
+
+impl<T: fmt::Debug + 'static> Dump for *mut T {
+    fn dump(&self, out: &mut String) {
+        unsafe { dump_pointer(*self as *const T, 1, out) }
+    }
+
+    fn dump_array(&self, count: usize, out: &mut String) {
+        unsafe { dump_pointer(*self as *const T, count, out) }
+    }
+
+    fn count(&self) -> Option<usize> {
+        unsafe { read_count(*self as *const T) }
+    }
+
+    fn is_output(&self) -> bool {
+        true
+    }
+}
+
+/// Reads a length given by pointer, like `pPropertyCount`.
+unsafe fn read_count<T: 'static>(ptr: *const T) -> Option<usize> {
+    if ptr.is_null() || TypeId::of::<T>() != TypeId::of::<u32>() {
+        None
+    } else {
+        Some(*(ptr as *const u32) as usize)
+    }
+}
+
+/// Dumps the address and the first `count` elements of an array, the strings being whole.
+unsafe fn dump_pointer<T: fmt::Debug + 'static>(ptr: *const T, count: usize, out: &mut String) {
+    if ptr.is_null() {
+        out.push_str("null");
+        return;
+    }
+    let _ = write!(out, "{:p}", ptr);
+    let ty = TypeId::of::<T>();
+    if count == 0 || ty == TypeId::of::<c_void>() {
+        return;
+    }
+    if ty == TypeId::of::<c_char>() {
+        let _ = write!(out, " -> {:?}", CStr::from_ptr(ptr as *const c_char));
+        return;
+    }
+    out.push_str(" -> ");
+    if count == 1 {
+        dump_element(ptr, out);
+        return;
+    }
+    out.push('[');
+    for i in 0..count {
+        let mut text = String::new();
+        dump_element(ptr.add(i), &mut text);
+        let _ = write!(out, "\n    {},", text.replace('\n', "\n    "));
+    }
+    out.push_str("\n]");
+}
+
+unsafe fn dump_element<T: fmt::Debug + 'static>(ptr: *const T, out: &mut String) {
+    let _ = write!(out, "{:#?}", &*ptr);
+    if STRUCTURES.contains(&TypeId::of::<T>()) {
+        dump_chain((*(ptr as *const VkBaseStruct)).pNext, out);
+    }
+}
+
+/// Length parameters that don't follow the naming of the arrays.
+const COUNTS: &[(&str, &str)] = &[
+    ("pPipelines", "createInfoCount"),
+    ("pBuffers", "bindingCount"),
+    ("pOffsets", "bindingCount"),
+    (
+        "pPhysicalDeviceGroupProperties",
+        "pPhysicalDeviceGroupCount",
+    ),
+];
+
+/// Returns true if `count` is the name of the length of the array `name`,
+/// like `fenceCount` for `pFences` or `pPropertyCount` for `pProperties`.
+fn is_count_of(count: &str, name: &str) -> bool {
+    if COUNTS.contains(&(name, count)) {
+        return true;
+    }
+    let plural = match name.strip_prefix('p') {
+        Some(plural) => plural,
+        None => return false,
+    };
+    let count = match count.strip_suffix("Count") {
+        Some(count) => count.strip_prefix('p').unwrap_or(count),
+        None => return false,
+    };
+    let singular = match plural.strip_suffix("ies") {
+        Some(stem) => format!("{}y", stem),
+        None => match plural.strip_suffix('s') {
+            Some(stem) => stem.to_string(),
+            None => return false,
+        },
+    };
+    let mut chars = count.chars();
+    match chars.next() {
+        Some(first) => {
+            first.to_ascii_uppercase() == singular.chars().next().unwrap_or_default()
+                && chars.as_str() == &singular[1..]
+        }
+        None => false,
+    }
+}
+
+/// Dumps the structures chained to another one.
+unsafe fn dump_chain(mut next: *const c_void, out: &mut String) {
+    while !next.is_null() {
+        let base = &*(next as *const VkBaseStruct);
+        let _ = write!(out, "\npNext: {:p} -> ", next);
+        if !dump_structure(base.sType, next, out) {
+            let _ = write!(out, "{:?}", base.sType);
+        }
+        next = base.pNext;
+    }
+}
+
+/// Defines the structures that can be chained, with their `sType`.
+macro_rules! structures {
+    ($($ty:ident => $sType:ident,)*) => {
+        lazy_static! {
+            static ref STRUCTURES: Vec<TypeId> = vec![$(TypeId::of::<$ty>()),*];
+        }
+
+        /// Dumps a structure of a `pNext` chain, returning false if its type is unknown.
+        unsafe fn dump_structure(
+            sType: VkStructureType,
+            ptr: *const c_void,
+            out: &mut String,
+        ) -> bool {
+            let _ = match sType {
+                $(VkStructureType::$sType => write!(out, "{:#?}", &*(ptr as *const $ty)),)*
+                _ => return false,
+            };
+            true
+        }
+    };
+}
+
+chained_structures!(structures);
+
+/// A call being dumped, in two records: the call with its inputs
+/// is written by `write`, the result and the outputs by `finish`.
+pub struct Call {
+    prefix: String,
+    text: String,
+    /// Lengths given by the parameters seen so far.
+    counts: Vec<(&'static str, usize)>,
+}
+
+impl Call {
+    pub fn new(backend: Backend, name: &str) -> Self {
+        Call {
+            prefix: format!("{:?} {}: {}", thread::current().id(), backend.name(), name),
+            text: String::new(),
+            counts: Vec::new(),
+        }
+    }
+
+    /// Adds an input, the outputs being only given their address.
+    pub fn arg(&mut self, name: &'static str, value: &dyn Dump) {
+        let count = if value.is_output() {
+            Some(0)
+        } else {
+            self.count_of(name)
+        };
+        self.add(name, value, count);
+    }
+
+    /// Writes the call with its inputs, before it's made.
+    pub fn write(&mut self) {
+        let text = format!("{}({}\n)\n", self.prefix, self.text);
+        write_record(&text);
+        self.text.clear();
+        self.counts.clear();
+    }
+
+    /// Adds an output once the call is made, the parameters being given in order.
+    pub fn output(&mut self, name: &'static str, value: &dyn Dump) {
+        if value.is_output() {
+            let count = self.count_of(name);
+            self.add(name, value, count);
+        } else if let Some(count) = value.count() {
+            self.counts.push((name, count));
+        }
+    }
+
+    /// Writes the value returned by the call with its outputs.
+    pub fn finish(self, result: &dyn Dump) {
+        let mut text = String::new();
+        result.dump(&mut text);
+        if text.is_empty() && self.text.is_empty() {
+            return;
+        }
+        let mut record = self.prefix;
+        if !text.is_empty() {
+            let _ = write!(record, " -> {}", text);
+        }
+        record.push_str(&self.text);
+        record.push('\n');
+        write_record(&record);
+    }
+
+    fn count_of(&self, name: &str) -> Option<usize> {
+        self.counts
+            .iter()
+            .rev()
+            .find(|&&(count, _)| is_count_of(count, name))
+            .map(|&(_, count)| count)
+    }
+
+    fn add(&mut self, name: &'static str, value: &dyn Dump, count: Option<usize>) {
+        let mut text = String::new();
+        match count {
+            Some(count) => value.dump_array(count, &mut text),
+            None => value.dump(&mut text),
+        }
+        let _ = write!(
+            self.text,
+            "\n    {}: {}",
+            name,
+            text.replace('\n', "\n    ")
+        );
+        if let Some(count) = value.count() {
+            self.counts.push((name, count));
+        }
+    }
+}
+
+/// Writes a record at once, so that the records of the threads don't mix.
+fn write_record(text: &str) {
+    let mut output = OUTPUT.lock().unwrap();
+    let _ = output.write_all(text.as_bytes());
+    let _ = output.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ptr;
+
+    #[test]
+    fn counts() {
+        assert!(is_count_of("fenceCount", "pFences"));
+        assert!(is_count_of("pPropertyCount", "pProperties"));
+        assert!(is_count_of("bindingCount", "pBuffers"));
+        assert!(is_count_of(
+            "pPhysicalDeviceGroupCount",
+            "pPhysicalDeviceGroupProperties"
+        ));
+        assert!(!is_count_of("fenceCount", "pSemaphores"));
+        assert!(!is_count_of("flags", "pFences"));
+        assert!(!is_count_of("fenceCount", "fences"));
+    }
+
+    #[test]
+    fn inputs() {
+        let fences = [1u64, 2];
+        let name = b"main\0";
+        let mut call = Call::new(Backend::Empty, "vkTest");
+        call.arg("fenceCount", &2u32);
+        call.arg("pFences", &fences.as_ptr());
+        call.arg("pName", &(name.as_ptr() as *const c_char));
+        call.arg("pAllocator", &(ptr::null::<u32>()));
+        assert_eq!(
+            call.text,
+            format!(
+                "\n    fenceCount: 2\
+                 \n    pFences: {:p} -> [\n        1,\n        2,\n    ]\
+                 \n    pName: {:p} -> \"main\"\
+                 \n    pAllocator: null",
+                fences.as_ptr(),
+                name.as_ptr(),
+            )
+        );
+    }
+
+    #[test]
+    fn outputs() {
+        let mut count = 0u32;
+        let mut properties = [0u32; 2];
+        let mut call = Call::new(Backend::Empty, "vkTest");
+        call.arg("pPropertyCount", &(&mut count as *mut u32));
+        call.arg("pProperties", &properties.as_mut_ptr());
+        assert_eq!(
+            call.text,
+            format!(
+                "\n    pPropertyCount: {:p}\n    pProperties: {:p}",
+                &count,
+                properties.as_ptr(),
+            )
+        );
+
+        // The outputs go to the record written once the call is made.
+        let mut call = Call::new(Backend::Empty, "vkTest");
+        count = 2;
+        properties = [3, 4];
+        call.output("pPropertyCount", &(&mut count as *mut u32));
+        call.output("pProperties", &properties.as_mut_ptr());
+        assert_eq!(
+            call.text,
+            format!(
+                "\n    pPropertyCount: {:p} -> 2\
+                 \n    pProperties: {:p} -> [\n        3,\n        4,\n    ]",
+                &count,
+                properties.as_ptr(),
+            )
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! Selection of the backend serving an instance, and the owners
+//! of the dispatchable handles, used to route the calls made with them.
+//!
+//! Instances and physical devices are looked up by their raw values, while
+//! the devices, queues and command buffers record their backend in their allocation.
+//!
+//! The backend is picked at `vkCreateInstance` time by the `backend` setting
+//! (see `config`), which the `[[application]]` sections can set per application,
+//! and defaults to `Backend::preferred`. It accepts a comma-separated list,
+//! in which case the instance spans all the backends of it.
+
+use crate::{
+    config,
+    handle::{self, DispatchHandle, Handle},
+    VkResult,
+};
+
+use lazy_static::lazy_static;
+use log::error;
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    RwLock,
+};
+
+/// A backend the layer can be compiled for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Backend {
+    Empty,
+    Vulkan,
+    Gl,
+    Metal,
+    Dx12,
+    Dx11,
+}
+
+impl Backend {
+    /// Backends compiled into the library, in the order of preference.
+    pub const ENABLED: &'static [Backend] = &[
+        #[cfg(feature = "gfx-backend-dx12")]
+        Backend::Dx12,
+        #[cfg(feature = "gfx-backend-dx11")]
+        Backend::Dx11,
+        #[cfg(feature = "gfx-backend-metal")]
+        Backend::Metal,
+        #[cfg(feature = "gfx-backend-vulkan")]
+        Backend::Vulkan,
+        #[cfg(feature = "gfx-backend-gl")]
+        Backend::Gl,
+        Backend::Empty,
+    ];
+
+    /// All the backends, in the order of declaration.
+    const ALL: &'static [Backend] = &[
+        Backend::Empty,
+        Backend::Vulkan,
+        Backend::Gl,
+        Backend::Metal,
+        Backend::Dx12,
+        Backend::Dx11,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Backend::Empty => "Empty",
+            Backend::Vulkan => "Vulkan",
+            Backend::Gl => "GL",
+            Backend::Metal => "Metal",
+            Backend::Dx12 => "DX12",
+            Backend::Dx11 => "DX11",
+        }
+    }
+
+    /// Looks up a backend by its case-insensitive name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .cloned()
+            .find(|backend| backend.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The backend used when nothing else is requested.
+    pub fn preferred() -> Self {
+        Self::ENABLED[0]
+    }
+
+    fn is_enabled(self) -> bool {
+        Self::ENABLED.contains(&self)
+    }
+}
+
+fn parse(names: &str, source: &str) -> Result<Vec<Backend>, VkResult> {
+    let mut backends = Vec::new();
+    for name in names.split(',').map(str::trim) {
+        let backend = parse_one(name, source)?;
+        if !backends.contains(&backend) {
+            backends.push(backend);
+        }
+    }
+    Ok(backends)
+}
+
+fn parse_one(name: &str, source: &str) -> Result<Backend, VkResult> {
+    match Backend::from_name(name) {
+        Some(backend) if backend.is_enabled() => Ok(backend),
+        Some(backend) => {
+            error!(
+                "Backend {} requested by {} is not compiled in",
+                backend.name(),
+                source
+            );
+            Err(VkResult::VK_ERROR_INCOMPATIBLE_DRIVER)
+        }
+        None => {
+            error!("Unknown backend {:?} requested by {}", name, source);
+            Err(VkResult::VK_ERROR_INCOMPATIBLE_DRIVER)
+        }
+    }
+}
+
+/// Picks the backends for a new instance, the first one being the main one.
+pub fn select(settings: &config::Settings) -> Result<Vec<Backend>, VkResult> {
+    match settings.backend {
+        Some(ref names) => parse(names, "the configuration"),
+        None => Ok(vec![Backend::preferred()]),
+    }
+}
+
+lazy_static! {
+    /// Backends owning the live instances and physical devices, keyed by their raw values.
+    /// The dispatchable objects created from them carry their owner instead.
+    static ref OWNERS: RwLock<fxhash::FxHashMap<u64, Owner>> = RwLock::new(Default::default());
+}
+
+struct Owner {
+    backend: Backend,
+    /// Raw value of the instance the handle was obtained from.
+    instance: u64,
+}
+
+/// Index of the backend of the first instance plus one, zero before that.
+static FIRST: AtomicUsize = AtomicUsize::new(0);
+/// Set once instances of different backends were created, from then on
+/// the owners of the handles have to be looked up.
+static MIXED: AtomicBool = AtomicBool::new(false);
+
+/// Remembers the backend owning a new instance.
+pub fn register_instance(raw: u64, backend: Backend) {
+    let index = backend as usize + 1;
+    if let Err(first) = FIRST.compare_exchange(0, index, Ordering::AcqRel, Ordering::Acquire) {
+        if first != index {
+            MIXED.store(true, Ordering::Release);
+        }
+    }
+    register_adapter(raw, raw, backend);
+}
+
+/// Remembers the backend owning a physical device enumerated from `instance`.
+pub fn register_adapter(raw: u64, instance: u64, backend: Backend) {
+    OWNERS
+        .write()
+        .unwrap()
+        .insert(raw, Owner { backend, instance });
+}
+
+/// Forgets a destroyed instance, along with its physical devices.
+pub fn unregister_instance(raw: u64) {
+    OWNERS
+        .write()
+        .unwrap()
+        .retain(|_, owner| owner.instance != raw);
+}
+
+fn default_owner() -> Backend {
+    match FIRST.load(Ordering::Acquire) {
+        0 => Backend::preferred(),
+        index => Backend::ALL[index - 1],
+    }
+}
+
+/// A handle the calls can be routed with.
+pub trait Owned {
+    /// Returns the backend owning the handle.
+    fn owner(&self) -> Backend;
+}
+
+impl<T> Owned for Handle<T> {
+    fn owner(&self) -> Backend {
+        if !MIXED.load(Ordering::Acquire) {
+            return default_owner();
+        }
+        let raw = self.as_raw();
+        match OWNERS.read().unwrap().get(&raw) {
+            Some(owner) => owner.backend,
+            None => {
+                if raw != 0 {
+                    error!("Handle {:#x} doesn't belong to any backend", raw);
+                }
+                Backend::preferred()
+            }
+        }
+    }
+}
+
+impl<T> Owned for DispatchHandle<T> {
+    fn owner(&self) -> Backend {
+        // A destroyed object is not read, the call being skipped anyway.
+        if !handle::is_alive(self.as_raw()) {
+            return default_owner();
+        }
+        self.backend().unwrap_or_else(default_owner)
+    }
+}
@@ -0,0 +1,375 @@
+//! Capture of the commands called by the application, to be played back
+//! by `portability-replay`.
+//!
+//! Enabled by the `capture_file` of the configuration. The entry points
+//! go through `captured!`, which writes every call to the trace before
+//! running it, and its return after. The trace is only locked while
+//! a record is written, the records of a call and of its return being
+//! paired by the identifier of the call. The data written by the application
+//! to the mapped memory is written before the commands that can read it,
+//! fully the first time and then by the pages that changed.
+
+use crate::{
+    config,
+    empty::*,
+    trace::{self, Traced, Writer},
+};
+
+use fxhash::FxHashMap;
+use lazy_static::lazy_static;
+use log::error;
+
+use std::{
+    cell::Cell,
+    fs::File,
+    io::Write,
+    mem,
+    os::raw::c_void,
+    slice,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+/// Granularity of the updates of the mapped memory.
+const PAGE_SIZE: usize = 4096;
+
+/// Commands reading the mapped memory, before which it's written to the trace.
+const MEMORY_READERS: &[&str] = &[
+    "gfxQueueSubmit",
+    "gfxQueueBindSparse",
+    "gfxFlushMappedMemoryRanges",
+    "gfxUnmapMemory",
+];
+
+/// Commands that can block until another thread calls a command,
+/// which are captured without their return: it may come long after
+/// the call, if ever, and the replay would have to read the trace
+/// up to it.
+const WAITS: &[&str] = &[
+    "gfxWaitForFences",
+    "gfxQueueWaitIdle",
+    "gfxDeviceWaitIdle",
+    "gfxAcquireNextImageKHR",
+    "gfxGetQueryPoolResults",
+];
+
+/// Set by the replay, which doesn't capture itself.
+static SUPPRESSED: AtomicBool = AtomicBool::new(false);
+/// Identifier of the next captured call.
+static NEXT_CALL: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Set while a command is captured, for the ones it calls in turn.
+    static ACTIVE: Cell<bool> = Cell::new(false);
+}
+
+lazy_static! {
+    static ref STATE: Option<Mutex<State>> = open();
+}
+
+struct Mapping {
+    ptr: *const u8,
+    /// Offset of the mapping in the memory object.
+    offset: u64,
+    size: usize,
+    /// Contents written to the trace last.
+    shadow: Option<Vec<u8>>,
+}
+
+struct State {
+    file: File,
+    /// Sizes of the memory objects, for the mappings of their whole size.
+    allocations: FxHashMap<u64, u64>,
+    mappings: FxHashMap<u64, Mapping>,
+    /// Entries of the descriptor update templates, giving the size of their data.
+    templates: FxHashMap<u64, Vec<VkDescriptorUpdateTemplateEntryKHR>>,
+}
+
+// The mapped memory is only read while the state is locked.
+unsafe impl Send for State {}
+
+fn open() -> Option<Mutex<State>> {
+    let path = config::current().capture_file.clone()?;
+    let mut file = match File::create(&path) {
+        Ok(file) => file,
+        Err(e) => {
+            error!("Unable to create the capture {:?}: {}", path, e);
+            return None;
+        }
+    };
+    let mut header = Writer::default();
+    header.bytes(trace::MAGIC);
+    header.u32(trace::VERSION);
+    header.u8(mem::size_of::<usize>() as u8);
+    if let Err(e) = file.write_all(&header.take()) {
+        error!("Unable to write the capture {:?}: {}", path, e);
+        return None;
+    }
+    Some(Mutex::new(State {
+        file,
+        allocations: FxHashMap::default(),
+        mappings: FxHashMap::default(),
+        templates: FxHashMap::default(),
+    }))
+}
+
+/// Disables the capture in this process, which replays a trace.
+pub fn suppress() {
+    SUPPRESSED.store(true, Ordering::Relaxed);
+}
+
+fn write_record(file: &mut File, kind: u8, payload: &[u8]) {
+    let mut record = Vec::with_capacity(5 + payload.len());
+    record.push(kind);
+    record.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    record.extend_from_slice(payload);
+    // Written at once, so that the trace of a crashing application is usable.
+    if let Err(e) = file.write_all(&record) {
+        error!("Unable to write the capture: {}", e);
+    }
+}
+
+fn memory_record(memory: u64, offset: u64, data: &[u8]) -> Vec<u8> {
+    let mut writer = Writer::default();
+    writer.u64(memory);
+    writer.u64(offset);
+    writer.u32(data.len() as u32);
+    writer.bytes(data);
+    writer.take()
+}
+
+impl State {
+    /// Writes the contents of the mapped memory that changed.
+    unsafe fn write_mappings(&mut self) {
+        let file = &mut self.file;
+        for (&memory, mapping) in &mut self.mappings {
+            let data = slice::from_raw_parts(mapping.ptr, mapping.size);
+            match mapping.shadow {
+                Some(ref mut shadow) => {
+                    let pages = data.chunks(PAGE_SIZE).zip(shadow.chunks_mut(PAGE_SIZE));
+                    for (i, (page, old)) in pages.enumerate() {
+                        if page != &old[..] {
+                            let offset = mapping.offset + (i * PAGE_SIZE) as u64;
+                            write_record(
+                                file,
+                                trace::RECORD_MEMORY,
+                                &memory_record(memory, offset, page),
+                            );
+                            old.copy_from_slice(page);
+                        }
+                    }
+                }
+                None => {
+                    write_record(
+                        file,
+                        trace::RECORD_MEMORY,
+                        &memory_record(memory, mapping.offset, data),
+                    );
+                    mapping.shadow = Some(data.to_vec());
+                }
+            }
+        }
+    }
+}
+
+/// A command being captured.
+pub struct Call {
+    name: &'static str,
+    id: u64,
+    state: &'static Mutex<State>,
+    writer: Writer,
+    /// Raw values of the parameters, by name.
+    values: Vec<(&'static str, u64)>,
+    /// Length of the arrays given by the parameters that follow.
+    count: Option<usize>,
+    wait: bool,
+}
+
+impl Call {
+    /// Starts capturing a command, unless the capture is disabled
+    /// or the command is called by another one.
+    pub fn begin(name: &'static str) -> Option<Self> {
+        if SUPPRESSED.load(Ordering::Relaxed) || ACTIVE.with(Cell::get) {
+            return None;
+        }
+        let state = STATE.as_ref()?;
+        ACTIVE.with(|active| active.set(true));
+
+        let id = NEXT_CALL.fetch_add(1, Ordering::Relaxed);
+        let wait = WAITS.contains(&name);
+        let mut writer = Writer::default();
+        writer.u64(id);
+        writer.string(name);
+        writer.u8(!wait as u8);
+        Some(Call {
+            name,
+            id,
+            state,
+            writer,
+            values: Vec::new(),
+            count: None,
+            wait,
+        })
+    }
+
+    fn value(&self, name: &str) -> u64 {
+        self.values
+            .iter()
+            .find(|&&(value_name, _)| value_name == name)
+            .map_or(0, |&(_, value)| value)
+    }
+
+    /// Length of the arrays that are not given by the preceding parameter.
+    unsafe fn special_length(&self, name: &str) -> Option<usize> {
+        match (self.name, name) {
+            ("gfxCmdSetBlendConstants", "blendConstants") => Some(4),
+            ("gfxAllocateCommandBuffers", "pCommandBuffers") => {
+                let info = self.value("pAllocateInfo") as *const VkCommandBufferAllocateInfo;
+                info.as_ref().map(|info| info.commandBufferCount as usize)
+            }
+            ("gfxAllocateDescriptorSets", "pDescriptorSets") => {
+                let info = self.value("pAllocateInfo") as *const VkDescriptorSetAllocateInfo;
+                info.as_ref().map(|info| info.descriptorSetCount as usize)
+            }
+            ("gfxUpdateDescriptorSetWithTemplate", "pData") => {
+                let template = self.value("descriptorUpdateTemplate");
+                let state = self.state.lock().unwrap();
+                state
+                    .templates
+                    .get(&template)
+                    .map(|entries| trace::template_data_size(entries))
+            }
+            _ => None,
+        }
+    }
+
+    unsafe fn length(&self, name: &str) -> Option<usize> {
+        match self.special_length(name) {
+            Some(len) => Some(len),
+            // Pointers to single pointers, like the one to the mapped memory.
+            None if name.starts_with("pp") => None,
+            None => self.count,
+        }
+    }
+
+    /// Keeps the length given by a parameter for the arrays that follow.
+    unsafe fn update_count<T: Traced>(&mut self, name: &str, value: &T) {
+        if name.ends_with("Count") || name.ends_with("Size") || name == "size" {
+            self.count = value.count();
+        }
+    }
+
+    /// Writes a parameter given to the command.
+    pub unsafe fn arg<T: Traced>(&mut self, name: &'static str, value: &T) {
+        let len = self.length(name);
+        value.write_array(&mut self.writer, len);
+        self.values.push((name, value.raw()));
+        self.update_count(name, value);
+    }
+
+    /// Writes the call, before running it.
+    pub fn write(&mut self) {
+        let payload = self.writer.take();
+        let mut state = self.state.lock().unwrap();
+        if MEMORY_READERS.contains(&self.name) {
+            unsafe { state.write_mappings() };
+        }
+        write_record(&mut state.file, trace::RECORD_CALL, &payload);
+        self.count = None;
+    }
+
+    /// Writes a parameter once the command returned.
+    pub unsafe fn output<T: Traced>(&mut self, name: &'static str, value: &T) {
+        if self.wait {
+            return;
+        }
+        let len = self.length(name);
+        value.write_output(&mut self.writer, len);
+        self.update_count(name, value);
+    }
+
+    /// Writes the return of the command.
+    pub unsafe fn finish<T: Traced>(mut self, result: &T) {
+        if self.wait {
+            return;
+        }
+        // The result goes before the parameters, for the replay to read
+        // them only if the call succeeded.
+        let mut payload = Writer::default();
+        payload.u64(self.id);
+        result.write(&mut payload);
+        payload.bytes(&self.writer.take());
+        let mut state = self.state.lock().unwrap();
+        write_record(&mut state.file, trace::RECORD_RETURN, &payload.take());
+        if result.succeeded() {
+            self.track(&mut state);
+        }
+    }
+
+    /// Keeps track of the memory and the templates created by the command.
+    unsafe fn track(&self, state: &mut State) {
+        let name = self.name;
+        let memory = self.value("memory");
+        match name {
+            "gfxAllocateMemory" => {
+                let info = &*(self.value("pAllocateInfo") as *const VkMemoryAllocateInfo);
+                let memory = (*(self.value("pMemory") as *const VkDeviceMemory)).as_raw();
+                state.allocations.insert(memory, info.allocationSize);
+            }
+            "gfxFreeMemory" => {
+                state.allocations.remove(&memory);
+                state.mappings.remove(&memory);
+            }
+            "gfxMapMemory" => {
+                let offset = self.value("offset");
+                let size = self.value("size");
+                let ptr = *(self.value("ppData") as *const *mut c_void) as *const u8;
+                let size = if size == VK_WHOLE_SIZE as VkDeviceSize {
+                    state
+                        .allocations
+                        .get(&memory)
+                        .map_or(0, |&total| total - offset)
+                } else {
+                    size
+                };
+                state.mappings.insert(
+                    memory,
+                    Mapping {
+                        ptr,
+                        offset,
+                        size: size as usize,
+                        shadow: None,
+                    },
+                );
+            }
+            "gfxUnmapMemory" => {
+                state.mappings.remove(&memory);
+            }
+            "gfxCreateDescriptorUpdateTemplate" => {
+                let info =
+                    &*(self.value("pCreateInfo") as *const VkDescriptorUpdateTemplateCreateInfoKHR);
+                let entries = slice::from_raw_parts(
+                    info.pDescriptorUpdateEntries,
+                    info.descriptorUpdateEntryCount as usize,
+                )
+                .to_vec();
+                let template = *(self.value("pDescriptorUpdateTemplate")
+                    as *const VkDescriptorUpdateTemplateKHR);
+                state.templates.insert(template.as_raw(), entries);
+            }
+            "gfxDestroyDescriptorUpdateTemplate" => {
+                let template = self.value("descriptorUpdateTemplate");
+                state.templates.remove(&template);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Drop for Call {
+    fn drop(&mut self) {
+        ACTIVE.with(|active| active.set(false));
+    }
+}
@@ -0,0 +1,801 @@
+//! Run-time settings of the library.
+//!
+//! They are read from a TOML file, given by `GFX_CONFIG` or found next to
+//! the executable as `gfx-portability.toml`:
+//! ```toml
+//! backend = "vulkan"
+//! shader_dump = "/tmp/shaders"
+//!
+//! [frame_dump]
+//! directory = "/tmp/frames"
+//! format = "ppm"
+//! interval = 10
+//! first = 100
+//! last = 200
+//!
+//! [metal]
+//! arguments = true
+//! recording = "deferred"
+//!
+//! # Applied on top of the above when `VkApplicationInfo` matches.
+//! [[application]]
+//! name = "vkcube"
+//! engine = "vkcube"
+//! metal = { stitching = false }
+//! workarounds = { present_mode = "fifo", hidden_extensions = ["VK_KHR_maintenance1"] }
+//! ```
+//! The environment variables, like `GFX_BACKEND` or `GFX_METAL_STITCHING`,
+//! override the file. Invalid settings are reported and ignored.
+//!
+//! Known applications also get the built-in workarounds of `PROFILES`,
+//! unless `disable_workarounds` or `GFX_DISABLE_WORKAROUNDS` is set.
+
+use crate::VkApplicationInfo;
+
+use lazy_static::lazy_static;
+use log::warn;
+use toml::{value::Table, Value};
+
+use std::{
+    env,
+    ffi::CStr,
+    fmt, fs,
+    os::raw::c_char,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+/// Name of the file looked up next to the executable.
+const FILE_NAME: &str = "gfx-portability.toml";
+
+/// How the Metal command buffers are recorded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Recording {
+    Immediate,
+    Deferred,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MetalSettings {
+    /// Use argument buffers for the descriptor sets.
+    pub arguments: Option<bool>,
+    pub recording: Option<Recording>,
+    /// Stitch the deferred command buffers at submission.
+    pub stitching: Option<bool>,
+}
+
+/// File format of the dumped frames.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFormat {
+    Png,
+    Ppm,
+}
+
+/// Presented frames written to image files, see the `frame_dump` module.
+#[derive(Clone, Debug)]
+pub struct FrameDump {
+    /// Directory to write the frames to, `None` disabling the dumps.
+    pub directory: Option<PathBuf>,
+    pub format: ImageFormat,
+    /// Dump every Nth frame of the range.
+    pub interval: u64,
+    /// Range of the dumped frames, counted from 0 for each swapchain.
+    pub first: u64,
+    pub last: Option<u64>,
+}
+
+impl Default for FrameDump {
+    fn default() -> Self {
+        FrameDump {
+            directory: None,
+            format: ImageFormat::Png,
+            interval: 1,
+            first: 0,
+            last: None,
+        }
+    }
+}
+
+/// Presentation mode forced on the swapchains.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PresentMode {
+    Immediate,
+    Mailbox,
+    Fifo,
+    FifoRelaxed,
+}
+
+/// Deviations from the Vulkan behavior that some applications rely on.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Workarounds {
+    /// Ignore the occlusion queries, reporting a passed sample.
+    pub disable_occlusion_queries: bool,
+    /// Cap on the number of sets of the descriptor pools,
+    /// their descriptor counts being reduced in proportion.
+    pub descriptor_pool_max_sets: Option<u32>,
+    /// Device extensions not exposed to the application.
+    pub hidden_extensions: Vec<String>,
+    pub present_mode: Option<PresentMode>,
+}
+
+/// Built-in workarounds of an application, see `PROFILES`.
+struct Profile {
+    /// Application and engine names to match, `None` matching any.
+    application: Option<&'static str>,
+    engine: Option<&'static str>,
+    disable_occlusion_queries: bool,
+    descriptor_pool_max_sets: Option<u32>,
+    hidden_extensions: &'static [&'static str],
+    present_mode: Option<PresentMode>,
+}
+
+/// Workarounds applied unless `disable_workarounds` is set.
+const PROFILES: &[Profile] = &[
+    // Dota 2 would otherwise need `-vulkan_disable_occlusion_queries`
+    // and `-vulkan_descriptor_sets_per_pool 256`.
+    Profile {
+        application: Some("dota"),
+        engine: None,
+        disable_occlusion_queries: true,
+        descriptor_pool_max_sets: Some(256),
+        hidden_extensions: &[],
+        present_mode: None,
+    },
+];
+
+/// The settings, `None` leaving the choice to the backend.
+#[derive(Clone, Debug, Default)]
+pub struct Settings {
+    /// Comma-separated backends of the instances, see `Backend::from_name`.
+    pub backend: Option<String>,
+    pub validation: bool,
+    /// Path of the report of the leaked handles.
+    pub leak_report: Option<PathBuf>,
+    pub leak_backtrace: bool,
+    /// Directory to write the SPIR-V of the shader modules to.
+    pub shader_dump: Option<PathBuf>,
+    /// File written by the `VK_LAYER_GFX_api_dump` layer instead of the standard output.
+    pub api_dump_file: Option<PathBuf>,
+    /// File to capture the calls to, for `portability-replay` to play them back.
+    pub capture_file: Option<PathBuf>,
+    pub frame_dump: FrameDump,
+    pub metal: MetalSettings,
+    /// Skip the built-in workarounds, keeping only the configured ones.
+    pub disable_workarounds: bool,
+    pub workarounds: Workarounds,
+}
+
+#[derive(Default)]
+struct Warnings {
+    pending: Vec<String>,
+    reported: Vec<String>,
+}
+
+lazy_static! {
+    /// Contents of the configuration file.
+    static ref FILE: Table = load_file();
+    /// Settings without any application.
+    static ref DEFAULT: Arc<Settings> = Arc::new(Settings::resolve(&FILE, None));
+    static ref WARNINGS: Mutex<Warnings> = Mutex::new(Warnings::default());
+}
+
+/// Records an invalid setting, to be logged by `report_warnings`.
+/// The file is read before the logger exists, and may be read several times.
+fn warning(message: String) {
+    let mut warnings = WARNINGS.lock().unwrap();
+    if !warnings.reported.contains(&message) && !warnings.pending.contains(&message) {
+        warnings.pending.push(message);
+    }
+}
+
+/// Logs the invalid settings found so far.
+pub fn report_warnings() {
+    let mut warnings = WARNINGS.lock().unwrap();
+    let Warnings { pending, reported } = &mut *warnings;
+    for message in pending.drain(..) {
+        warn!("{}", message);
+        reported.push(message);
+    }
+}
+
+fn load_file() -> Table {
+    let path = match env::var_os("GFX_CONFIG") {
+        Some(path) => PathBuf::from(path),
+        None => match env::current_exe() {
+            Ok(exe) if exe.with_file_name(FILE_NAME).is_file() => exe.with_file_name(FILE_NAME),
+            _ => return Table::new(),
+        },
+    };
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) => {
+            warning(format!(
+                "Unable to read the configuration {:?}: {}",
+                path, e
+            ));
+            return Table::new();
+        }
+    };
+    match text.parse::<Value>() {
+        Ok(Value::Table(table)) => table,
+        Ok(_) => Table::new(),
+        Err(e) => {
+            warning(format!(
+                "Unable to parse the configuration {:?}: {}",
+                path, e
+            ));
+            Table::new()
+        }
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "1" | "yes" | "true" | "on" => Some(true),
+        "0" | "no" | "false" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_recording(value: &str) -> Option<Recording> {
+    match value.to_lowercase().as_str() {
+        "immediate" => Some(Recording::Immediate),
+        "deferred" => Some(Recording::Deferred),
+        _ => None,
+    }
+}
+
+fn parse_image_format(value: &str) -> Option<ImageFormat> {
+    match value.to_lowercase().as_str() {
+        "png" => Some(ImageFormat::Png),
+        "ppm" => Some(ImageFormat::Ppm),
+        _ => None,
+    }
+}
+
+fn parse_present_mode(value: &str) -> Option<PresentMode> {
+    match value.to_lowercase().as_str() {
+        "immediate" => Some(PresentMode::Immediate),
+        "mailbox" => Some(PresentMode::Mailbox),
+        "fifo" => Some(PresentMode::Fifo),
+        "fifo_relaxed" => Some(PresentMode::FifoRelaxed),
+        _ => None,
+    }
+}
+
+/// Checks the value of a setting, reporting it if it's invalid.
+fn checked<T>(value: Option<T>, name: &str, raw: &dyn fmt::Display, expected: &str) -> Option<T> {
+    if value.is_none() {
+        warning(format!(
+            "Invalid value {} of {}, expected {}",
+            raw, name, expected
+        ));
+    }
+    value
+}
+
+fn env_var(name: &str) -> Option<String> {
+    env::var(name).ok()
+}
+
+fn env_bool(name: &str) -> Option<bool> {
+    let value = env_var(name)?;
+    checked(parse_bool(&value), name, &value, "yes or no")
+}
+
+fn env_u64(name: &str) -> Option<u64> {
+    let value = env_var(name)?;
+    checked(value.parse().ok(), name, &value, "an integer")
+}
+
+impl FrameDump {
+    fn apply(&mut self, table: &Table, prefix: &str) {
+        for (key, value) in table {
+            let name = format!("{}{}", prefix, key);
+            let integer = || value.as_integer().filter(|&i| i >= 0).map(|i| i as u64);
+            match key.as_str() {
+                "directory" => {
+                    if let Some(path) = checked(value.as_str(), &name, value, "a path") {
+                        self.directory = Some(PathBuf::from(path));
+                    }
+                }
+                "format" => {
+                    let format = value.as_str().and_then(parse_image_format);
+                    if let Some(format) = checked(format, &name, value, "\"png\" or \"ppm\"") {
+                        self.format = format;
+                    }
+                }
+                "interval" => {
+                    let interval = integer().filter(|&interval| interval > 0);
+                    if let Some(interval) = checked(interval, &name, value, "a positive integer") {
+                        self.interval = interval;
+                    }
+                }
+                "first" => {
+                    if let Some(first) = checked(integer(), &name, value, "an integer") {
+                        self.first = first;
+                    }
+                }
+                "last" => {
+                    if let Some(last) = checked(integer(), &name, value, "an integer") {
+                        self.last = Some(last);
+                    }
+                }
+                _ => warning(format!("Unknown setting {}", name)),
+            }
+        }
+    }
+}
+
+impl MetalSettings {
+    fn apply(&mut self, table: &Table, prefix: &str) {
+        for (key, value) in table {
+            let name = format!("{}{}", prefix, key);
+            match key.as_str() {
+                "arguments" => {
+                    if let Some(arguments) = checked(value.as_bool(), &name, value, "a boolean") {
+                        self.arguments = Some(arguments);
+                    }
+                }
+                "recording" => {
+                    let recording = value.as_str().and_then(parse_recording);
+                    if let Some(recording) =
+                        checked(recording, &name, value, "\"immediate\" or \"deferred\"")
+                    {
+                        self.recording = Some(recording);
+                    }
+                }
+                "stitching" => {
+                    if let Some(stitching) = checked(value.as_bool(), &name, value, "a boolean") {
+                        self.stitching = Some(stitching);
+                    }
+                }
+                _ => warning(format!("Unknown setting {}", name)),
+            }
+        }
+    }
+}
+
+impl Workarounds {
+    fn apply(&mut self, table: &Table, prefix: &str) {
+        for (key, value) in table {
+            let name = format!("{}{}", prefix, key);
+            match key.as_str() {
+                "disable_occlusion_queries" => {
+                    if let Some(disable) = checked(value.as_bool(), &name, value, "a boolean") {
+                        self.disable_occlusion_queries = disable;
+                    }
+                }
+                "descriptor_pool_max_sets" => {
+                    let count = value
+                        .as_integer()
+                        .filter(|&count| count > 0 && count <= u32::max_value() as i64);
+                    if let Some(count) = checked(count, &name, value, "a positive integer") {
+                        self.descriptor_pool_max_sets = Some(count as u32);
+                    }
+                }
+                "hidden_extensions" => {
+                    let extensions = value.as_array().and_then(|array| {
+                        array
+                            .iter()
+                            .map(|value| value.as_str().map(str::to_string))
+                            .collect::<Option<Vec<_>>>()
+                    });
+                    if let Some(extensions) =
+                        checked(extensions, &name, value, "an array of strings")
+                    {
+                        self.hidden_extensions.extend(extensions);
+                    }
+                }
+                "present_mode" => {
+                    let mode = value.as_str().and_then(parse_present_mode);
+                    if let Some(mode) = checked(
+                        mode,
+                        &name,
+                        value,
+                        "\"immediate\", \"mailbox\", \"fifo\" or \"fifo_relaxed\"",
+                    ) {
+                        self.present_mode = Some(mode);
+                    }
+                }
+                _ => warning(format!("Unknown setting {}", name)),
+            }
+        }
+    }
+}
+
+impl Profile {
+    fn matches(&self, application: &str, engine: &str) -> bool {
+        self.application.map_or(true, |name| name == application)
+            && self.engine.map_or(true, |name| name == engine)
+    }
+
+    fn apply(&self, workarounds: &mut Workarounds) {
+        workarounds.disable_occlusion_queries |= self.disable_occlusion_queries;
+        if self.descriptor_pool_max_sets.is_some() {
+            workarounds.descriptor_pool_max_sets = self.descriptor_pool_max_sets;
+        }
+        workarounds
+            .hidden_extensions
+            .extend(self.hidden_extensions.iter().map(|name| name.to_string()));
+        if self.present_mode.is_some() {
+            workarounds.present_mode = self.present_mode;
+        }
+    }
+}
+
+impl Settings {
+    /// Resolves the settings of an application, given its name and the one of its engine,
+    /// from the contents of a configuration file.
+    fn resolve(file: &Table, application: Option<(&str, &str)>) -> Self {
+        let mut settings = Settings::default();
+        // Tables applying to the application, with their paths.
+        let mut tables = vec![(file, String::new())];
+
+        match file.get("application") {
+            Some(Value::Array(sections)) => {
+                for (i, section) in sections.iter().enumerate() {
+                    let prefix = format!("application[{}].", i);
+                    match section.as_table() {
+                        Some(section) => {
+                            if matches(section, &prefix, application) {
+                                tables.push((section, prefix));
+                            }
+                        }
+                        None => warning(format!(
+                            "Invalid section {}, expected a table",
+                            &prefix[..prefix.len() - 1]
+                        )),
+                    }
+                }
+            }
+            Some(_) => {
+                warning("Invalid section application, expected [[application]] tables".to_string())
+            }
+            None => {}
+        }
+
+        for (table, prefix) in &tables {
+            settings.apply(table, prefix);
+        }
+        settings.apply_env();
+
+        // The configured workarounds go on top of the built-in ones.
+        if let (false, Some((name, engine))) = (settings.disable_workarounds, application) {
+            for profile in PROFILES
+                .iter()
+                .filter(|profile| profile.matches(name, engine))
+            {
+                profile.apply(&mut settings.workarounds);
+            }
+        }
+        for (table, prefix) in &tables {
+            match table.get("workarounds") {
+                Some(Value::Table(workarounds)) => settings
+                    .workarounds
+                    .apply(workarounds, &format!("{}workarounds.", prefix)),
+                Some(_) => warning(format!(
+                    "Invalid section {}workarounds, expected a table",
+                    prefix
+                )),
+                None => {}
+            }
+        }
+        settings
+    }
+
+    /// Applies a table of the file, `prefix` being its path for the warnings.
+    fn apply(&mut self, table: &Table, prefix: &str) {
+        for (key, value) in table {
+            let name = format!("{}{}", prefix, key);
+            match key.as_str() {
+                "backend" => {
+                    if let Some(backend) = checked(value.as_str(), &name, value, "a string") {
+                        self.backend = Some(backend.to_string());
+                    }
+                }
+                "validation" => {
+                    if let Some(validation) = checked(value.as_bool(), &name, value, "a boolean") {
+                        self.validation = validation;
+                    }
+                }
+                "leak_report" => {
+                    if let Some(path) = checked(value.as_str(), &name, value, "a path") {
+                        self.leak_report = Some(PathBuf::from(path));
+                    }
+                }
+                "leak_backtrace" => {
+                    if let Some(backtrace) = checked(value.as_bool(), &name, value, "a boolean") {
+                        self.leak_backtrace = backtrace;
+                    }
+                }
+                "shader_dump" => {
+                    if let Some(path) = checked(value.as_str(), &name, value, "a path") {
+                        self.shader_dump = Some(PathBuf::from(path));
+                    }
+                }
+                "api_dump_file" => {
+                    if let Some(path) = checked(value.as_str(), &name, value, "a path") {
+                        self.api_dump_file = Some(PathBuf::from(path));
+                    }
+                }
+                "capture_file" => {
+                    if let Some(path) = checked(value.as_str(), &name, value, "a path") {
+                        self.capture_file = Some(PathBuf::from(path));
+                    }
+                }
+                "frame_dump" => match value.as_table() {
+                    Some(dump) => self.frame_dump.apply(dump, &format!("{}.", name)),
+                    None => warning(format!("Invalid section {}, expected a table", name)),
+                },
+                "metal" => match value.as_table() {
+                    Some(metal) => self.metal.apply(metal, &format!("{}.", name)),
+                    None => warning(format!("Invalid section {}, expected a table", name)),
+                },
+                "disable_workarounds" => {
+                    if let Some(disable) = checked(value.as_bool(), &name, value, "a boolean") {
+                        self.disable_workarounds = disable;
+                    }
+                }
+                // Handled by `resolve`.
+                "workarounds" => {}
+                "application" if prefix.is_empty() => {}
+                "name" | "engine" if !prefix.is_empty() => {}
+                _ => warning(format!("Unknown setting {}", name)),
+            }
+        }
+    }
+
+    /// Applies the environment variables.
+    fn apply_env(&mut self) {
+        if let Some(backend) = env_var("GFX_BACKEND") {
+            self.backend = Some(backend);
+        }
+        if let Some(validation) = env_bool("GFX_VALIDATION") {
+            self.validation = validation;
+        }
+        if let Some(path) = env::var_os("GFX_LEAK_REPORT") {
+            self.leak_report = Some(PathBuf::from(path));
+        }
+        if let Some(backtrace) = env_bool("GFX_LEAK_BACKTRACE") {
+            self.leak_backtrace = backtrace;
+        }
+        if let Some(path) = env::var_os("GFX_SHADER_DUMP") {
+            self.shader_dump = Some(PathBuf::from(path));
+        }
+        if let Some(path) = env::var_os("GFX_API_DUMP_FILE") {
+            self.api_dump_file = Some(PathBuf::from(path));
+        }
+        if let Some(path) = env::var_os("GFX_CAPTURE_FILE") {
+            self.capture_file = Some(PathBuf::from(path));
+        }
+        if let Some(path) = env::var_os("GFX_FRAME_DUMP") {
+            self.frame_dump.directory = Some(PathBuf::from(path));
+        }
+        if let Some(value) = env_var("GFX_FRAME_DUMP_FORMAT") {
+            let format = parse_image_format(&value);
+            if let Some(format) = checked(format, "GFX_FRAME_DUMP_FORMAT", &value, "png or ppm") {
+                self.frame_dump.format = format;
+            }
+        }
+        if let Some(value) = env_var("GFX_FRAME_DUMP_INTERVAL") {
+            let interval = value.parse().ok().filter(|&interval| interval > 0);
+            if let Some(interval) = checked(
+                interval,
+                "GFX_FRAME_DUMP_INTERVAL",
+                &value,
+                "a positive integer",
+            ) {
+                self.frame_dump.interval = interval;
+            }
+        }
+        if let Some(first) = env_u64("GFX_FRAME_DUMP_FIRST") {
+            self.frame_dump.first = first;
+        }
+        if let Some(last) = env_u64("GFX_FRAME_DUMP_LAST") {
+            self.frame_dump.last = Some(last);
+        }
+        if let Some(arguments) = env_bool("GFX_METAL_ARGUMENTS") {
+            self.metal.arguments = Some(arguments);
+        }
+        if let Some(value) = env_var("GFX_METAL_RECORDING") {
+            let recording = parse_recording(&value);
+            if let Some(recording) = checked(
+                recording,
+                "GFX_METAL_RECORDING",
+                &value,
+                "immediate or deferred",
+            ) {
+                self.metal.recording = Some(recording);
+            }
+        }
+        if let Some(stitching) = env_bool("GFX_METAL_STITCHING") {
+            self.metal.stitching = Some(stitching);
+        }
+        if let Some(disable) = env_bool("GFX_DISABLE_WORKAROUNDS") {
+            self.disable_workarounds = disable;
+        }
+    }
+}
+
+/// Returns true if an `[[application]]` section applies to the application.
+/// All the names it gives have to match.
+fn matches(section: &Table, prefix: &str, application: Option<(&str, &str)>) -> bool {
+    let (name, engine) = application.unwrap_or(("", ""));
+    let mut matched = false;
+    for &(key, actual) in &[("name", name), ("engine", engine)] {
+        match section.get(key) {
+            Some(Value::String(expected)) if application.is_some() && expected == actual => {
+                matched = true
+            }
+            Some(Value::String(_)) => return false,
+            Some(value) => {
+                warning(format!(
+                    "Invalid value {} of {}{}, expected a string",
+                    value, prefix, key
+                ));
+                return false;
+            }
+            None => {}
+        }
+    }
+    if !matched {
+        warning(format!(
+            "Section {} matches no application, it needs a name or an engine",
+            &prefix[..prefix.len() - 1]
+        ));
+    }
+    matched
+}
+
+/// Resolves the settings of the application creating an instance,
+/// which are kept by the instance and the objects created from it.
+pub unsafe fn for_application(info: Option<&VkApplicationInfo>) -> Arc<Settings> {
+    let name = |ptr: *const c_char| {
+        if ptr.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(ptr).to_string_lossy().into_owned()
+        }
+    };
+    let (application, engine) = match info {
+        Some(info) => (name(info.pApplicationName), name(info.pEngineName)),
+        None => (String::new(), String::new()),
+    };
+    Arc::new(Settings::resolve(&FILE, Some((&application, &engine))))
+}
+
+/// Returns the settings without any application, used by the features
+/// that aren't tied to an instance.
+pub fn current() -> Arc<Settings> {
+    Arc::clone(&DEFAULT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Table {
+        match text.parse::<Value>() {
+            Ok(Value::Table(table)) => table,
+            other => panic!("Invalid test configuration: {:?}", other),
+        }
+    }
+
+    fn warned(message: &str) -> bool {
+        WARNINGS
+            .lock()
+            .unwrap()
+            .pending
+            .iter()
+            .any(|warning| warning == message)
+    }
+
+    const FILE_TEXT: &str = r#"
+        backend = "vulkan"
+        shader_dump = "/tmp/shaders"
+
+        [frame_dump]
+        directory = "/tmp/frames"
+        format = "ppm"
+        interval = 10
+        first = 100
+        last = 200
+
+        [metal]
+        arguments = true
+        recording = "deferred"
+
+        [[application]]
+        name = "vkcube"
+        engine = "vkcube"
+        metal = { stitching = false }
+        workarounds = { present_mode = "fifo", hidden_extensions = ["VK_KHR_maintenance1"] }
+    "#;
+
+    #[test]
+    fn file() {
+        let settings = Settings::resolve(&parse(FILE_TEXT), None);
+        assert_eq!(settings.backend.as_deref(), Some("vulkan"));
+        assert_eq!(settings.shader_dump, Some(PathBuf::from("/tmp/shaders")));
+        let dump = &settings.frame_dump;
+        assert_eq!(dump.directory, Some(PathBuf::from("/tmp/frames")));
+        assert_eq!(dump.format, ImageFormat::Ppm);
+        assert_eq!((dump.interval, dump.first, dump.last), (10, 100, Some(200)));
+        assert_eq!(settings.metal.arguments, Some(true));
+        assert_eq!(settings.metal.recording, Some(Recording::Deferred));
+        assert_eq!(settings.metal.stitching, None);
+        assert_eq!(settings.workarounds, Workarounds::default());
+    }
+
+    #[test]
+    fn applications() {
+        let file = parse(FILE_TEXT);
+        let settings = Settings::resolve(&file, Some(("vkcube", "vkcube")));
+        assert_eq!(settings.metal.arguments, Some(true));
+        assert_eq!(settings.metal.stitching, Some(false));
+        assert_eq!(settings.workarounds.present_mode, Some(PresentMode::Fifo));
+        assert_eq!(
+            settings.workarounds.hidden_extensions,
+            ["VK_KHR_maintenance1"]
+        );
+
+        // Both the names have to match.
+        let settings = Settings::resolve(&file, Some(("vkcube", "other")));
+        assert_eq!(settings.metal.stitching, None);
+        assert_eq!(settings.workarounds, Workarounds::default());
+    }
+
+    #[test]
+    fn profiles() {
+        let settings = Settings::resolve(&Table::new(), Some(("dota", "")));
+        assert!(settings.workarounds.disable_occlusion_queries);
+        assert_eq!(settings.workarounds.descriptor_pool_max_sets, Some(256));
+
+        let file = parse(
+            r#"
+            [[application]]
+            name = "dota"
+            workarounds = { descriptor_pool_max_sets = 64 }
+            "#,
+        );
+        let settings = Settings::resolve(&file, Some(("dota", "")));
+        assert!(settings.workarounds.disable_occlusion_queries);
+        assert_eq!(settings.workarounds.descriptor_pool_max_sets, Some(64));
+
+        let file = parse("disable_workarounds = true");
+        let settings = Settings::resolve(&file, Some(("dota", "")));
+        assert_eq!(settings.workarounds, Workarounds::default());
+    }
+
+    #[test]
+    fn invalid_settings() {
+        let file = parse(
+            r#"
+            validation = "maybe"
+            unknown = 1
+            frame_dump = { format = "gif", interval = 0 }
+
+            [[application]]
+            engine = 1
+            "#,
+        );
+        let settings = Settings::resolve(&file, Some(("test", "")));
+        assert!(!settings.validation);
+        assert_eq!(settings.frame_dump.format, ImageFormat::Png);
+        assert_eq!(settings.frame_dump.interval, 1);
+        assert!(warned(
+            "Invalid value \"maybe\" of validation, expected a boolean"
+        ));
+        assert!(warned("Unknown setting unknown"));
+        assert!(warned(
+            "Invalid value \"gif\" of frame_dump.format, expected \"png\" or \"ppm\""
+        ));
+        assert!(warned(
+            "Invalid value 0 of frame_dump.interval, expected a positive integer"
+        ));
+        assert!(warned(
+            "Invalid value 1 of application[0].engine, expected a string"
+        ));
+    }
+}
@@ -126,6 +126,73 @@ pub fn limits_from_hal(limits: Limits) -> VkPhysicalDeviceLimits {
     }
 }
 
+fn flag(on: VkBool32, feature: Features) -> Features {
+    if on != 0 {
+        feature
+    } else {
+        Features::empty()
+    }
+}
+
+#[rustfmt::skip]
+pub fn features_to_hal(ef: &VkPhysicalDeviceFeatures) -> Features {
+    flag(ef.robustBufferAccess, Features::ROBUST_BUFFER_ACCESS) |
+    flag(ef.fullDrawIndexUint32, Features::FULL_DRAW_INDEX_U32) |
+    flag(ef.imageCubeArray, Features::IMAGE_CUBE_ARRAY) |
+    flag(ef.independentBlend, Features::INDEPENDENT_BLENDING) |
+    flag(ef.geometryShader, Features::GEOMETRY_SHADER) |
+    flag(ef.tessellationShader, Features::TESSELLATION_SHADER) |
+    flag(ef.sampleRateShading, Features::SAMPLE_RATE_SHADING) |
+    flag(ef.dualSrcBlend, Features::DUAL_SRC_BLENDING) |
+    flag(ef.logicOp, Features::LOGIC_OP) |
+    flag(ef.multiDrawIndirect, Features::MULTI_DRAW_INDIRECT) |
+    flag(ef.drawIndirectFirstInstance, Features::DRAW_INDIRECT_FIRST_INSTANCE) |
+    flag(ef.depthClamp, Features::DEPTH_CLAMP) |
+    flag(ef.depthBiasClamp, Features::DEPTH_BIAS_CLAMP) |
+    flag(ef.fillModeNonSolid, Features::NON_FILL_POLYGON_MODE) |
+    flag(ef.depthBounds, Features::DEPTH_BOUNDS) |
+    flag(ef.wideLines, Features::LINE_WIDTH) |
+    flag(ef.largePoints, Features::POINT_SIZE) |
+    flag(ef.alphaToOne, Features::ALPHA_TO_ONE) |
+    flag(ef.multiViewport, Features::MULTI_VIEWPORTS) |
+    flag(ef.samplerAnisotropy, Features::SAMPLER_ANISOTROPY) |
+    flag(ef.textureCompressionETC2, Features::FORMAT_ETC2) |
+    flag(ef.textureCompressionASTC_LDR, Features::FORMAT_ASTC_LDR) |
+    flag(ef.textureCompressionBC, Features::FORMAT_BC) |
+    flag(ef.occlusionQueryPrecise, Features::PRECISE_OCCLUSION_QUERY) |
+    flag(ef.pipelineStatisticsQuery, Features::PIPELINE_STATISTICS_QUERY) |
+    flag(ef.vertexPipelineStoresAndAtomics, Features::VERTEX_STORES_AND_ATOMICS) |
+    flag(ef.fragmentStoresAndAtomics, Features::FRAGMENT_STORES_AND_ATOMICS) |
+    flag(ef.shaderTessellationAndGeometryPointSize, Features::SHADER_TESSELLATION_AND_GEOMETRY_POINT_SIZE) |
+    flag(ef.shaderImageGatherExtended, Features::SHADER_IMAGE_GATHER_EXTENDED) |
+    flag(ef.shaderStorageImageExtendedFormats, Features::SHADER_STORAGE_IMAGE_EXTENDED_FORMATS) |
+    flag(ef.shaderStorageImageMultisample, Features::SHADER_STORAGE_IMAGE_MULTISAMPLE) |
+    flag(ef.shaderStorageImageReadWithoutFormat, Features::SHADER_STORAGE_IMAGE_READ_WITHOUT_FORMAT) |
+    flag(ef.shaderStorageImageWriteWithoutFormat, Features::SHADER_STORAGE_IMAGE_WRITE_WITHOUT_FORMAT) |
+    flag(ef.shaderUniformBufferArrayDynamicIndexing, Features::SHADER_UNIFORM_BUFFER_ARRAY_DYNAMIC_INDEXING) |
+    flag(ef.shaderSampledImageArrayDynamicIndexing, Features::SHADER_SAMPLED_IMAGE_ARRAY_DYNAMIC_INDEXING) |
+    flag(ef.shaderStorageBufferArrayDynamicIndexing, Features::SHADER_STORAGE_BUFFER_ARRAY_DYNAMIC_INDEXING) |
+    flag(ef.shaderStorageImageArrayDynamicIndexing, Features::SHADER_STORAGE_IMAGE_ARRAY_DYNAMIC_INDEXING) |
+    flag(ef.shaderClipDistance, Features::SHADER_CLIP_DISTANCE) |
+    flag(ef.shaderCullDistance, Features::SHADER_CULL_DISTANCE) |
+    flag(ef.shaderFloat64, Features::SHADER_FLOAT64) |
+    flag(ef.shaderInt64, Features::SHADER_INT64) |
+    flag(ef.shaderInt16, Features::SHADER_INT16) |
+    flag(ef.shaderResourceResidency, Features::SHADER_RESOURCE_RESIDENCY) |
+    flag(ef.shaderResourceMinLod, Features::SHADER_RESOURCE_MIN_LOD) |
+    flag(ef.sparseBinding, Features::SPARSE_BINDING) |
+    flag(ef.sparseResidencyBuffer, Features::SPARSE_RESIDENCY_BUFFER) |
+    flag(ef.sparseResidencyImage2D, Features::SPARSE_RESIDENCY_IMAGE_2D) |
+    flag(ef.sparseResidencyImage3D, Features::SPARSE_RESIDENCY_IMAGE_3D) |
+    flag(ef.sparseResidency2Samples, Features::SPARSE_RESIDENCY_2_SAMPLES) |
+    flag(ef.sparseResidency4Samples, Features::SPARSE_RESIDENCY_4_SAMPLES) |
+    flag(ef.sparseResidency8Samples, Features::SPARSE_RESIDENCY_8_SAMPLES) |
+    flag(ef.sparseResidency16Samples, Features::SPARSE_RESIDENCY_16_SAMPLES) |
+    flag(ef.sparseResidencyAliased, Features::SPARSE_RESIDENCY_ALIASED) |
+    flag(ef.variableMultisampleRate, Features::VARIABLE_MULTISAMPLE_RATE) |
+    flag(ef.inheritedQueries, Features::INHERITED_QUERIES)
+}
+
 pub fn features_from_hal(features: Features) -> VkPhysicalDeviceFeatures {
     VkPhysicalDeviceFeatures {
         robustBufferAccess: features.contains(Features::ROBUST_BUFFER_ACCESS) as _,
@@ -328,7 +395,7 @@ fn map_swizzle_component(
     component: VkComponentSwizzle,
     identity: format::Component,
 ) -> format::Component {
-    use crate::VkComponentSwizzle::*;
+    use super::VkComponentSwizzle::*;
 
     match component {
         VK_COMPONENT_SWIZZLE_IDENTITY => identity,
@@ -774,6 +841,16 @@ pub fn map_present_mode(present_mode: VkPresentModeKHR) -> window::PresentMode {
     unsafe { mem::transmute(present_mode) }
 }
 
+pub fn map_forced_present_mode(present_mode: crate::config::PresentMode) -> VkPresentModeKHR {
+    use crate::config::PresentMode;
+    match present_mode {
+        PresentMode::Immediate => VkPresentModeKHR::VK_PRESENT_MODE_IMMEDIATE_KHR,
+        PresentMode::Mailbox => VkPresentModeKHR::VK_PRESENT_MODE_MAILBOX_KHR,
+        PresentMode::Fifo => VkPresentModeKHR::VK_PRESENT_MODE_FIFO_KHR,
+        PresentMode::FifoRelaxed => VkPresentModeKHR::VK_PRESENT_MODE_FIFO_RELAXED_KHR,
+    }
+}
+
 pub fn map_composite_alpha(
     composite_alpha: VkCompositeAlphaFlagBitsKHR,
 ) -> window::CompositeAlphaMode {
@@ -1017,6 +1094,22 @@ pub fn map_subresource_layers(
     }
 }
 
+/// Returns the queue families of an ownership transfer, `None` if the barrier
+/// doesn't transfer the resource, or transfers it outside of the device.
+pub fn map_queue_family_transfer(
+    src: u32,
+    dst: u32,
+) -> Option<std::ops::Range<hal::queue::QueueFamilyId>> {
+    let is_local = |family: u32| {
+        family != VK_QUEUE_FAMILY_IGNORED as u32 && family != VK_QUEUE_FAMILY_EXTERNAL_KHX as u32
+    };
+    if src == dst || !is_local(src) || !is_local(dst) {
+        None
+    } else {
+        Some(hal::queue::QueueFamilyId(src as _)..hal::queue::QueueFamilyId(dst as _))
+    }
+}
+
 #[inline]
 pub fn map_subresource_range(subresource: VkImageSubresourceRange) -> hal::image::SubresourceRange {
     hal::image::SubresourceRange {
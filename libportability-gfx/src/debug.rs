@@ -0,0 +1,516 @@
+//! Delivery of diagnostics to the `VK_EXT_debug_utils` messengers
+//! and the `VK_EXT_debug_report` callbacks.
+//!
+//! All the records going through the `log` crate (ours and the backend ones)
+//! are forwarded to the registered messengers, in addition to `env_logger`.
+//! The messengers are shared by all the backends, so that a single logger
+//! reaches the instances of every one of them.
+
+use crate::{
+    ffi::{
+        PFN_vkDebugReportCallbackEXT, PFN_vkDebugUtilsMessengerCallbackEXT,
+        VkDebugReportFlagBitsEXT, VkDebugReportFlagsEXT, VkDebugReportObjectTypeEXT,
+        VkDebugUtilsLabelEXT, VkDebugUtilsMessageSeverityFlagBitsEXT,
+        VkDebugUtilsMessageSeverityFlagsEXT, VkDebugUtilsMessageTypeFlagBitsEXT,
+        VkDebugUtilsMessageTypeFlagsEXT, VkDebugUtilsMessengerCallbackDataEXT,
+        VkDebugUtilsObjectNameInfoEXT, VkObjectType, VkStructureType,
+    },
+    handle::Handle,
+    validation,
+};
+
+use lazy_static::lazy_static;
+use log::{Level, LevelFilter};
+
+use std::{
+    cell::RefCell,
+    ffi::{CStr, CString},
+    os::raw,
+    ptr,
+    sync::{Once, RwLock},
+};
+
+//NOTE: all *EXT types have to be pure `Handle` things for compatibility with
+//`VK_DEFINE_NON_DISPATCHABLE_HANDLE` used in `vulkan.h`
+pub type VkDebugUtilsMessengerEXT = Handle<DebugUtilsMessenger>;
+pub type VkDebugReportCallbackEXT = Handle<DebugReportCallback>;
+
+#[derive(Clone, Copy)]
+pub struct DebugUtilsMessenger {
+    pub severity: VkDebugUtilsMessageSeverityFlagsEXT,
+    pub types: VkDebugUtilsMessageTypeFlagsEXT,
+    pub callback: PFN_vkDebugUtilsMessengerCallbackEXT,
+    pub user_data: *mut raw::c_void,
+}
+
+#[derive(Clone, Copy)]
+pub struct DebugReportCallback {
+    pub flags: VkDebugReportFlagsEXT,
+    pub callback: PFN_vkDebugReportCallbackEXT,
+    pub user_data: *mut raw::c_void,
+}
+
+// The user data is opaque to us, it's only handed back to the callback.
+unsafe impl Send for DebugUtilsMessenger {}
+unsafe impl Sync for DebugUtilsMessenger {}
+unsafe impl Send for DebugReportCallback {}
+unsafe impl Sync for DebugReportCallback {}
+
+lazy_static! {
+    /// Registered messengers, keyed by their instances and the address of their handles.
+    static ref MESSENGERS: RwLock<Vec<(Key, DebugUtilsMessenger)>> = RwLock::new(Vec::new());
+    /// Registered report callbacks, keyed by their instances and the address of their handles.
+    static ref REPORT_CALLBACKS: RwLock<Vec<(Key, DebugReportCallback)>> =
+        RwLock::new(Vec::new());
+}
+
+thread_local! {
+    /// Labels of the queue that is currently executing an operation on this thread.
+    static QUEUE_LABELS: RefCell<Vec<CString>> = RefCell::new(Vec::new());
+    /// Objects of the operations in progress on this thread, the innermost last.
+    static OBJECTS: RefCell<Vec<(VkDebugReportObjectTypeEXT, u64)>> = RefCell::new(Vec::new());
+}
+
+static LOGGER_INIT: Once = Once::new();
+
+struct Logger {
+    #[cfg(feature = "env_logger")]
+    inner: env_logger::Logger,
+}
+
+impl Logger {
+    fn filter(&self) -> LevelFilter {
+        #[cfg(feature = "env_logger")]
+        return self.inner.filter();
+        #[cfg(not(feature = "env_logger"))]
+        return LevelFilter::Off;
+    }
+}
+
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        #[cfg(feature = "env_logger")]
+        {
+            if self.inner.enabled(metadata) {
+                return true;
+            }
+        }
+        metadata.level() <= messenger_filter()
+    }
+
+    fn log(&self, record: &log::Record) {
+        #[cfg(feature = "env_logger")]
+        self.inner.log(record);
+        // Validation errors are delivered to the messengers on their own.
+        if !is_active() || record.target() == validation::MESSAGE_ID {
+            return;
+        }
+
+        let severity = match record.level() {
+            Level::Error => {
+                VkDebugUtilsMessageSeverityFlagBitsEXT::VK_DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT
+            }
+            Level::Warn => {
+                VkDebugUtilsMessageSeverityFlagBitsEXT::VK_DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT
+            }
+            Level::Info => {
+                VkDebugUtilsMessageSeverityFlagBitsEXT::VK_DEBUG_UTILS_MESSAGE_SEVERITY_INFO_BIT_EXT
+            }
+            Level::Debug | Level::Trace => {
+                VkDebugUtilsMessageSeverityFlagBitsEXT::VK_DEBUG_UTILS_MESSAGE_SEVERITY_VERBOSE_BIT_EXT
+            }
+        };
+        message(
+            severity,
+            VkDebugUtilsMessageTypeFlagBitsEXT::VK_DEBUG_UTILS_MESSAGE_TYPE_GENERAL_BIT_EXT as _,
+            record.target(),
+            &record.args().to_string(),
+            OBJECTS.with(|objects| objects.borrow().last().cloned()),
+        );
+    }
+
+    fn flush(&self) {
+        #[cfg(feature = "env_logger")]
+        self.inner.flush();
+    }
+}
+
+/// Installs the logger that feeds the messengers. Does nothing if the application
+/// has already installed its own logger.
+pub fn init_logger() {
+    LOGGER_INIT.call_once(|| {
+        let logger = Logger {
+            #[cfg(feature = "env_logger")]
+            inner: env_logger::Builder::from_default_env().build(),
+        };
+        let filter = logger.filter();
+        if log::set_boxed_logger(Box::new(logger)).is_ok() {
+            log::set_max_level(filter);
+        }
+    });
+}
+
+fn is_active() -> bool {
+    !MESSENGERS.read().unwrap().is_empty() || !REPORT_CALLBACKS.read().unwrap().is_empty()
+}
+
+/// Returns the most verbose level requested by any of the registered messengers
+/// or report callbacks.
+fn messenger_filter() -> LevelFilter {
+    use VkDebugReportFlagBitsEXT::*;
+    use VkDebugUtilsMessageSeverityFlagBitsEXT::*;
+
+    let severity = MESSENGERS
+        .read()
+        .unwrap()
+        .iter()
+        .fold(0, |severity, &(_, ref messenger)| {
+            severity | messenger.severity
+        });
+    let report_flags = REPORT_CALLBACKS
+        .read()
+        .unwrap()
+        .iter()
+        .fold(0, |flags, &(_, ref callback)| flags | callback.flags);
+
+    if severity & VK_DEBUG_UTILS_MESSAGE_SEVERITY_VERBOSE_BIT_EXT as u32 != 0
+        || report_flags & VK_DEBUG_REPORT_DEBUG_BIT_EXT as u32 != 0
+    {
+        LevelFilter::Trace
+    } else if severity & VK_DEBUG_UTILS_MESSAGE_SEVERITY_INFO_BIT_EXT as u32 != 0
+        || report_flags & VK_DEBUG_REPORT_INFORMATION_BIT_EXT as u32 != 0
+    {
+        LevelFilter::Info
+    } else if severity & VK_DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT as u32 != 0
+        || report_flags
+            & (VK_DEBUG_REPORT_WARNING_BIT_EXT as u32
+                | VK_DEBUG_REPORT_PERFORMANCE_WARNING_BIT_EXT as u32)
+            != 0
+    {
+        LevelFilter::Warn
+    } else if severity & VK_DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT as u32 != 0
+        || report_flags & VK_DEBUG_REPORT_ERROR_BIT_EXT as u32 != 0
+    {
+        LevelFilter::Error
+    } else {
+        LevelFilter::Off
+    }
+}
+
+fn update_max_level() {
+    let filter = messenger_filter();
+    if filter > log::max_level() {
+        log::set_max_level(filter);
+    }
+}
+
+/// Raw handles of the instance and of the messenger or callback.
+#[derive(Clone, Copy, PartialEq)]
+struct Key {
+    instance: u64,
+    handle: u64,
+}
+
+pub fn register<T>(instance: Handle<T>, handle: VkDebugUtilsMessengerEXT) {
+    let key = Key {
+        instance: instance.as_raw(),
+        handle: handle.as_raw(),
+    };
+    MESSENGERS.write().unwrap().push((key, *handle));
+    update_max_level();
+}
+
+pub fn unregister(handle: VkDebugUtilsMessengerEXT) {
+    let raw = handle.as_raw();
+    MESSENGERS
+        .write()
+        .unwrap()
+        .retain(|&(k, _)| k.handle != raw);
+}
+
+pub fn register_report<T>(instance: Handle<T>, handle: VkDebugReportCallbackEXT) {
+    let key = Key {
+        instance: instance.as_raw(),
+        handle: handle.as_raw(),
+    };
+    REPORT_CALLBACKS.write().unwrap().push((key, *handle));
+    update_max_level();
+}
+
+pub fn unregister_report(handle: VkDebugReportCallbackEXT) {
+    let raw = handle.as_raw();
+    REPORT_CALLBACKS
+        .write()
+        .unwrap()
+        .retain(|&(k, _)| k.handle != raw);
+}
+
+/// Drops the messengers and report callbacks of a destroyed instance,
+/// including the ones the application didn't destroy.
+pub fn unregister_instance<T>(instance: Handle<T>) {
+    let raw = instance.as_raw();
+    MESSENGERS
+        .write()
+        .unwrap()
+        .retain(|&(k, _)| k.instance != raw);
+    REPORT_CALLBACKS
+        .write()
+        .unwrap()
+        .retain(|&(k, _)| k.instance != raw);
+}
+
+/// Invokes all the report callbacks interested in the given flags.
+pub unsafe fn report(
+    flags: VkDebugReportFlagsEXT,
+    object_type: VkDebugReportObjectTypeEXT,
+    object: u64,
+    location: usize,
+    code: i32,
+    layer_prefix: &CStr,
+    text: &CStr,
+) {
+    // The callbacks may call back into us, so the lock can't be held here.
+    let callbacks = REPORT_CALLBACKS.read().unwrap().clone();
+    for (_, callback) in callbacks {
+        if callback.flags & flags == 0 {
+            continue;
+        }
+        if let Some(fun) = callback.callback {
+            fun(
+                flags,
+                object_type,
+                object,
+                location,
+                code,
+                layer_prefix.as_ptr(),
+                text.as_ptr(),
+                callback.user_data,
+            );
+        }
+    }
+}
+
+fn report_flags(
+    severity: VkDebugUtilsMessageSeverityFlagBitsEXT,
+    types: VkDebugUtilsMessageTypeFlagsEXT,
+) -> VkDebugReportFlagsEXT {
+    use VkDebugReportFlagBitsEXT::*;
+    use VkDebugUtilsMessageSeverityFlagBitsEXT::*;
+
+    let flag = match severity {
+        VK_DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT => VK_DEBUG_REPORT_ERROR_BIT_EXT,
+        VK_DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT
+            if types
+                & VkDebugUtilsMessageTypeFlagBitsEXT::VK_DEBUG_UTILS_MESSAGE_TYPE_PERFORMANCE_BIT_EXT
+                    as u32
+                != 0 =>
+        {
+            VK_DEBUG_REPORT_PERFORMANCE_WARNING_BIT_EXT
+        }
+        VK_DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT => VK_DEBUG_REPORT_WARNING_BIT_EXT,
+        VK_DEBUG_UTILS_MESSAGE_SEVERITY_INFO_BIT_EXT => VK_DEBUG_REPORT_INFORMATION_BIT_EXT,
+        _ => VK_DEBUG_REPORT_DEBUG_BIT_EXT,
+    };
+    flag as _
+}
+
+/// Invokes all the messengers interested in the given severity and types.
+pub unsafe fn submit(
+    severity: VkDebugUtilsMessageSeverityFlagBitsEXT,
+    types: VkDebugUtilsMessageTypeFlagsEXT,
+    data: &VkDebugUtilsMessengerCallbackDataEXT,
+) {
+    // The callbacks may call back into us, so the lock can't be held here.
+    let messengers = MESSENGERS.read().unwrap().clone();
+    for (_, messenger) in messengers {
+        if messenger.severity & severity as u32 == 0 || messenger.types & types == 0 {
+            continue;
+        }
+        if let Some(callback) = messenger.callback {
+            callback(severity, types, data, messenger.user_data);
+        }
+    }
+}
+
+/// Builds the callback data for a message, optionally related to an object,
+/// and sends it to both the messengers and the report callbacks.
+pub fn message(
+    severity: VkDebugUtilsMessageSeverityFlagBitsEXT,
+    types: VkDebugUtilsMessageTypeFlagsEXT,
+    id_name: &str,
+    text: &str,
+    object: Option<(VkDebugReportObjectTypeEXT, u64)>,
+) {
+    if !is_active() {
+        return;
+    }
+    let id_name = CString::new(id_name).unwrap_or_default();
+    let text = CString::new(text.replace('\0', " ")).unwrap();
+    let (object_type, object_handle) = object.unwrap_or((
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_UNKNOWN_EXT,
+        0,
+    ));
+
+    unsafe {
+        report(
+            report_flags(severity, types),
+            object_type,
+            object_handle,
+            0,
+            0,
+            &id_name,
+            &text,
+        )
+    };
+
+    let objects = object
+        .map(
+            |(object_type, object_handle)| VkDebugUtilsObjectNameInfoEXT {
+                sType: VkStructureType::VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_NAME_INFO_EXT,
+                pNext: ptr::null(),
+                objectType: map_report_object_type(object_type),
+                objectHandle: object_handle,
+                pObjectName: ptr::null(),
+            },
+        )
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    QUEUE_LABELS.with(|labels| {
+        let queue_labels = labels
+            .borrow()
+            .iter()
+            .map(|name| VkDebugUtilsLabelEXT {
+                sType: VkStructureType::VK_STRUCTURE_TYPE_DEBUG_UTILS_LABEL_EXT,
+                pNext: ptr::null(),
+                pLabelName: name.as_ptr(),
+                color: [0.0; 4],
+            })
+            .collect::<Vec<_>>();
+        let data = VkDebugUtilsMessengerCallbackDataEXT {
+            sType: VkStructureType::VK_STRUCTURE_TYPE_DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT,
+            pNext: ptr::null(),
+            flags: 0,
+            pMessageIdName: id_name.as_ptr(),
+            messageIdNumber: 0,
+            pMessage: text.as_ptr(),
+            queueLabelCount: queue_labels.len() as _,
+            pQueueLabels: queue_labels.as_ptr(),
+            cmdBufLabelCount: 0,
+            pCmdBufLabels: ptr::null(),
+            objectCount: objects.len() as _,
+            pObjects: objects.as_ptr(),
+        };
+        unsafe { submit(severity, types, &data) }
+    });
+}
+
+pub fn map_object_type(object_type: VkObjectType) -> VkDebugReportObjectTypeEXT {
+    use VkDebugReportObjectTypeEXT::*;
+    use VkObjectType::*;
+
+    match object_type {
+        VK_OBJECT_TYPE_INSTANCE => VK_DEBUG_REPORT_OBJECT_TYPE_INSTANCE_EXT,
+        VK_OBJECT_TYPE_PHYSICAL_DEVICE => VK_DEBUG_REPORT_OBJECT_TYPE_PHYSICAL_DEVICE_EXT,
+        VK_OBJECT_TYPE_DEVICE => VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+        VK_OBJECT_TYPE_QUEUE => VK_DEBUG_REPORT_OBJECT_TYPE_QUEUE_EXT,
+        VK_OBJECT_TYPE_SEMAPHORE => VK_DEBUG_REPORT_OBJECT_TYPE_SEMAPHORE_EXT,
+        VK_OBJECT_TYPE_COMMAND_BUFFER => VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT,
+        VK_OBJECT_TYPE_FENCE => VK_DEBUG_REPORT_OBJECT_TYPE_FENCE_EXT,
+        VK_OBJECT_TYPE_DEVICE_MEMORY => VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_MEMORY_EXT,
+        VK_OBJECT_TYPE_BUFFER => VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_EXT,
+        VK_OBJECT_TYPE_IMAGE => VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT,
+        VK_OBJECT_TYPE_EVENT => VK_DEBUG_REPORT_OBJECT_TYPE_EVENT_EXT,
+        VK_OBJECT_TYPE_QUERY_POOL => VK_DEBUG_REPORT_OBJECT_TYPE_QUERY_POOL_EXT,
+        VK_OBJECT_TYPE_BUFFER_VIEW => VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_VIEW_EXT,
+        VK_OBJECT_TYPE_IMAGE_VIEW => VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_VIEW_EXT,
+        VK_OBJECT_TYPE_SHADER_MODULE => VK_DEBUG_REPORT_OBJECT_TYPE_SHADER_MODULE_EXT,
+        VK_OBJECT_TYPE_PIPELINE_CACHE => VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_CACHE_EXT,
+        VK_OBJECT_TYPE_PIPELINE_LAYOUT => VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_LAYOUT_EXT,
+        VK_OBJECT_TYPE_RENDER_PASS => VK_DEBUG_REPORT_OBJECT_TYPE_RENDER_PASS_EXT,
+        VK_OBJECT_TYPE_PIPELINE => VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_EXT,
+        VK_OBJECT_TYPE_DESCRIPTOR_SET_LAYOUT => {
+            VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_SET_LAYOUT_EXT
+        }
+        VK_OBJECT_TYPE_SAMPLER => VK_DEBUG_REPORT_OBJECT_TYPE_SAMPLER_EXT,
+        VK_OBJECT_TYPE_DESCRIPTOR_POOL => VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_POOL_EXT,
+        VK_OBJECT_TYPE_DESCRIPTOR_SET => VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_SET_EXT,
+        VK_OBJECT_TYPE_FRAMEBUFFER => VK_DEBUG_REPORT_OBJECT_TYPE_FRAMEBUFFER_EXT,
+        VK_OBJECT_TYPE_COMMAND_POOL => VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_POOL_EXT,
+        VK_OBJECT_TYPE_SURFACE_KHR => VK_DEBUG_REPORT_OBJECT_TYPE_SURFACE_KHR_EXT,
+        VK_OBJECT_TYPE_SWAPCHAIN_KHR => VK_DEBUG_REPORT_OBJECT_TYPE_SWAPCHAIN_KHR_EXT,
+        VK_OBJECT_TYPE_DEBUG_REPORT_CALLBACK_EXT => VK_DEBUG_REPORT_OBJECT_TYPE_DEBUG_REPORT_EXT,
+        _ => VK_DEBUG_REPORT_OBJECT_TYPE_UNKNOWN_EXT,
+    }
+}
+
+pub fn map_report_object_type(object_type: VkDebugReportObjectTypeEXT) -> VkObjectType {
+    use VkDebugReportObjectTypeEXT::*;
+    use VkObjectType::*;
+
+    match object_type {
+        VK_DEBUG_REPORT_OBJECT_TYPE_INSTANCE_EXT => VK_OBJECT_TYPE_INSTANCE,
+        VK_DEBUG_REPORT_OBJECT_TYPE_PHYSICAL_DEVICE_EXT => VK_OBJECT_TYPE_PHYSICAL_DEVICE,
+        VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT => VK_OBJECT_TYPE_DEVICE,
+        VK_DEBUG_REPORT_OBJECT_TYPE_QUEUE_EXT => VK_OBJECT_TYPE_QUEUE,
+        VK_DEBUG_REPORT_OBJECT_TYPE_SEMAPHORE_EXT => VK_OBJECT_TYPE_SEMAPHORE,
+        VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT => VK_OBJECT_TYPE_COMMAND_BUFFER,
+        VK_DEBUG_REPORT_OBJECT_TYPE_FENCE_EXT => VK_OBJECT_TYPE_FENCE,
+        VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_MEMORY_EXT => VK_OBJECT_TYPE_DEVICE_MEMORY,
+        VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_EXT => VK_OBJECT_TYPE_BUFFER,
+        VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT => VK_OBJECT_TYPE_IMAGE,
+        VK_DEBUG_REPORT_OBJECT_TYPE_EVENT_EXT => VK_OBJECT_TYPE_EVENT,
+        VK_DEBUG_REPORT_OBJECT_TYPE_QUERY_POOL_EXT => VK_OBJECT_TYPE_QUERY_POOL,
+        VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_VIEW_EXT => VK_OBJECT_TYPE_BUFFER_VIEW,
+        VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_VIEW_EXT => VK_OBJECT_TYPE_IMAGE_VIEW,
+        VK_DEBUG_REPORT_OBJECT_TYPE_SHADER_MODULE_EXT => VK_OBJECT_TYPE_SHADER_MODULE,
+        VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_CACHE_EXT => VK_OBJECT_TYPE_PIPELINE_CACHE,
+        VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_LAYOUT_EXT => VK_OBJECT_TYPE_PIPELINE_LAYOUT,
+        VK_DEBUG_REPORT_OBJECT_TYPE_RENDER_PASS_EXT => VK_OBJECT_TYPE_RENDER_PASS,
+        VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_EXT => VK_OBJECT_TYPE_PIPELINE,
+        VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_SET_LAYOUT_EXT => {
+            VK_OBJECT_TYPE_DESCRIPTOR_SET_LAYOUT
+        }
+        VK_DEBUG_REPORT_OBJECT_TYPE_SAMPLER_EXT => VK_OBJECT_TYPE_SAMPLER,
+        VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_POOL_EXT => VK_OBJECT_TYPE_DESCRIPTOR_POOL,
+        VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_SET_EXT => VK_OBJECT_TYPE_DESCRIPTOR_SET,
+        VK_DEBUG_REPORT_OBJECT_TYPE_FRAMEBUFFER_EXT => VK_OBJECT_TYPE_FRAMEBUFFER,
+        VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_POOL_EXT => VK_OBJECT_TYPE_COMMAND_POOL,
+        VK_DEBUG_REPORT_OBJECT_TYPE_SURFACE_KHR_EXT => VK_OBJECT_TYPE_SURFACE_KHR,
+        VK_DEBUG_REPORT_OBJECT_TYPE_SWAPCHAIN_KHR_EXT => VK_OBJECT_TYPE_SWAPCHAIN_KHR,
+        VK_DEBUG_REPORT_OBJECT_TYPE_DEBUG_REPORT_EXT => VK_OBJECT_TYPE_DEBUG_REPORT_CALLBACK_EXT,
+        _ => VK_OBJECT_TYPE_UNKNOWN,
+    }
+}
+
+/// Attaches an object to the messages logged while it's in scope.
+pub struct ObjectScope(());
+
+impl ObjectScope {
+    pub fn enter(object_type: VkDebugReportObjectTypeEXT, object: u64) -> Self {
+        OBJECTS.with(|objects| objects.borrow_mut().push((object_type, object)));
+        ObjectScope(())
+    }
+}
+
+impl Drop for ObjectScope {
+    fn drop(&mut self) {
+        OBJECTS.with(|objects| objects.borrow_mut().pop());
+    }
+}
+
+/// Exposes the labels of a queue to the messages emitted while it's in scope.
+pub struct QueueScope(());
+
+impl QueueScope {
+    pub fn enter(labels: &[CString]) -> Self {
+        QUEUE_LABELS.with(|current| current.borrow_mut().extend_from_slice(labels));
+        QueueScope(())
+    }
+}
+
+impl Drop for QueueScope {
+    fn drop(&mut self) {
+        QUEUE_LABELS.with(|current| current.borrow_mut().clear());
+    }
+}
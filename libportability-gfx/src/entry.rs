@@ -0,0 +1,2097 @@
+//! Entry points of the library, forwarding every call to the backend
+//! owning the dispatchable handle passed as the first argument.
+//!
+//! The instances spanning several backends are handled here as well,
+//! see the `group` module.
+
+use crate::{
+    api_dump,
+    backend::{self, Backend, Owned},
+    capture, config,
+    empty::*,
+    group,
+    handle::Handle,
+};
+
+use log::warn;
+
+use std::{ffi::CStr, mem, os::raw::c_char, ptr, slice};
+
+/// Calls the implementation of a function by the given backend.
+///
+/// All the backends declare it with the same signature, but the handles
+/// and the structures holding them are types of their own, so every
+/// argument is converted to the type of the callee by `Forward`.
+/// The call is skipped if one of the handles it's given is stale, see `Checked`.
+/// The call is written to the API dump when the object it's made with,
+/// its first argument, belongs to an instance that enabled it.
+macro_rules! forward {
+    (@call $module:ident, $name:ident($($arg:ident),*)) => {
+        crate::$module::impls::$name($(Forward::forward($arg)),*)
+    };
+    (@first $first:ident $(, $rest:ident)*) => {
+        $first
+    };
+    ($backend:expr, $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?) => {{
+        let backend = $backend;
+        // Written before the call, which may crash.
+        let call = if api_dump::is_enabled()
+            && api_dump::Dumped::is_dumped(&forward!(@first $($arg),*))
+        {
+            let mut call = api_dump::Call::new(backend, stringify!($name));
+            $(call.arg(stringify!($arg), &$arg);)*
+            call.write();
+            Some(call)
+        } else {
+            None
+        };
+        let result $(: $ret)? = if true $(&& Checked::is_valid(&$arg))* {
+            match backend {
+                Backend::Empty => forward!(@call empty, $name($($arg),*)),
+                #[cfg(feature = "gfx-backend-dx11")]
+                Backend::Dx11 => forward!(@call dx11, $name($($arg),*)),
+                #[cfg(feature = "gfx-backend-dx12")]
+                Backend::Dx12 => forward!(@call dx12, $name($($arg),*)),
+                #[cfg(feature = "gfx-backend-gl")]
+                Backend::Gl => forward!(@call gl, $name($($arg),*)),
+                #[cfg(feature = "gfx-backend-metal")]
+                Backend::Metal => forward!(@call metal, $name($($arg),*)),
+                #[cfg(feature = "gfx-backend-vulkan")]
+                Backend::Vulkan => forward!(@call vulkan, $name($($arg),*)),
+                other => unreachable!("{:?} is not compiled in", other),
+            }
+        } else {
+            Skipped::skipped()
+        };
+        if let Some(mut call) = call {
+            $(call.output(stringify!($arg), &$arg);)*
+            call.finish(&result);
+        }
+        result
+    }};
+}
+
+/// Conversion of an argument to the type a backend declares it with.
+///
+/// The handles are rebuilt from their raw values, and the pointers to the
+/// structures holding handles are cast, these structures being `repr(C)`
+/// with the same fields in all the backends. The other types are shared.
+trait Forward<T> {
+    fn forward(self) -> T;
+}
+
+impl<A, B> Forward<Handle<B>> for Handle<A> {
+    #[inline(always)]
+    fn forward(self) -> Handle<B> {
+        Handle::from_raw(self.as_raw())
+    }
+}
+
+impl<A, B> Forward<crate::handle::DispatchHandle<B>> for crate::handle::DispatchHandle<A> {
+    #[inline(always)]
+    fn forward(self) -> crate::handle::DispatchHandle<B> {
+        crate::handle::DispatchHandle::from_raw(self.as_raw())
+    }
+}
+
+impl<A, B> Forward<*const B> for *const A {
+    #[inline(always)]
+    fn forward(self) -> *const B {
+        self as *const B
+    }
+}
+
+impl<A, B> Forward<*mut B> for *mut A {
+    #[inline(always)]
+    fn forward(self) -> *mut B {
+        self as *mut B
+    }
+}
+
+/// Validity of an argument, checked before the call.
+///
+/// The calls given a destroyed handle are skipped rather than letting
+/// the backend touch the freed object.
+trait Checked {
+    #[inline(always)]
+    fn is_valid(&self) -> bool {
+        true
+    }
+}
+
+impl<T> Checked for Handle<T> {
+    #[inline(always)]
+    fn is_valid(&self) -> bool {
+        Handle::is_valid(self)
+    }
+}
+
+impl<T> Checked for crate::handle::DispatchHandle<T> {
+    #[inline(always)]
+    fn is_valid(&self) -> bool {
+        crate::handle::DispatchHandle::is_valid(self)
+    }
+}
+
+impl<T> Checked for *const T {}
+
+impl<T> Checked for *mut T {}
+
+/// Result of a call skipped because of an invalid argument.
+trait Skipped {
+    fn skipped() -> Self;
+}
+
+impl Skipped for () {
+    fn skipped() -> Self {}
+}
+
+impl Skipped for VkResult {
+    fn skipped() -> Self {
+        VkResult::VK_ERROR_VALIDATION_FAILED_EXT
+    }
+}
+
+impl Skipped for VkBool32 {
+    fn skipped() -> Self {
+        VK_FALSE
+    }
+}
+
+impl<T> Skipped for Option<T> {
+    fn skipped() -> Self {
+        None
+    }
+}
+
+macro_rules! forward_as_is {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Forward<$ty> for $ty {
+                #[inline(always)]
+                fn forward(self) -> $ty {
+                    self
+                }
+            }
+
+            impl Checked for $ty {}
+        )*
+    };
+}
+
+forward_as_is!(
+    u32,
+    u64,
+    i32,
+    f32,
+    usize,
+    VkDebugReportObjectTypeEXT,
+    VkDebugUtilsMessageSeverityFlagBitsEXT,
+    VkFilter,
+    VkFormat,
+    VkImageLayout,
+    VkImageTiling,
+    VkImageType,
+    VkIndexType,
+    VkPipelineBindPoint,
+    VkPipelineStageFlagBits,
+    VkSampleCountFlagBits,
+    VkSubpassContents,
+    VkSurfaceCounterFlagBitsEXT,
+);
+
+/// Defines entry points called by the application, which are captured
+/// when the capture is enabled.
+///
+/// The parameters are written before the call, and the ones it wrote
+/// along with its result after.
+macro_rules! captured {
+    ($(
+        $(#[$attr:meta])*
+        pub unsafe extern "C" fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $body:block
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub unsafe extern "C" fn $name($($arg: $ty),*) $(-> $ret)? {
+                #[inline(always)]
+                unsafe extern "C" fn $name($($arg: $ty),*) $(-> $ret)? $body
+
+                let mut capture = capture::Call::begin(stringify!($name));
+                if let Some(call) = &mut capture {
+                    $(call.arg(stringify!($arg), &$arg);)*
+                    call.write();
+                }
+                let result = $name($($arg),*);
+                if let Some(mut call) = capture {
+                    $(call.output(stringify!($arg), &$arg);)*
+                    call.finish(&result);
+                }
+                result
+            }
+        )*
+    };
+}
+
+/// Defines a function replaying one of the given commands from a trace,
+/// returning false if the command is not one of them.
+macro_rules! replay_commands {
+    ($vis:vis fn $func:ident; $(fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?;)*) => {
+        $vis unsafe fn $func(name: &str, player: &mut crate::replay::Player) -> bool {
+            match name {
+                $(
+                    stringify!($name) => {
+                        $(let $arg = <$ty as crate::trace::Traced>::read(player.reader());)*
+                        if !player.is_complete() {
+                            return true;
+                        }
+                        let result = $name($($arg),*);
+                        player.finish(result);
+                        true
+                    }
+                )*
+                _ => false,
+            }
+        }
+    };
+}
+
+/// Defines the entry points that only need to be routed to the backend
+/// owning their first parameter.
+macro_rules! entry_points {
+    ($(fn $name:ident($key:ident: $key_ty:ty $(, $arg:ident: $ty:ty)* $(,)?) $(-> $ret:ty)?;)*) => {
+        captured! {
+            $(
+                #[inline]
+                pub unsafe extern "C" fn $name($key: $key_ty $(, $arg: $ty)*) $(-> $ret)? {
+                    forward!(
+                        $key.owner(),
+                        $name($key: $key_ty $(, $arg: $ty)*) $(-> $ret)?
+                    )
+                }
+            )*
+        }
+
+        replay_commands! {
+            pub(crate) fn replay;
+            $(fn $name($key: $key_ty $(, $arg: $ty)*) $(-> $ret)?;)*
+        }
+    };
+}
+
+entry_points! {
+    fn gfxGetPhysicalDeviceQueueFamilyProperties(
+        adapter: VkPhysicalDevice,
+        pQueueFamilyPropertyCount: *mut u32,
+        pQueueFamilyProperties: *mut VkQueueFamilyProperties,
+    );
+    fn gfxGetPhysicalDeviceQueueFamilyProperties2KHR(
+        adapter: VkPhysicalDevice,
+        pQueueFamilyPropertyCount: *mut u32,
+        pQueueFamilyProperties: *mut VkQueueFamilyProperties2KHR,
+    );
+    fn gfxGetPhysicalDeviceFeatures(
+        adapter: VkPhysicalDevice,
+        pFeatures: *mut VkPhysicalDeviceFeatures,
+    );
+    fn gfxGetPhysicalDeviceFeatures2KHR(
+        adapter: VkPhysicalDevice,
+        pFeatures: *mut VkPhysicalDeviceFeatures2KHR,
+    );
+    fn gfxGetPhysicalDeviceFormatProperties(
+        adapter: VkPhysicalDevice,
+        format: VkFormat,
+        pFormatProperties: *mut VkFormatProperties,
+    );
+    fn gfxGetPhysicalDeviceFormatProperties2KHR(
+        adapter: VkPhysicalDevice,
+        format: VkFormat,
+        pFormatProperties: *mut VkFormatProperties2KHR,
+    );
+    fn gfxGetPhysicalDeviceImageFormatProperties(
+        adapter: VkPhysicalDevice,
+        format: VkFormat,
+        type_: VkImageType,
+        tiling: VkImageTiling,
+        usage: VkImageUsageFlags,
+        flags: VkImageCreateFlags,
+        pImageFormatProperties: *mut VkImageFormatProperties,
+    ) -> VkResult;
+    fn gfxGetPhysicalDeviceImageFormatProperties2KHR(
+        adapter: VkPhysicalDevice,
+        pImageFormatInfo: *const VkPhysicalDeviceImageFormatInfo2KHR,
+        pImageFormatProperties: *mut VkImageFormatProperties2KHR,
+    ) -> VkResult;
+    fn gfxGetPhysicalDeviceMemoryProperties(
+        adapter: VkPhysicalDevice,
+        pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties,
+    );
+    fn gfxGetPhysicalDeviceMemoryProperties2KHR(
+        adapter: VkPhysicalDevice,
+        pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties2KHR,
+    );
+    fn gfxQueueSubmit(
+        queue: VkQueue,
+        submitCount: u32,
+        pSubmits: *const VkSubmitInfo,
+        fence: VkFence,
+    ) -> VkResult;
+    fn gfxQueueWaitIdle(queue: VkQueue) -> VkResult;
+    fn gfxDeviceWaitIdle(gpu: VkDevice) -> VkResult;
+    fn gfxAllocateMemory(
+        gpu: VkDevice,
+        pAllocateInfo: *const VkMemoryAllocateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pMemory: *mut VkDeviceMemory,
+    ) -> VkResult;
+    fn gfxFreeMemory(
+        gpu: VkDevice,
+        memory: VkDeviceMemory,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxMapMemory(
+        gpu: VkDevice,
+        memory: VkDeviceMemory,
+        offset: VkDeviceSize,
+        size: VkDeviceSize,
+        flags: VkMemoryMapFlags,
+        ppData: *mut *mut c_void,
+    ) -> VkResult;
+    fn gfxUnmapMemory(gpu: VkDevice, memory: VkDeviceMemory);
+    fn gfxFlushMappedMemoryRanges(
+        gpu: VkDevice,
+        memoryRangeCount: u32,
+        pMemoryRanges: *const VkMappedMemoryRange,
+    ) -> VkResult;
+    fn gfxInvalidateMappedMemoryRanges(
+        gpu: VkDevice,
+        memoryRangeCount: u32,
+        pMemoryRanges: *const VkMappedMemoryRange,
+    ) -> VkResult;
+    fn gfxGetDeviceMemoryCommitment(
+        device: VkDevice,
+        memory: VkDeviceMemory,
+        pCommittedMemoryInBytes: *mut VkDeviceSize,
+    );
+    fn gfxBindBufferMemory(
+        gpu: VkDevice,
+        buffer: VkBuffer,
+        memory: VkDeviceMemory,
+        memoryOffset: VkDeviceSize,
+    ) -> VkResult;
+    fn gfxBindImageMemory(
+        gpu: VkDevice,
+        image: VkImage,
+        memory: VkDeviceMemory,
+        memoryOffset: VkDeviceSize,
+    ) -> VkResult;
+    fn gfxBindBufferMemory2KHR(
+        gpu: VkDevice,
+        bindInfoCount: u32,
+        pBindInfos: *const VkBindBufferMemoryInfoKHR,
+    ) -> VkResult;
+    fn gfxBindImageMemory2KHR(
+        gpu: VkDevice,
+        bindInfoCount: u32,
+        pBindInfos: *const VkBindImageMemoryInfoKHR,
+    ) -> VkResult;
+    fn gfxGetDeviceGroupPeerMemoryFeatures(
+        gpu: VkDevice,
+        heapIndex: u32,
+        localDeviceIndex: u32,
+        remoteDeviceIndex: u32,
+        pPeerMemoryFeatures: *mut VkPeerMemoryFeatureFlagsKHX,
+    );
+    fn gfxGetBufferMemoryRequirements(
+        gpu: VkDevice,
+        buffer: VkBuffer,
+        pMemoryRequirements: *mut VkMemoryRequirements,
+    );
+    fn gfxGetImageMemoryRequirements(
+        gpu: VkDevice,
+        image: VkImage,
+        pMemoryRequirements: *mut VkMemoryRequirements,
+    );
+    fn gfxGetBufferMemoryRequirements2KHR(
+        gpu: VkDevice,
+        pInfo: *const VkBufferMemoryRequirementsInfo2KHR,
+        pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+    );
+    fn gfxGetImageMemoryRequirements2KHR(
+        gpu: VkDevice,
+        pInfo: *const VkImageMemoryRequirementsInfo2KHR,
+        pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+    );
+    fn gfxGetImageSparseMemoryRequirements2KHR(
+        gpu: VkDevice,
+        pInfo: *const VkImageSparseMemoryRequirementsInfo2KHR,
+        pSparseMemoryRequirementCount: *mut u32,
+        pSparseMemoryRequirements: *mut VkSparseImageMemoryRequirements2KHR,
+    );
+    fn gfxGetImageSparseMemoryRequirements(
+        device: VkDevice,
+        image: VkImage,
+        pSparseMemoryRequirementCount: *mut u32,
+        pSparseMemoryRequirements: *mut VkSparseImageMemoryRequirements,
+    );
+    fn gfxGetPhysicalDeviceSparseImageFormatProperties(
+        physicalDevice: VkPhysicalDevice,
+        format: VkFormat,
+        type_: VkImageType,
+        samples: VkSampleCountFlagBits,
+        usage: VkImageUsageFlags,
+        tiling: VkImageTiling,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkSparseImageFormatProperties,
+    );
+    fn gfxGetPhysicalDeviceSparseImageFormatProperties2KHR(
+        physicalDevice: VkPhysicalDevice,
+        pFormatInfo: *const VkPhysicalDeviceSparseImageFormatInfo2KHR,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkSparseImageFormatProperties2KHR,
+    );
+    fn gfxGetPhysicalDeviceExternalBufferProperties(
+        physicalDevice: VkPhysicalDevice,
+        pExternalBufferInfo: *const VkPhysicalDeviceExternalBufferInfoKHX,
+        pExternalBufferProperties: *mut VkExternalBufferPropertiesKHX,
+    );
+    fn gfxGetPhysicalDeviceExternalFenceProperties(
+        physicalDevice: VkPhysicalDevice,
+        pExternalFenceInfo: *const VkPhysicalDeviceExternalFenceInfo,
+        pExternalFenceProperties: *mut VkExternalFenceProperties,
+    );
+    fn gfxGetPhysicalDeviceExternalSemaphoreProperties(
+        physicalDevice: VkPhysicalDevice,
+        pExternalSemaphoreInfo: *const VkPhysicalDeviceExternalSemaphoreInfoKHX,
+        pExternalSemaphoreProperties: *mut VkExternalSemaphorePropertiesKHX,
+    );
+    fn gfxQueueBindSparse(
+        queue: VkQueue,
+        bindInfoCount: u32,
+        pBindInfo: *const VkBindSparseInfo,
+        fence: VkFence,
+    ) -> VkResult;
+    fn gfxCreateFence(
+        gpu: VkDevice,
+        pCreateInfo: *const VkFenceCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pFence: *mut VkFence,
+    ) -> VkResult;
+    fn gfxDestroyFence(gpu: VkDevice, fence: VkFence, pAllocator: *const VkAllocationCallbacks);
+    fn gfxResetFences(gpu: VkDevice, fenceCount: u32, pFences: *const VkFence) -> VkResult;
+    fn gfxGetFenceStatus(gpu: VkDevice, fence: VkFence) -> VkResult;
+    fn gfxWaitForFences(
+        gpu: VkDevice,
+        fenceCount: u32,
+        pFences: *const VkFence,
+        waitAll: VkBool32,
+        timeout: u64,
+    ) -> VkResult;
+    fn gfxCreateSemaphore(
+        gpu: VkDevice,
+        pCreateInfo: *const VkSemaphoreCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pSemaphore: *mut VkSemaphore,
+    ) -> VkResult;
+    fn gfxDestroySemaphore(
+        gpu: VkDevice,
+        semaphore: VkSemaphore,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxCreateEvent(
+        gpu: VkDevice,
+        pCreateInfo: *const VkEventCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pEvent: *mut VkEvent,
+    ) -> VkResult;
+    fn gfxDestroyEvent(gpu: VkDevice, event: VkEvent, pAllocator: *const VkAllocationCallbacks);
+    fn gfxGetEventStatus(gpu: VkDevice, event: VkEvent) -> VkResult;
+    fn gfxSetEvent(gpu: VkDevice, event: VkEvent) -> VkResult;
+    fn gfxResetEvent(gpu: VkDevice, event: VkEvent) -> VkResult;
+    fn gfxCreateQueryPool(
+        gpu: VkDevice,
+        pCreateInfo: *const VkQueryPoolCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pQueryPool: *mut VkQueryPool,
+    ) -> VkResult;
+    fn gfxDestroyQueryPool(
+        gpu: VkDevice,
+        queryPool: VkQueryPool,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxGetQueryPoolResults(
+        gpu: VkDevice,
+        queryPool: VkQueryPool,
+        firstQuery: u32,
+        queryCount: u32,
+        dataSize: usize,
+        pData: *mut c_void,
+        stride: VkDeviceSize,
+        flags: VkQueryResultFlags,
+    ) -> VkResult;
+    fn gfxCreateBuffer(
+        gpu: VkDevice,
+        pCreateInfo: *const VkBufferCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pBuffer: *mut VkBuffer,
+    ) -> VkResult;
+    fn gfxDestroyBuffer(gpu: VkDevice, buffer: VkBuffer, pAllocator: *const VkAllocationCallbacks);
+    fn gfxCreateBufferView(
+        gpu: VkDevice,
+        pCreateInfo: *const VkBufferViewCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pView: *mut VkBufferView,
+    ) -> VkResult;
+    fn gfxDestroyBufferView(
+        gpu: VkDevice,
+        view: VkBufferView,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxCreateImage(
+        gpu: VkDevice,
+        pCreateInfo: *const VkImageCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pImage: *mut VkImage,
+    ) -> VkResult;
+    fn gfxDestroyImage(gpu: VkDevice, image: VkImage, pAllocator: *const VkAllocationCallbacks);
+    fn gfxGetImageSubresourceLayout(
+        gpu: VkDevice,
+        image: VkImage,
+        pSubresource: *const VkImageSubresource,
+        pLayout: *mut VkSubresourceLayout,
+    );
+    fn gfxCreateImageView(
+        gpu: VkDevice,
+        pCreateInfo: *const VkImageViewCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pView: *mut VkImageView,
+    ) -> VkResult;
+    fn gfxDestroyImageView(
+        gpu: VkDevice,
+        imageView: VkImageView,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxCreateShaderModule(
+        gpu: VkDevice,
+        pCreateInfo: *const VkShaderModuleCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pShaderModule: *mut VkShaderModule,
+    ) -> VkResult;
+    fn gfxDestroyShaderModule(
+        gpu: VkDevice,
+        shaderModule: VkShaderModule,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxCreatePipelineCache(
+        gpu: VkDevice,
+        pCreateInfo: *const VkPipelineCacheCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pPipelineCache: *mut VkPipelineCache,
+    ) -> VkResult;
+    fn gfxDestroyPipelineCache(
+        gpu: VkDevice,
+        pipelineCache: VkPipelineCache,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxGetPipelineCacheData(
+        gpu: VkDevice,
+        pipelineCache: VkPipelineCache,
+        pDataSize: *mut usize,
+        pData: *mut c_void,
+    ) -> VkResult;
+    fn gfxMergePipelineCaches(
+        gpu: VkDevice,
+        dstCache: VkPipelineCache,
+        srcCacheCount: u32,
+        pSrcCaches: *const VkPipelineCache,
+    ) -> VkResult;
+    fn gfxCreateGraphicsPipelines(
+        gpu: VkDevice,
+        pipelineCache: VkPipelineCache,
+        createInfoCount: u32,
+        pCreateInfos: *const VkGraphicsPipelineCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pPipelines: *mut VkPipeline,
+    ) -> VkResult;
+    fn gfxCreateComputePipelines(
+        gpu: VkDevice,
+        pipelineCache: VkPipelineCache,
+        createInfoCount: u32,
+        pCreateInfos: *const VkComputePipelineCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pPipelines: *mut VkPipeline,
+    ) -> VkResult;
+    fn gfxDestroyPipeline(
+        gpu: VkDevice,
+        pipeline: VkPipeline,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxCreatePipelineLayout(
+        gpu: VkDevice,
+        pCreateInfo: *const VkPipelineLayoutCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pPipelineLayout: *mut VkPipelineLayout,
+    ) -> VkResult;
+    fn gfxDestroyPipelineLayout(
+        gpu: VkDevice,
+        pipelineLayout: VkPipelineLayout,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxCreateSampler(
+        gpu: VkDevice,
+        pCreateInfo: *const VkSamplerCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pSampler: *mut VkSampler,
+    ) -> VkResult;
+    fn gfxDestroySampler(
+        gpu: VkDevice,
+        sampler: VkSampler,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxCreateDescriptorSetLayout(
+        gpu: VkDevice,
+        pCreateInfo: *const VkDescriptorSetLayoutCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pSetLayout: *mut VkDescriptorSetLayout,
+    ) -> VkResult;
+    fn gfxDestroyDescriptorSetLayout(
+        gpu: VkDevice,
+        descriptorSetLayout: VkDescriptorSetLayout,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxGetDescriptorSetLayoutSupport(
+        gpu: VkDevice,
+        pCreateInfo: *const VkDescriptorSetLayoutCreateInfo,
+        pSupport: *mut VkDescriptorSetLayoutSupport,
+    );
+    fn gfxCreateDescriptorPool(
+        gpu: VkDevice,
+        pCreateInfo: *const VkDescriptorPoolCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pDescriptorPool: *mut VkDescriptorPool,
+    ) -> VkResult;
+    fn gfxDestroyDescriptorPool(
+        gpu: VkDevice,
+        descriptorPool: VkDescriptorPool,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxResetDescriptorPool(
+        gpu: VkDevice,
+        descriptorPool: VkDescriptorPool,
+        flags: VkDescriptorPoolResetFlags,
+    ) -> VkResult;
+    fn gfxAllocateDescriptorSets(
+        gpu: VkDevice,
+        pAllocateInfo: *const VkDescriptorSetAllocateInfo,
+        pDescriptorSets: *mut VkDescriptorSet,
+    ) -> VkResult;
+    fn gfxFreeDescriptorSets(
+        device: VkDevice,
+        descriptorPool: VkDescriptorPool,
+        descriptorSetCount: u32,
+        pDescriptorSets: *const VkDescriptorSet,
+    ) -> VkResult;
+    fn gfxUpdateDescriptorSets(
+        gpu: VkDevice,
+        descriptorWriteCount: u32,
+        pDescriptorWrites: *const VkWriteDescriptorSet,
+        descriptorCopyCount: u32,
+        pDescriptorCopies: *const VkCopyDescriptorSet,
+    );
+    fn gfxCreateDescriptorUpdateTemplate(
+        gpu: VkDevice,
+        pCreateInfo: *const VkDescriptorUpdateTemplateCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pDescriptorUpdateTemplate: *mut VkDescriptorUpdateTemplateKHR,
+    ) -> VkResult;
+    fn gfxDestroyDescriptorUpdateTemplate(
+        gpu: VkDevice,
+        descriptorUpdateTemplate: VkDescriptorUpdateTemplateKHR,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxUpdateDescriptorSetWithTemplate(
+        gpu: VkDevice,
+        descriptorSet: VkDescriptorSet,
+        descriptorUpdateTemplate: VkDescriptorUpdateTemplateKHR,
+        pData: *const c_void,
+    );
+    fn gfxCreateFramebuffer(
+        gpu: VkDevice,
+        pCreateInfo: *const VkFramebufferCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pFramebuffer: *mut VkFramebuffer,
+    ) -> VkResult;
+    fn gfxDestroyFramebuffer(
+        gpu: VkDevice,
+        framebuffer: VkFramebuffer,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxCreateRenderPass(
+        gpu: VkDevice,
+        pCreateInfo: *const VkRenderPassCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pRenderPass: *mut VkRenderPass,
+    ) -> VkResult;
+    fn gfxDestroyRenderPass(
+        gpu: VkDevice,
+        renderPass: VkRenderPass,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxGetRenderAreaGranularity(
+        gpu: VkDevice,
+        renderPass: VkRenderPass,
+        pGranularity: *mut VkExtent2D,
+    );
+    fn gfxCreateCommandPool(
+        gpu: VkDevice,
+        pCreateInfo: *const VkCommandPoolCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pCommandPool: *mut VkCommandPool,
+    ) -> VkResult;
+    fn gfxDestroyCommandPool(
+        gpu: VkDevice,
+        commandPool: VkCommandPool,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxResetCommandPool(
+        gpu: VkDevice,
+        commandPool: VkCommandPool,
+        flags: VkCommandPoolResetFlags,
+    ) -> VkResult;
+    fn gfxTrimCommandPoolKHR(
+        gpu: VkDevice,
+        commandPool: VkCommandPool,
+        flags: VkCommandPoolTrimFlagsKHR,
+    );
+    fn gfxBeginCommandBuffer(
+        commandBuffer: VkCommandBuffer,
+        pBeginInfo: *const VkCommandBufferBeginInfo,
+    ) -> VkResult;
+    fn gfxEndCommandBuffer(commandBuffer: VkCommandBuffer) -> VkResult;
+    fn gfxResetCommandBuffer(
+        commandBuffer: VkCommandBuffer,
+        flags: VkCommandBufferResetFlags,
+    ) -> VkResult;
+    fn gfxCmdBindPipeline(
+        commandBuffer: VkCommandBuffer,
+        pipelineBindPoint: VkPipelineBindPoint,
+        pipeline: VkPipeline,
+    );
+    fn gfxCmdSetViewport(
+        commandBuffer: VkCommandBuffer,
+        firstViewport: u32,
+        viewportCount: u32,
+        pViewports: *const VkViewport,
+    );
+    fn gfxCmdSetScissor(
+        commandBuffer: VkCommandBuffer,
+        firstScissor: u32,
+        scissorCount: u32,
+        pScissors: *const VkRect2D,
+    );
+    fn gfxCmdSetLineWidth(commandBuffer: VkCommandBuffer, lineWidth: f32);
+    fn gfxCmdSetDepthBias(
+        commandBuffer: VkCommandBuffer,
+        depthBiasConstantFactor: f32,
+        depthBiasClamp: f32,
+        depthBiasSlopeFactor: f32,
+    );
+    fn gfxCmdSetBlendConstants(commandBuffer: VkCommandBuffer, blendConstants: *const f32);
+    fn gfxCmdSetDepthBounds(
+        commandBuffer: VkCommandBuffer,
+        minDepthBounds: f32,
+        maxDepthBounds: f32,
+    );
+    fn gfxCmdSetStencilCompareMask(
+        commandBuffer: VkCommandBuffer,
+        faceMask: VkStencilFaceFlags,
+        compareMask: u32,
+    );
+    fn gfxCmdSetStencilWriteMask(
+        commandBuffer: VkCommandBuffer,
+        faceMask: VkStencilFaceFlags,
+        writeMask: u32,
+    );
+    fn gfxCmdSetStencilReference(
+        commandBuffer: VkCommandBuffer,
+        faceMask: VkStencilFaceFlags,
+        reference: u32,
+    );
+    fn gfxCmdBindDescriptorSets(
+        commandBuffer: VkCommandBuffer,
+        pipelineBindPoint: VkPipelineBindPoint,
+        layout: VkPipelineLayout,
+        firstSet: u32,
+        descriptorSetCount: u32,
+        pDescriptorSets: *const VkDescriptorSet,
+        dynamicOffsetCount: u32,
+        pDynamicOffsets: *const u32,
+    );
+    fn gfxCmdBindIndexBuffer(
+        commandBuffer: VkCommandBuffer,
+        buffer: VkBuffer,
+        offset: VkDeviceSize,
+        indexType: VkIndexType,
+    );
+    fn gfxCmdBindVertexBuffers(
+        commandBuffer: VkCommandBuffer,
+        firstBinding: u32,
+        bindingCount: u32,
+        pBuffers: *const VkBuffer,
+        pOffsets: *const VkDeviceSize,
+    );
+    fn gfxCmdDraw(
+        commandBuffer: VkCommandBuffer,
+        vertexCount: u32,
+        instanceCount: u32,
+        firstVertex: u32,
+        firstInstance: u32,
+    );
+    fn gfxCmdDrawIndexed(
+        commandBuffer: VkCommandBuffer,
+        indexCount: u32,
+        instanceCount: u32,
+        firstIndex: u32,
+        vertexOffset: i32,
+        firstInstance: u32,
+    );
+    fn gfxCmdDrawIndirect(
+        commandBuffer: VkCommandBuffer,
+        buffer: VkBuffer,
+        offset: VkDeviceSize,
+        drawCount: u32,
+        stride: u32,
+    );
+    fn gfxCmdDrawIndexedIndirect(
+        commandBuffer: VkCommandBuffer,
+        buffer: VkBuffer,
+        offset: VkDeviceSize,
+        drawCount: u32,
+        stride: u32,
+    );
+    fn gfxCmdDispatch(
+        commandBuffer: VkCommandBuffer,
+        groupCountX: u32,
+        groupCountY: u32,
+        groupCountZ: u32,
+    );
+    fn gfxCmdDispatchBase(
+        commandBuffer: VkCommandBuffer,
+        baseGroupX: u32,
+        baseGroupY: u32,
+        baseGroupZ: u32,
+        groupCountX: u32,
+        groupCountY: u32,
+        groupCountZ: u32,
+    );
+    fn gfxCmdSetDeviceMask(commandBuffer: VkCommandBuffer, deviceMask: u32);
+    fn gfxCmdDispatchIndirect(
+        commandBuffer: VkCommandBuffer,
+        buffer: VkBuffer,
+        offset: VkDeviceSize,
+    );
+    fn gfxCmdCopyBuffer(
+        commandBuffer: VkCommandBuffer,
+        srcBuffer: VkBuffer,
+        dstBuffer: VkBuffer,
+        regionCount: u32,
+        pRegions: *const VkBufferCopy,
+    );
+    fn gfxCmdCopyImage(
+        commandBuffer: VkCommandBuffer,
+        srcImage: VkImage,
+        srcImageLayout: VkImageLayout,
+        dstImage: VkImage,
+        dstImageLayout: VkImageLayout,
+        regionCount: u32,
+        pRegions: *const VkImageCopy,
+    );
+    fn gfxCmdBlitImage(
+        commandBuffer: VkCommandBuffer,
+        srcImage: VkImage,
+        srcImageLayout: VkImageLayout,
+        dstImage: VkImage,
+        dstImageLayout: VkImageLayout,
+        regionCount: u32,
+        pRegions: *const VkImageBlit,
+        filter: VkFilter,
+    );
+    fn gfxCmdCopyBufferToImage(
+        commandBuffer: VkCommandBuffer,
+        srcBuffer: VkBuffer,
+        dstImage: VkImage,
+        dstImageLayout: VkImageLayout,
+        regionCount: u32,
+        pRegions: *const VkBufferImageCopy,
+    );
+    fn gfxCmdCopyImageToBuffer(
+        commandBuffer: VkCommandBuffer,
+        srcImage: VkImage,
+        srcImageLayout: VkImageLayout,
+        dstBuffer: VkBuffer,
+        regionCount: u32,
+        pRegions: *const VkBufferImageCopy,
+    );
+    fn gfxCmdUpdateBuffer(
+        commandBuffer: VkCommandBuffer,
+        dstBuffer: VkBuffer,
+        dstOffset: VkDeviceSize,
+        dataSize: VkDeviceSize,
+        pData: *const c_void,
+    );
+    fn gfxCmdFillBuffer(
+        commandBuffer: VkCommandBuffer,
+        dstBuffer: VkBuffer,
+        dstOffset: VkDeviceSize,
+        size: VkDeviceSize,
+        data: u32,
+    );
+    fn gfxCmdClearColorImage(
+        commandBuffer: VkCommandBuffer,
+        image: VkImage,
+        imageLayout: VkImageLayout,
+        pColor: *const VkClearColorValue,
+        rangeCount: u32,
+        pRanges: *const VkImageSubresourceRange,
+    );
+    fn gfxCmdClearDepthStencilImage(
+        commandBuffer: VkCommandBuffer,
+        image: VkImage,
+        imageLayout: VkImageLayout,
+        pDepthStencil: *const VkClearDepthStencilValue,
+        rangeCount: u32,
+        pRanges: *const VkImageSubresourceRange,
+    );
+    fn gfxCmdClearAttachments(
+        commandBuffer: VkCommandBuffer,
+        attachmentCount: u32,
+        pAttachments: *const VkClearAttachment,
+        rectCount: u32,
+        pRects: *const VkClearRect,
+    );
+    fn gfxCmdResolveImage(
+        commandBuffer: VkCommandBuffer,
+        srcImage: VkImage,
+        srcImageLayout: VkImageLayout,
+        dstImage: VkImage,
+        dstImageLayout: VkImageLayout,
+        regionCount: u32,
+        pRegions: *const VkImageResolve,
+    );
+    fn gfxCmdSetEvent(
+        commandBuffer: VkCommandBuffer,
+        event: VkEvent,
+        stageMask: VkPipelineStageFlags,
+    );
+    fn gfxCmdResetEvent(
+        commandBuffer: VkCommandBuffer,
+        event: VkEvent,
+        stageMask: VkPipelineStageFlags,
+    );
+    fn gfxCmdWaitEvents(
+        commandBuffer: VkCommandBuffer,
+        eventCount: u32,
+        pEvents: *const VkEvent,
+        srcStageMask: VkPipelineStageFlags,
+        dstStageMask: VkPipelineStageFlags,
+        memoryBarrierCount: u32,
+        pMemoryBarriers: *const VkMemoryBarrier,
+        bufferMemoryBarrierCount: u32,
+        pBufferMemoryBarriers: *const VkBufferMemoryBarrier,
+        imageMemoryBarrierCount: u32,
+        pImageMemoryBarriers: *const VkImageMemoryBarrier,
+    );
+    fn gfxCmdPipelineBarrier(
+        commandBuffer: VkCommandBuffer,
+        srcStageMask: VkPipelineStageFlags,
+        dstStageMask: VkPipelineStageFlags,
+        dependencyFlags: VkDependencyFlags,
+        memoryBarrierCount: u32,
+        pMemoryBarriers: *const VkMemoryBarrier,
+        bufferMemoryBarrierCount: u32,
+        pBufferMemoryBarriers: *const VkBufferMemoryBarrier,
+        imageMemoryBarrierCount: u32,
+        pImageMemoryBarriers: *const VkImageMemoryBarrier,
+    );
+    fn gfxCmdBeginQuery(
+        commandBuffer: VkCommandBuffer,
+        queryPool: VkQueryPool,
+        query: u32,
+        flags: VkQueryControlFlags,
+    );
+    fn gfxCmdEndQuery(commandBuffer: VkCommandBuffer, queryPool: VkQueryPool, query: u32);
+    fn gfxCmdResetQueryPool(
+        commandBuffer: VkCommandBuffer,
+        queryPool: VkQueryPool,
+        firstQuery: u32,
+        queryCount: u32,
+    );
+    fn gfxCmdWriteTimestamp(
+        commandBuffer: VkCommandBuffer,
+        pipelineStage: VkPipelineStageFlagBits,
+        queryPool: VkQueryPool,
+        query: u32,
+    );
+    fn gfxCmdCopyQueryPoolResults(
+        commandBuffer: VkCommandBuffer,
+        queryPool: VkQueryPool,
+        firstQuery: u32,
+        queryCount: u32,
+        dstBuffer: VkBuffer,
+        dstOffset: VkDeviceSize,
+        stride: VkDeviceSize,
+        flags: VkQueryResultFlags,
+    );
+    fn gfxCmdPushConstants(
+        commandBuffer: VkCommandBuffer,
+        layout: VkPipelineLayout,
+        stageFlags: VkShaderStageFlags,
+        offset: u32,
+        size: u32,
+        pValues: *const c_void,
+    );
+    fn gfxCmdBeginRenderPass(
+        commandBuffer: VkCommandBuffer,
+        pRenderPassBegin: *const VkRenderPassBeginInfo,
+        contents: VkSubpassContents,
+    );
+    fn gfxCmdNextSubpass(commandBuffer: VkCommandBuffer, contents: VkSubpassContents);
+    fn gfxCmdEndRenderPass(commandBuffer: VkCommandBuffer);
+    fn gfxCmdExecuteCommands(
+        commandBuffer: VkCommandBuffer,
+        commandBufferCount: u32,
+        pCommandBuffers: *const VkCommandBuffer,
+    );
+    fn gfxGetPhysicalDeviceWin32PresentationSupportKHR(
+        adapter: VkPhysicalDevice,
+        queueFamilyIndex: u32,
+    ) -> VkBool32;
+    fn gfxGetPhysicalDeviceWaylandPresentationSupportKHR(
+        adapter: VkPhysicalDevice,
+        queueFamilyIndex: u32,
+        display: *mut c_void,
+    ) -> VkBool32;
+    fn gfxDestroySwapchainKHR(
+        gpu: VkDevice,
+        swapchain: VkSwapchainKHR,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxGetSwapchainImagesKHR(
+        gpu: VkDevice,
+        swapchain: VkSwapchainKHR,
+        pSwapchainImageCount: *mut u32,
+        pSwapchainImages: *mut VkImage,
+    ) -> VkResult;
+    fn gfxCmdProcessCommandsNVX(
+        commandBuffer: VkCommandBuffer,
+        pProcessCommandsInfo: *const VkCmdProcessCommandsInfoNVX,
+    );
+    fn gfxCmdReserveSpaceForCommandsNVX(
+        commandBuffer: VkCommandBuffer,
+        pReserveSpaceInfo: *const VkCmdReserveSpaceForCommandsInfoNVX,
+    );
+    fn gfxCreateIndirectCommandsLayoutNVX(
+        gpu: VkDevice,
+        pCreateInfo: *const VkIndirectCommandsLayoutCreateInfoNVX,
+        pAllocator: *const VkAllocationCallbacks,
+        pIndirectCommandsLayout: *mut VkIndirectCommandsLayoutNVX,
+    ) -> VkResult;
+    fn gfxDestroyIndirectCommandsLayoutNVX(
+        gpu: VkDevice,
+        indirectCommandsLayout: VkIndirectCommandsLayoutNVX,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxCreateObjectTableNVX(
+        gpu: VkDevice,
+        pCreateInfo: *const VkObjectTableCreateInfoNVX,
+        pAllocator: *const VkAllocationCallbacks,
+        pObjectTable: *mut VkObjectTableNVX,
+    ) -> VkResult;
+    fn gfxDestroyObjectTableNVX(
+        gpu: VkDevice,
+        objectTable: VkObjectTableNVX,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxRegisterObjectsNVX(
+        gpu: VkDevice,
+        objectTable: VkObjectTableNVX,
+        objectCount: u32,
+        ppObjectTableEntries: *const *const VkObjectTableEntryNVX,
+        pObjectIndices: *const u32,
+    ) -> VkResult;
+    fn gfxUnregisterObjectsNVX(
+        gpu: VkDevice,
+        objectTable: VkObjectTableNVX,
+        objectCount: u32,
+        pObjectEntryTypes: *const VkObjectEntryTypeNVX,
+        pObjectIndices: *const u32,
+    ) -> VkResult;
+    fn gfxGetPhysicalDeviceGeneratedCommandsPropertiesNVX(
+        physicalDevice: VkPhysicalDevice,
+        pFeatures: *mut VkDeviceGeneratedCommandsFeaturesNVX,
+        pLimits: *mut VkDeviceGeneratedCommandsLimitsNVX,
+    );
+    fn gfxCmdSetViewportWScalingNV(
+        commandBuffer: VkCommandBuffer,
+        firstViewport: u32,
+        viewportCount: u32,
+        pViewportWScalings: *const VkViewportWScalingNV,
+    );
+    fn gfxReleaseDisplayEXT(physicalDevice: VkPhysicalDevice, display: VkDisplayKHR) -> VkResult;
+    fn gfxDisplayPowerControlEXT(
+        gpu: VkDevice,
+        display: VkDisplayKHR,
+        pDisplayPowerInfo: *const VkDisplayPowerInfoEXT,
+    ) -> VkResult;
+    fn gfxRegisterDeviceEventEXT(
+        gpu: VkDevice,
+        pDeviceEventInfo: *const VkDeviceEventInfoEXT,
+        pAllocator: *const VkAllocationCallbacks,
+        pFence: *mut VkFence,
+    ) -> VkResult;
+    fn gfxRegisterDisplayEventEXT(
+        gpu: VkDevice,
+        display: VkDisplayKHR,
+        pDisplayEventInfo: *const VkDisplayEventInfoEXT,
+        pAllocator: *const VkAllocationCallbacks,
+        pFence: *mut VkFence,
+    ) -> VkResult;
+    fn gfxGetSwapchainCounterEXT(
+        gpu: VkDevice,
+        swapchain: VkSwapchainKHR,
+        counter: VkSurfaceCounterFlagBitsEXT,
+        pCounterValue: *mut u64,
+    ) -> VkResult;
+    fn gfxCmdSetDiscardRectangleEXT(
+        commandBuffer: VkCommandBuffer,
+        firstDiscardRectangle: u32,
+        discardRectangleCount: u32,
+        pDiscardRectangles: *const VkRect2D,
+    );
+    fn gfxAcquireNextImageKHR(
+        gpu: VkDevice,
+        swapchain: VkSwapchainKHR,
+        timeout: u64,
+        semaphore: VkSemaphore,
+        fence: VkFence,
+        pImageIndex: *mut u32,
+    ) -> VkResult;
+    fn gfxQueuePresentKHR(queue: VkQueue, pPresentInfo: *const VkPresentInfoKHR) -> VkResult;
+    fn gfxDebugMarkerSetObjectTagEXT(
+        gpu: VkDevice,
+        pTagInfo: *mut VkDebugMarkerObjectTagInfoEXT,
+    ) -> VkResult;
+    fn gfxDebugMarkerSetObjectNameEXT(
+        gpu: VkDevice,
+        pNameInfo: *mut VkDebugMarkerObjectNameInfoEXT,
+    ) -> VkResult;
+    fn gfxCmdDebugMarkerBeginEXT(
+        commandBuffer: VkCommandBuffer,
+        pMarkerInfo: *mut VkDebugMarkerMarkerInfoEXT,
+    );
+    fn gfxCmdDebugMarkerEndEXT(commandBuffer: VkCommandBuffer);
+    fn gfxCmdDebugMarkerInsertEXT(
+        commandBuffer: VkCommandBuffer,
+        pMarkerInfo: *mut VkDebugMarkerMarkerInfoEXT,
+    );
+    fn gfxCreateDebugUtilsMessengerEXT(
+        instance: VkInstance,
+        pCreateInfo: *const VkDebugUtilsMessengerCreateInfoEXT,
+        pAllocator: *const VkAllocationCallbacks,
+        pMessenger: *mut VkDebugUtilsMessengerEXT,
+    ) -> VkResult;
+    fn gfxDestroyDebugUtilsMessengerEXT(
+        instance: VkInstance,
+        messenger: VkDebugUtilsMessengerEXT,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxSubmitDebugUtilsMessageEXT(
+        instance: VkInstance,
+        messageSeverity: VkDebugUtilsMessageSeverityFlagBitsEXT,
+        messageTypes: VkDebugUtilsMessageTypeFlagsEXT,
+        pCallbackData: *const VkDebugUtilsMessengerCallbackDataEXT,
+    );
+    fn gfxCreateDebugReportCallbackEXT(
+        instance: VkInstance,
+        pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT,
+        pAllocator: *const VkAllocationCallbacks,
+        pCallback: *mut VkDebugReportCallbackEXT,
+    ) -> VkResult;
+    fn gfxDestroyDebugReportCallbackEXT(
+        instance: VkInstance,
+        callback: VkDebugReportCallbackEXT,
+        pAllocator: *const VkAllocationCallbacks,
+    );
+    fn gfxDebugReportMessageEXT(
+        instance: VkInstance,
+        flags: VkDebugReportFlagsEXT,
+        objectType: VkDebugReportObjectTypeEXT,
+        object: u64,
+        location: usize,
+        messageCode: i32,
+        pLayerPrefix: *const ::std::os::raw::c_char,
+        pMessage: *const ::std::os::raw::c_char,
+    );
+    fn gfxSetDebugUtilsObjectNameEXT(
+        gpu: VkDevice,
+        pNameInfo: *const VkDebugUtilsObjectNameInfoEXT,
+    ) -> VkResult;
+    fn gfxSetDebugUtilsObjectTagEXT(
+        gpu: VkDevice,
+        pTagInfo: *const VkDebugUtilsObjectTagInfoEXT,
+    ) -> VkResult;
+    fn gfxQueueBeginDebugUtilsLabelEXT(queue: VkQueue, pLabelInfo: *const VkDebugUtilsLabelEXT);
+    fn gfxQueueEndDebugUtilsLabelEXT(queue: VkQueue);
+    fn gfxQueueInsertDebugUtilsLabelEXT(queue: VkQueue, pLabelInfo: *const VkDebugUtilsLabelEXT);
+    fn gfxCmdBeginDebugUtilsLabelEXT(
+        commandBuffer: VkCommandBuffer,
+        pLabelInfo: *const VkDebugUtilsLabelEXT,
+    );
+    fn gfxCmdEndDebugUtilsLabelEXT(commandBuffer: VkCommandBuffer);
+    fn gfxCmdInsertDebugUtilsLabelEXT(
+        commandBuffer: VkCommandBuffer,
+        pLabelInfo: *const VkDebugUtilsLabelEXT,
+    );
+    fn gfxDestroyDevice(gpu: VkDevice, pAllocator: *const VkAllocationCallbacks);
+    fn gfxAllocateCommandBuffers(
+        gpu: VkDevice,
+        pAllocateInfo: *const VkCommandBufferAllocateInfo,
+        pCommandBuffers: *mut VkCommandBuffer,
+    ) -> VkResult;
+    fn gfxFreeCommandBuffers(
+        gpu: VkDevice,
+        commandPool: VkCommandPool,
+        commandBufferCount: u32,
+        pCommandBuffers: *const VkCommandBuffer,
+    );
+}
+
+/// Defines the entry points creating surfaces, which create one
+/// with every instance of a group.
+macro_rules! surface_constructors {
+    ($(fn $name:ident(pCreateInfo: *const $info:ty);)*) => {
+        captured! {
+            $(
+                #[inline]
+                pub unsafe extern "C" fn $name(
+                    instance: VkInstance,
+                    pCreateInfo: *const $info,
+                    pAllocator: *const VkAllocationCallbacks,
+                    pSurface: *mut VkSurfaceKHR,
+                ) -> VkResult {
+                    let instances = group::instances(instance.as_raw());
+                    if instances.is_empty() {
+                        return forward!(
+                            instance.owner(),
+                            $name(
+                                instance: VkInstance,
+                                pCreateInfo: *const $info,
+                                pAllocator: *const VkAllocationCallbacks,
+                                pSurface: *mut VkSurfaceKHR
+                            ) -> VkResult
+                        );
+                    }
+
+                    let mut surfaces = Vec::with_capacity(instances.len());
+                    for (backend, raw) in instances {
+                        let instance = VkInstance::from_raw(raw);
+                        let mut surface = VkSurfaceKHR::null();
+                        let pSurface = &mut surface as *mut VkSurfaceKHR;
+                        let result = forward!(
+                            backend,
+                            $name(
+                                instance: VkInstance,
+                                pCreateInfo: *const $info,
+                                pAllocator: *const VkAllocationCallbacks,
+                                pSurface: *mut VkSurfaceKHR
+                            ) -> VkResult
+                        );
+                        match result {
+                            VkResult::VK_SUCCESS => surfaces.push((backend, surface.as_raw())),
+                            // The surface is required from the main backend only.
+                            _ if surfaces.is_empty() => return result,
+                            _ => warn!(
+                                "Backend {} failed to create the surface: {:?}",
+                                backend.name(),
+                                result
+                            ),
+                        }
+                    }
+                    *pSurface = VkSurfaceKHR::from_raw(surfaces[0].1);
+                    group::add_surfaces(surfaces);
+                    VkResult::VK_SUCCESS
+                }
+            )*
+        }
+    };
+}
+
+surface_constructors! {
+    fn gfxCreateWin32SurfaceKHR(pCreateInfo: *const VkWin32SurfaceCreateInfoKHR);
+    fn gfxCreateXlibSurfaceKHR(pCreateInfo: *const VkXlibSurfaceCreateInfoKHR);
+    fn gfxCreateXcbSurfaceKHR(pCreateInfo: *const VkXcbSurfaceCreateInfoKHR);
+    fn gfxCreateWaylandSurfaceKHR(pCreateInfo: *const VkWaylandSurfaceCreateInfoKHR);
+    fn gfxCreateMetalSurfaceEXT(pCreateInfo: *const VkMetalSurfaceCreateInfoEXT);
+    fn gfxCreateMacOSSurfaceMVK(pCreateInfo: *const VkMacOSSurfaceCreateInfoMVK);
+    fn gfxCreateHeadlessSurfaceEXT(pCreateInfo: *const VkHeadlessSurfaceCreateInfoEXT);
+}
+
+/// Defines the entry points querying a surface for a physical device,
+/// which are given the surface of the backend of the physical device.
+macro_rules! surface_queries {
+    ($(
+        fn $name:ident(
+            $adapter:ident: VkPhysicalDevice,
+            $surface:ident: VkSurfaceKHR
+            $(, $arg:ident: $ty:ty)* $(,)?
+        ) -> VkResult;
+    )*) => {
+        captured! {
+            $(
+                #[inline]
+                pub unsafe extern "C" fn $name(
+                    $adapter: VkPhysicalDevice,
+                    $surface: VkSurfaceKHR
+                    $(, $arg: $ty)*
+                ) -> VkResult {
+                    let backend = $adapter.owner();
+                    let $surface = match surface_for($surface, backend) {
+                        Some(surface) => surface,
+                        None => return VkResult::VK_ERROR_SURFACE_LOST_KHR,
+                    };
+                    forward!(
+                        backend,
+                        $name($adapter: VkPhysicalDevice, $surface: VkSurfaceKHR $(, $arg: $ty)*) -> VkResult
+                    )
+                }
+            )*
+        }
+    };
+}
+
+surface_queries! {
+    fn gfxGetPhysicalDeviceSurfaceCapabilitiesKHR(
+        adapter: VkPhysicalDevice,
+        surface: VkSurfaceKHR,
+        pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR,
+    ) -> VkResult;
+    fn gfxGetPhysicalDeviceSurfaceFormatsKHR(
+        adapter: VkPhysicalDevice,
+        surface: VkSurfaceKHR,
+        pSurfaceFormatCount: *mut u32,
+        pSurfaceFormats: *mut VkSurfaceFormatKHR,
+    ) -> VkResult;
+    fn gfxGetPhysicalDeviceSurfacePresentModesKHR(
+        adapter: VkPhysicalDevice,
+        surface: VkSurfaceKHR,
+        pPresentModeCount: *mut u32,
+        pPresentModes: *mut VkPresentModeKHR,
+    ) -> VkResult;
+    fn gfxGetPhysicalDeviceSurfaceCapabilities2EXT(
+        physicalDevice: VkPhysicalDevice,
+        surface: VkSurfaceKHR,
+        pSurfaceCapabilities: *mut VkSurfaceCapabilities2EXT,
+    ) -> VkResult;
+}
+
+/// Backend serving the global commands, which are called without an instance.
+fn global_backend() -> Backend {
+    backend::select(&config::current()).map_or(Backend::preferred(), |backends| backends[0])
+}
+
+/// Returns the surface of the given backend, for the surfaces of groups.
+fn surface_for(surface: VkSurfaceKHR, backend: Backend) -> Option<VkSurfaceKHR> {
+    group::surface(surface.as_raw(), backend).map(VkSurfaceKHR::from_raw)
+}
+
+/// Appends the name of the backend to the name of a physical device
+/// of a group, so that the ones of different backends can be told apart.
+unsafe fn tag_device_name(name: &mut [c_char], backend: Backend) {
+    let len = CStr::from_ptr(name.as_ptr()).to_bytes().len();
+    let suffix = format!(" ({})", backend.name());
+    let end = (len + suffix.len()).min(name.len() - 1);
+    for (dst, &src) in name[len..end].iter_mut().zip(suffix.as_bytes()) {
+        *dst = src as c_char;
+    }
+    name[end] = 0;
+}
+
+/// Enumerates the physical devices of all the instances of a group.
+unsafe fn group_adapters(instances: Vec<(Backend, u64)>) -> Vec<VkPhysicalDevice> {
+    let mut adapters = Vec::new();
+    let group = instances[0].1;
+    for (backend, raw) in instances {
+        let instance = VkInstance::from_raw(raw);
+        let mut count = 0;
+        let pPhysicalDeviceCount = &mut count as *mut u32;
+        let pPhysicalDevices: *mut VkPhysicalDevice = ptr::null_mut();
+        forward!(
+            backend,
+            gfxEnumeratePhysicalDevices(
+                instance: VkInstance,
+                pPhysicalDeviceCount: *mut u32,
+                pPhysicalDevices: *mut VkPhysicalDevice
+            ) -> VkResult
+        );
+        let mut backend_adapters = vec![VkPhysicalDevice::null(); count as usize];
+        let pPhysicalDevices = backend_adapters.as_mut_ptr();
+        forward!(
+            backend,
+            gfxEnumeratePhysicalDevices(
+                instance: VkInstance,
+                pPhysicalDeviceCount: *mut u32,
+                pPhysicalDevices: *mut VkPhysicalDevice
+            ) -> VkResult
+        );
+        backend_adapters.truncate(count as usize);
+
+        for adapter in backend_adapters {
+            backend::register_adapter(adapter.as_raw(), raw, backend);
+            api_dump::add_adapter(adapter.as_raw(), group);
+            group::add_adapter(adapter.as_raw(), group);
+            adapters.push(adapter);
+        }
+    }
+    adapters
+}
+
+/// Resolves a command to our own entry point, which routes it
+/// to the backend owning the handle it's called with.
+unsafe fn command(pName: *const c_char) -> PFN_vkVoidFunction {
+    let name = match CStr::from_ptr(pName).to_str() {
+        Ok(name) => name,
+        Err(_) => return None,
+    };
+
+    let function: PFN_vkVoidFunction = device_commands!(name);
+    function.or_else(|| instance_commands!(name))
+}
+
+/// Returns our own entry point for the commands creating or destroying
+/// dispatchable handles, or resolving other commands, which have to go
+/// through this module even when called through a function pointer.
+unsafe fn entry_point(pName: *const c_char) -> PFN_vkVoidFunction {
+    let name = match CStr::from_ptr(pName).to_str() {
+        Ok(name) => name,
+        Err(_) => return None,
+    };
+
+    proc_addr! { name,
+        vkCreateInstance, PFN_vkCreateInstance => gfxCreateInstance,
+        vkDestroyInstance, PFN_vkDestroyInstance => gfxDestroyInstance,
+        vkGetInstanceProcAddr, PFN_vkGetInstanceProcAddr => gfxGetInstanceProcAddr,
+        vkEnumerateInstanceVersion, PFN_vkEnumerateInstanceVersion => gfxEnumerateInstanceVersion,
+        vkEnumerateInstanceLayerProperties, PFN_vkEnumerateInstanceLayerProperties => gfxEnumerateInstanceLayerProperties,
+        vkEnumerateInstanceExtensionProperties, PFN_vkEnumerateInstanceExtensionProperties => gfxEnumerateInstanceExtensionProperties,
+        vkEnumerateDeviceLayerProperties, PFN_vkEnumerateDeviceLayerProperties => gfxEnumerateDeviceLayerProperties,
+        vkEnumerateDeviceExtensionProperties, PFN_vkEnumerateDeviceExtensionProperties => gfxEnumerateDeviceExtensionProperties,
+        vkEnumeratePhysicalDevices, PFN_vkEnumeratePhysicalDevices => gfxEnumeratePhysicalDevices,
+        vkEnumeratePhysicalDeviceGroups, PFN_vkEnumeratePhysicalDeviceGroupsKHX => gfxEnumeratePhysicalDeviceGroups,
+        vkCreateDevice, PFN_vkCreateDevice => gfxCreateDevice,
+        vkDestroyDevice, PFN_vkDestroyDevice => gfxDestroyDevice,
+        vkGetDeviceProcAddr, PFN_vkGetDeviceProcAddr => gfxGetDeviceProcAddr,
+        vkGetDeviceQueue, PFN_vkGetDeviceQueue => gfxGetDeviceQueue,
+        vkGetDeviceQueue2, PFN_vkGetDeviceQueue2 => gfxGetDeviceQueue2,
+        vkAllocateCommandBuffers, PFN_vkAllocateCommandBuffers => gfxAllocateCommandBuffers,
+        vkFreeCommandBuffers, PFN_vkFreeCommandBuffers => gfxFreeCommandBuffers,
+    }
+}
+
+/// Lets the backend decide if the command is available, replacing
+/// the pointer by our own entry point if there is one.
+unsafe fn resolve(function: PFN_vkVoidFunction, pName: *const c_char) -> PFN_vkVoidFunction {
+    match function {
+        Some(_) => entry_point(pName).or(function),
+        None => None,
+    }
+}
+
+// The entry points above that are replayed as they are, the others
+// being emulated or skipped by the replay.
+replay_commands! {
+    pub(crate) fn replay_front;
+    fn gfxCreateInstance(
+        pCreateInfo: *const VkInstanceCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pInstance: *mut VkInstance,
+    ) -> VkResult;
+    fn gfxDestroyInstance(instance: VkInstance, pAllocator: *const VkAllocationCallbacks);
+    fn gfxEnumerateInstanceVersion(pApiVersion: *mut u32) -> VkResult;
+    fn gfxEnumerateInstanceExtensionProperties(
+        pLayerName: *const c_char,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkExtensionProperties,
+    ) -> VkResult;
+    fn gfxEnumerateInstanceLayerProperties(
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkLayerProperties,
+    ) -> VkResult;
+    fn gfxEnumerateDeviceExtensionProperties(
+        physicalDevice: VkPhysicalDevice,
+        pLayerName: *const c_char,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkExtensionProperties,
+    ) -> VkResult;
+    fn gfxEnumerateDeviceLayerProperties(
+        physicalDevice: VkPhysicalDevice,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkLayerProperties,
+    ) -> VkResult;
+    fn gfxEnumeratePhysicalDevices(
+        instance: VkInstance,
+        pPhysicalDeviceCount: *mut u32,
+        pPhysicalDevices: *mut VkPhysicalDevice,
+    ) -> VkResult;
+    fn gfxEnumeratePhysicalDeviceGroups(
+        instance: VkInstance,
+        pPhysicalDeviceGroupCount: *mut u32,
+        pPhysicalDeviceGroupProperties: *mut VkPhysicalDeviceGroupPropertiesKHX,
+    ) -> VkResult;
+    fn gfxCreateDevice(
+        adapter: VkPhysicalDevice,
+        pCreateInfo: *const VkDeviceCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pDevice: *mut VkDevice,
+    ) -> VkResult;
+    fn gfxGetPhysicalDeviceProperties(
+        adapter: VkPhysicalDevice,
+        pProperties: *mut VkPhysicalDeviceProperties,
+    );
+    fn gfxGetPhysicalDeviceProperties2KHR(
+        adapter: VkPhysicalDevice,
+        pProperties: *mut VkPhysicalDeviceProperties2KHR,
+    );
+}
+
+/// The entry points that are not routed to a single backend.
+captured! {
+    #[inline]
+    pub unsafe extern "C" fn gfxCreateInstance(
+        pCreateInfo: *const VkInstanceCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pInstance: *mut VkInstance,
+    ) -> VkResult {
+        let create_info = pCreateInfo.as_ref();
+        let settings =
+            config::for_application(create_info.and_then(|info| info.pApplicationInfo.as_ref()));
+        let backends = match backend::select(&settings) {
+            Ok(backends) => backends,
+            Err(result) => return result,
+        };
+        let dump = match create_info {
+            Some(info) => {
+                match api_dump::check_layers(info.enabledLayerCount, info.ppEnabledLayerNames) {
+                    Ok(dump) => dump,
+                    Err(result) => return result,
+                }
+            }
+            None => false,
+        };
+        // Enabled right away, so that the creation is dumped as well.
+        if dump {
+            api_dump::enable();
+        }
+
+        let mut instances = Vec::with_capacity(backends.len());
+        let mut result = VkResult::VK_SUCCESS;
+        for &backend in &backends {
+            let status = forward!(
+                backend,
+                gfxCreateInstance(
+                    pCreateInfo: *const VkInstanceCreateInfo,
+                    pAllocator: *const VkAllocationCallbacks,
+                    pInstance: *mut VkInstance
+                ) -> VkResult
+            );
+            if status == VkResult::VK_SUCCESS {
+                backend::register_instance((*pInstance).as_raw(), backend);
+                instances.push((backend, (*pInstance).as_raw()));
+            } else {
+                if backends.len() > 1 {
+                    warn!(
+                        "Backend {} failed to create the instance: {:?}",
+                        backend.name(),
+                        status
+                    );
+                }
+                result = status;
+            }
+        }
+
+        if dump {
+            match instances.first() {
+                Some(&(_, group)) => {
+                    for &(_, raw) in &instances {
+                        api_dump::add_instance(raw, group);
+                    }
+                }
+                None => api_dump::disable(),
+            }
+        }
+
+        match instances.len() {
+            0 => result,
+            1 => {
+                *pInstance = VkInstance::from_raw(instances[0].1);
+                VkResult::VK_SUCCESS
+            }
+            _ => {
+                *pInstance = VkInstance::from_raw(instances[0].1);
+                group::add_instances(instances);
+                VkResult::VK_SUCCESS
+            }
+        }
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxDestroyInstance(
+        instance: VkInstance,
+        pAllocator: *const VkAllocationCallbacks,
+    ) {
+        let mut instances = group::remove_instances(instance.as_raw());
+        if instances.is_empty() {
+            instances.push((instance.owner(), instance.as_raw()));
+        }
+        for (backend, raw) in instances {
+            let instance = VkInstance::from_raw(raw);
+            forward!(
+                backend,
+                gfxDestroyInstance(instance: VkInstance, pAllocator: *const VkAllocationCallbacks)
+            );
+            backend::unregister_instance(raw);
+        }
+        if api_dump::remove_instance(instance.as_raw()) {
+            api_dump::disable();
+        }
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxEnumerateInstanceVersion(pApiVersion: *mut u32) -> VkResult {
+        forward!(
+            global_backend(),
+            gfxEnumerateInstanceVersion(pApiVersion: *mut u32) -> VkResult
+        )
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxEnumerateInstanceExtensionProperties(
+        pLayerName: *const c_char,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkExtensionProperties,
+    ) -> VkResult {
+        if !pLayerName.is_null() {
+            return api_dump::enumerate_layer_extensions(pLayerName, pPropertyCount);
+        }
+        forward!(
+            global_backend(),
+            gfxEnumerateInstanceExtensionProperties(
+                pLayerName: *const c_char,
+                pPropertyCount: *mut u32,
+                pProperties: *mut VkExtensionProperties
+            ) -> VkResult
+        )
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxEnumerateInstanceLayerProperties(
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkLayerProperties,
+    ) -> VkResult {
+        api_dump::enumerate_layers(pPropertyCount, pProperties)
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxEnumerateDeviceExtensionProperties(
+        physicalDevice: VkPhysicalDevice,
+        pLayerName: *const c_char,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkExtensionProperties,
+    ) -> VkResult {
+        if !pLayerName.is_null() {
+            return api_dump::enumerate_layer_extensions(pLayerName, pPropertyCount);
+        }
+        forward!(
+            physicalDevice.owner(),
+            gfxEnumerateDeviceExtensionProperties(
+                physicalDevice: VkPhysicalDevice,
+                pLayerName: *const c_char,
+                pPropertyCount: *mut u32,
+                pProperties: *mut VkExtensionProperties
+            ) -> VkResult
+        )
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxEnumerateDeviceLayerProperties(
+        _physicalDevice: VkPhysicalDevice,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkLayerProperties,
+    ) -> VkResult {
+        api_dump::enumerate_layers(pPropertyCount, pProperties)
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxGetInstanceProcAddr(
+        instance: VkInstance,
+        pName: *const c_char,
+    ) -> PFN_vkVoidFunction {
+        let backend = if instance.as_raw() == VK_NULL_HANDLE as u64 {
+            global_backend()
+        } else {
+            instance.owner()
+        };
+        let function = forward!(
+            backend,
+            gfxGetInstanceProcAddr(instance: VkInstance, pName: *const c_char) -> PFN_vkVoidFunction
+        );
+        // The physical devices and devices of a group belong to different
+        // backends, so all the commands have to be routed. The dumped ones
+        // have to go through `forward!` as well.
+        if function.is_some() && (group::is_group(instance.as_raw()) || api_dump::is_enabled()) {
+            return command(pName);
+        }
+        resolve(function, pName)
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxGetDeviceProcAddr(
+        gpu: VkDevice,
+        pName: *const c_char,
+    ) -> PFN_vkVoidFunction {
+        let function = forward!(
+            gpu.owner(),
+            gfxGetDeviceProcAddr(gpu: VkDevice, pName: *const c_char) -> PFN_vkVoidFunction
+        );
+        if function.is_some() && gpu.is_dumped() {
+            return command(pName);
+        }
+        resolve(function, pName)
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxEnumeratePhysicalDevices(
+        instance: VkInstance,
+        pPhysicalDeviceCount: *mut u32,
+        pPhysicalDevices: *mut VkPhysicalDevice,
+    ) -> VkResult {
+        let instances = group::instances(instance.as_raw());
+        if !instances.is_empty() {
+            let adapters = group_adapters(instances);
+            if pPhysicalDevices.is_null() {
+                *pPhysicalDeviceCount = adapters.len() as _;
+                return VkResult::VK_SUCCESS;
+            }
+            let count = adapters.len().min(*pPhysicalDeviceCount as _);
+            slice::from_raw_parts_mut(pPhysicalDevices, count).copy_from_slice(&adapters[..count]);
+            *pPhysicalDeviceCount = count as _;
+            return if count < adapters.len() {
+                VkResult::VK_INCOMPLETE
+            } else {
+                VkResult::VK_SUCCESS
+            };
+        }
+
+        let backend = instance.owner();
+        let result = forward!(
+            backend,
+            gfxEnumeratePhysicalDevices(
+                instance: VkInstance,
+                pPhysicalDeviceCount: *mut u32,
+                pPhysicalDevices: *mut VkPhysicalDevice
+            ) -> VkResult
+        );
+        if !pPhysicalDevices.is_null() {
+            for adapter in slice::from_raw_parts(pPhysicalDevices, *pPhysicalDeviceCount as _) {
+                backend::register_adapter(adapter.as_raw(), instance.as_raw(), backend);
+                api_dump::add_adapter(adapter.as_raw(), instance.as_raw());
+            }
+        }
+        result
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxEnumeratePhysicalDeviceGroups(
+        instance: VkInstance,
+        pPhysicalDeviceGroupCount: *mut u32,
+        pPhysicalDeviceGroupProperties: *mut VkPhysicalDeviceGroupPropertiesKHX,
+    ) -> VkResult {
+        let instances = group::instances(instance.as_raw());
+        if !instances.is_empty() {
+            // Every physical device is a group of its own.
+            let adapters = group_adapters(instances);
+            if pPhysicalDeviceGroupProperties.is_null() {
+                *pPhysicalDeviceGroupCount = adapters.len() as _;
+                return VkResult::VK_SUCCESS;
+            }
+            let count = adapters.len().min(*pPhysicalDeviceGroupCount as _);
+            let groups = slice::from_raw_parts_mut(pPhysicalDeviceGroupProperties, count);
+            for (group, &adapter) in groups.iter_mut().zip(&adapters) {
+                group.physicalDeviceCount = 1;
+                group.physicalDevices[0] = adapter;
+                group.subsetAllocation = VK_FALSE;
+            }
+            *pPhysicalDeviceGroupCount = count as _;
+            return if count < adapters.len() {
+                VkResult::VK_INCOMPLETE
+            } else {
+                VkResult::VK_SUCCESS
+            };
+        }
+
+        let backend = instance.owner();
+        let result = forward!(
+            backend,
+            gfxEnumeratePhysicalDeviceGroups(
+                instance: VkInstance,
+                pPhysicalDeviceGroupCount: *mut u32,
+                pPhysicalDeviceGroupProperties: *mut VkPhysicalDeviceGroupPropertiesKHX
+            ) -> VkResult
+        );
+        if !pPhysicalDeviceGroupProperties.is_null() {
+            let groups = slice::from_raw_parts(
+                pPhysicalDeviceGroupProperties,
+                *pPhysicalDeviceGroupCount as _,
+            );
+            for group in groups {
+                for adapter in &group.physicalDevices[..group.physicalDeviceCount as usize] {
+                    backend::register_adapter(adapter.as_raw(), instance.as_raw(), backend);
+                    api_dump::add_adapter(adapter.as_raw(), instance.as_raw());
+                }
+            }
+        }
+        result
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxCreateDevice(
+        adapter: VkPhysicalDevice,
+        pCreateInfo: *const VkDeviceCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pDevice: *mut VkDevice,
+    ) -> VkResult {
+        // The device layers are deprecated, but have to be known ones.
+        if let Some(info) = pCreateInfo.as_ref() {
+            if let Err(result) =
+                api_dump::check_layers(info.enabledLayerCount, info.ppEnabledLayerNames)
+            {
+                return result;
+            }
+        }
+        forward!(
+            adapter.owner(),
+            gfxCreateDevice(
+                adapter: VkPhysicalDevice,
+                pCreateInfo: *const VkDeviceCreateInfo,
+                pAllocator: *const VkAllocationCallbacks,
+                pDevice: *mut VkDevice
+            ) -> VkResult
+        )
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxGetDeviceQueue(
+        gpu: VkDevice,
+        queueFamilyIndex: u32,
+        queueIndex: u32,
+        pQueue: *mut VkQueue,
+    ) {
+        forward!(
+            gpu.owner(),
+            gfxGetDeviceQueue(
+                gpu: VkDevice,
+                queueFamilyIndex: u32,
+                queueIndex: u32,
+                pQueue: *mut VkQueue
+            )
+        );
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxGetDeviceQueue2(
+        gpu: VkDevice,
+        pQueueInfo: *const VkDeviceQueueInfo2,
+        pQueue: *mut VkQueue,
+    ) {
+        forward!(
+            gpu.owner(),
+            gfxGetDeviceQueue2(
+                gpu: VkDevice,
+                pQueueInfo: *const VkDeviceQueueInfo2,
+                pQueue: *mut VkQueue
+            )
+        );
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxGetPhysicalDeviceProperties(
+        adapter: VkPhysicalDevice,
+        pProperties: *mut VkPhysicalDeviceProperties,
+    ) {
+        let backend = adapter.owner();
+        forward!(
+            backend,
+            gfxGetPhysicalDeviceProperties(
+                adapter: VkPhysicalDevice,
+                pProperties: *mut VkPhysicalDeviceProperties
+            )
+        );
+        if group::is_grouped(adapter.as_raw()) {
+            tag_device_name(&mut (*pProperties).deviceName, backend);
+        }
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxGetPhysicalDeviceProperties2KHR(
+        adapter: VkPhysicalDevice,
+        pProperties: *mut VkPhysicalDeviceProperties2KHR,
+    ) {
+        let backend = adapter.owner();
+        forward!(
+            backend,
+            gfxGetPhysicalDeviceProperties2KHR(
+                adapter: VkPhysicalDevice,
+                pProperties: *mut VkPhysicalDeviceProperties2KHR
+            )
+        );
+        if group::is_grouped(adapter.as_raw()) {
+            tag_device_name(&mut (*pProperties).properties.deviceName, backend);
+        }
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxDestroySurfaceKHR(
+        instance: VkInstance,
+        surface: VkSurfaceKHR,
+        pAllocator: *const VkAllocationCallbacks,
+    ) {
+        let surfaces = match group::remove_surfaces(surface.as_raw()) {
+            Some(surfaces) => surfaces,
+            None => {
+                forward!(
+                    instance.owner(),
+                    gfxDestroySurfaceKHR(
+                        instance: VkInstance,
+                        surface: VkSurfaceKHR,
+                        pAllocator: *const VkAllocationCallbacks
+                    )
+                );
+                return;
+            }
+        };
+
+        let instances = group::instances(instance.as_raw());
+        for (backend, raw) in surfaces {
+            let owner = instances.iter().find(|&&(owner, _)| owner == backend);
+            if let Some(&(_, instance)) = owner {
+                let instance = VkInstance::from_raw(instance);
+                let surface = VkSurfaceKHR::from_raw(raw);
+                forward!(
+                    backend,
+                    gfxDestroySurfaceKHR(
+                        instance: VkInstance,
+                        surface: VkSurfaceKHR,
+                        pAllocator: *const VkAllocationCallbacks
+                    )
+                );
+            }
+        }
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxGetPhysicalDeviceSurfaceSupportKHR(
+        adapter: VkPhysicalDevice,
+        queueFamilyIndex: u32,
+        surface: VkSurfaceKHR,
+        pSupported: *mut VkBool32,
+    ) -> VkResult {
+        let backend = adapter.owner();
+        let surface = match surface_for(surface, backend) {
+            Some(surface) => surface,
+            None => {
+                *pSupported = VK_FALSE;
+                return VkResult::VK_SUCCESS;
+            }
+        };
+        forward!(
+            backend,
+            gfxGetPhysicalDeviceSurfaceSupportKHR(
+                adapter: VkPhysicalDevice,
+                queueFamilyIndex: u32,
+                surface: VkSurfaceKHR,
+                pSupported: *mut VkBool32
+            ) -> VkResult
+        )
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxGetPhysicalDeviceSurfaceCapabilities2KHR(
+        adapter: VkPhysicalDevice,
+        pSurfaceInfo: *const VkPhysicalDeviceSurfaceInfo2KHR,
+        pSurfaceCapabilities: *mut VkSurfaceCapabilities2KHR,
+    ) -> VkResult {
+        let backend = adapter.owner();
+        let mut surface_info = *pSurfaceInfo;
+        surface_info.surface = match surface_for(surface_info.surface, backend) {
+            Some(surface) => surface,
+            None => return VkResult::VK_ERROR_SURFACE_LOST_KHR,
+        };
+        let pSurfaceInfo = &surface_info as *const VkPhysicalDeviceSurfaceInfo2KHR;
+        forward!(
+            backend,
+            gfxGetPhysicalDeviceSurfaceCapabilities2KHR(
+                adapter: VkPhysicalDevice,
+                pSurfaceInfo: *const VkPhysicalDeviceSurfaceInfo2KHR,
+                pSurfaceCapabilities: *mut VkSurfaceCapabilities2KHR
+            ) -> VkResult
+        )
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxGetPhysicalDeviceSurfaceFormats2KHR(
+        adapter: VkPhysicalDevice,
+        pSurfaceInfo: *const VkPhysicalDeviceSurfaceInfo2KHR,
+        pSurfaceFormatCount: *mut u32,
+        pSurfaceFormats: *mut VkSurfaceFormat2KHR,
+    ) -> VkResult {
+        let backend = adapter.owner();
+        let mut surface_info = *pSurfaceInfo;
+        surface_info.surface = match surface_for(surface_info.surface, backend) {
+            Some(surface) => surface,
+            None => return VkResult::VK_ERROR_SURFACE_LOST_KHR,
+        };
+        let pSurfaceInfo = &surface_info as *const VkPhysicalDeviceSurfaceInfo2KHR;
+        forward!(
+            backend,
+            gfxGetPhysicalDeviceSurfaceFormats2KHR(
+                adapter: VkPhysicalDevice,
+                pSurfaceInfo: *const VkPhysicalDeviceSurfaceInfo2KHR,
+                pSurfaceFormatCount: *mut u32,
+                pSurfaceFormats: *mut VkSurfaceFormat2KHR
+            ) -> VkResult
+        )
+    }
+
+    #[inline]
+    pub unsafe extern "C" fn gfxCreateSwapchainKHR(
+        gpu: VkDevice,
+        pCreateInfo: *const VkSwapchainCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pSwapchain: *mut VkSwapchainKHR,
+    ) -> VkResult {
+        let backend = gpu.owner();
+        let mut create_info = *pCreateInfo;
+        create_info.surface = match surface_for(create_info.surface, backend) {
+            Some(surface) => surface,
+            None => return VkResult::VK_ERROR_SURFACE_LOST_KHR,
+        };
+        let pCreateInfo = &create_info as *const VkSwapchainCreateInfoKHR;
+        forward!(
+            backend,
+            gfxCreateSwapchainKHR(
+                gpu: VkDevice,
+                pCreateInfo: *const VkSwapchainCreateInfoKHR,
+                pAllocator: *const VkAllocationCallbacks,
+                pSwapchain: *mut VkSwapchainKHR
+            ) -> VkResult
+        )
+    }
+}
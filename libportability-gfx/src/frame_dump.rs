@@ -0,0 +1,300 @@
+//! Dumps of the presented frames to image files, for comparing the output
+//! of an application across backends and versions.
+//!
+//! Enabled by the `frame_dump` section of the configuration. The backends
+//! read the images back in `vkQueuePresentKHR`, and this module writes them
+//! as PNG or PPM, along with a JSON file giving the index and the timing of
+//! the frame. The images of the native swapchains are copied while still
+//! acquired, before being presented, so the surface has to support copying
+//! from them. Each swapchain counts its frames from 0, and its files are
+//! named after the order of its creation, e.g. `swapchain0-000042.png`.
+
+use crate::config::{FrameDump, ImageFormat};
+
+use std::{
+    fs,
+    io::{self, Write},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+/// Number of swapchains created, giving the name of their files.
+static SWAPCHAINS: AtomicUsize = AtomicUsize::new(0);
+
+/// Presentation counter of a swapchain.
+pub struct Frames {
+    swapchain: usize,
+    /// Whether the images can be read back, which the surface may not support.
+    readable: bool,
+    count: u64,
+    created: Instant,
+    last: Option<Instant>,
+}
+
+/// A presented frame to dump.
+pub struct Frame {
+    pub swapchain: usize,
+    pub index: u64,
+    /// Time of the presentation since the creation of the swapchain.
+    pub time: Duration,
+    /// Time since the previous presentation.
+    pub frame_time: Duration,
+}
+
+/// Order of the channels of the pixels read back, 4 bytes each.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Channels {
+    Rgba,
+    Bgra,
+}
+
+impl Frames {
+    pub fn new(readable: bool) -> Self {
+        Frames {
+            swapchain: SWAPCHAINS.fetch_add(1, Ordering::Relaxed),
+            readable,
+            count: 0,
+            created: Instant::now(),
+            last: None,
+        }
+    }
+
+    /// Counts a presentation, returning the frame if it's to be dumped.
+    pub fn present(&mut self, settings: &FrameDump) -> Option<Frame> {
+        let now = Instant::now();
+        let index = self.count;
+        let previous = self.last.replace(now).unwrap_or(self.created);
+        self.count += 1;
+
+        settings.directory.as_ref()?;
+        if !self.readable
+            || index < settings.first
+            || settings.last.map_or(false, |last| index > last)
+            || (index - settings.first) % settings.interval != 0
+        {
+            return None;
+        }
+        Some(Frame {
+            swapchain: self.swapchain,
+            index,
+            time: now - self.created,
+            frame_time: now - previous,
+        })
+    }
+}
+
+/// Writes a frame read back from a swapchain image, with rows of
+/// `width` pixels tightly packed. The alpha channel is dropped.
+pub fn write(
+    settings: &FrameDump,
+    frame: &Frame,
+    width: u32,
+    height: u32,
+    channels: Channels,
+    format: &str,
+    pixels: &[u8],
+) -> io::Result<()> {
+    let directory = match settings.directory {
+        Some(ref directory) => directory,
+        None => return Ok(()),
+    };
+    fs::create_dir_all(directory)?;
+    let name = format!("swapchain{}-{:06}", frame.swapchain, frame.index);
+
+    let (red, blue) = match channels {
+        Channels::Rgba => (0, 2),
+        Channels::Bgra => (2, 0),
+    };
+    let mut rgb = Vec::with_capacity(pixels.len() / 4 * 3);
+    for pixel in pixels.chunks_exact(4) {
+        rgb.extend_from_slice(&[pixel[red], pixel[1], pixel[blue]]);
+    }
+
+    let (extension, image) = match settings.format {
+        ImageFormat::Png => ("png", encode_png(width, height, &rgb)),
+        ImageFormat::Ppm => ("ppm", encode_ppm(width, height, &rgb)),
+    };
+    fs::write(directory.join(format!("{}.{}", name, extension)), image)?;
+
+    let mut metadata = fs::File::create(directory.join(format!("{}.json", name)))?;
+    writeln!(metadata, "{{")?;
+    writeln!(metadata, "    \"swapchain\": {},", frame.swapchain)?;
+    writeln!(metadata, "    \"frame\": {},", frame.index)?;
+    writeln!(metadata, "    \"width\": {},", width)?;
+    writeln!(metadata, "    \"height\": {},", height)?;
+    writeln!(metadata, "    \"format\": \"{}\",", format)?;
+    writeln!(metadata, "    \"time\": {:.6},", frame.time.as_secs_f64())?;
+    writeln!(
+        metadata,
+        "    \"frame_time\": {:.6}",
+        frame.frame_time.as_secs_f64()
+    )?;
+    writeln!(metadata, "}}")
+}
+
+fn encode_ppm(width: u32, height: u32, rgb: &[u8]) -> Vec<u8> {
+    let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    out.extend_from_slice(rgb);
+    out
+}
+
+/// Encodes an 8-bit RGB PNG, with the image data stored uncompressed.
+fn encode_png(width: u32, height: u32, rgb: &[u8]) -> Vec<u8> {
+    // Each row starts with its filter type, none here.
+    let row = width as usize * 3;
+    let mut raw = Vec::with_capacity((row + 1) * height as usize);
+    for line in rgb.chunks_exact(row) {
+        raw.push(0);
+        raw.extend_from_slice(line);
+    }
+
+    // A zlib stream of stored deflate blocks.
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(0xFFFF).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        zlib.push(blocks.peek().is_none() as u8);
+        zlib.extend_from_slice(&(block.len() as u16).to_le_bytes());
+        zlib.extend_from_slice(&(!(block.len() as u16)).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // Bit depth, RGB color type, default compression, filtering and interlacing.
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_chunk(&mut out, b"IHDR", &header);
+    write_chunk(&mut out, b"IDAT", &zlib);
+    write_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Splits a PNG into its chunks, checking their CRC.
+    fn chunks(png: &[u8]) -> Vec<([u8; 4], Vec<u8>)> {
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        let mut chunks = Vec::new();
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let mut len = [0; 4];
+            len.copy_from_slice(&rest[..4]);
+            let len = u32::from_be_bytes(len) as usize;
+            let mut kind = [0; 4];
+            kind.copy_from_slice(&rest[4..8]);
+            let mut crc = [0; 4];
+            crc.copy_from_slice(&rest[8 + len..12 + len]);
+            assert_eq!(u32::from_be_bytes(crc), crc32(&rest[4..8 + len]));
+            chunks.push((kind, rest[8..8 + len].to_vec()));
+            rest = &rest[12 + len..];
+        }
+        chunks
+    }
+
+    /// Reads the stored blocks of a zlib stream, checking its checksum.
+    fn inflate_stored(zlib: &[u8]) -> Vec<u8> {
+        assert_eq!(&zlib[..2], &[0x78, 0x01]);
+        let mut data = Vec::new();
+        let mut rest = &zlib[2..];
+        loop {
+            let last = rest[0] & 1 != 0;
+            assert_eq!(rest[0] >> 1, 0, "not a stored block");
+            let len = u16::from_le_bytes([rest[1], rest[2]]);
+            let nlen = u16::from_le_bytes([rest[3], rest[4]]);
+            assert_eq!(len, !nlen);
+            data.extend_from_slice(&rest[5..5 + len as usize]);
+            rest = &rest[5 + len as usize..];
+            if last {
+                break;
+            }
+        }
+        assert_eq!(rest, &adler32(&data).to_be_bytes()[..]);
+        data
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(adler32(b""), 1);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn png() {
+        let rgb = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let chunks = chunks(&encode_png(2, 2, &rgb));
+        let kinds = chunks.iter().map(|(kind, _)| kind).collect::<Vec<_>>();
+        assert_eq!(kinds, [b"IHDR", b"IDAT", b"IEND"]);
+        assert_eq!(chunks[0].1, [0, 0, 0, 2, 0, 0, 0, 2, 8, 2, 0, 0, 0]);
+        assert_eq!(
+            inflate_stored(&chunks[1].1),
+            [0, 1, 2, 3, 4, 5, 6, 0, 7, 8, 9, 10, 11, 12]
+        );
+        assert!(chunks[2].1.is_empty());
+    }
+
+    #[test]
+    fn png_blocks() {
+        // Rows of 601 bytes with their filter type, over two stored blocks.
+        let (width, height) = (200, 120);
+        let rgb = (0..width * height * 3).map(|i| i as u8).collect::<Vec<_>>();
+        let chunks = chunks(&encode_png(width, height, &rgb));
+        let raw = inflate_stored(&chunks[1].1);
+        assert_eq!(raw.len(), (width as usize * 3 + 1) * height as usize);
+        for (line, row) in raw
+            .chunks(width as usize * 3 + 1)
+            .zip(rgb.chunks(width as usize * 3))
+        {
+            assert_eq!(line[0], 0);
+            assert_eq!(&line[1..], row);
+        }
+    }
+
+    #[test]
+    fn ppm() {
+        assert_eq!(encode_ppm(1, 1, &[1, 2, 3]), b"P6\n1 1\n255\n\x01\x02\x03");
+    }
+}
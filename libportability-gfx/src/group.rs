@@ -0,0 +1,92 @@
+//! Instances spanning several backends, whose adapters are exposed
+//! side by side as separate physical devices.
+//!
+//! Such an instance is made of one instance per backend, the one of the first
+//! backend being returned to the application. The surfaces created with it
+//! are created by every backend, and the one of the backend owning the physical
+//! device or the device they are used with is substituted in the calls.
+
+use crate::backend::Backend;
+
+use fxhash::FxHashMap;
+use lazy_static::lazy_static;
+
+use std::sync::RwLock;
+
+lazy_static! {
+    /// Instances of all the backends of a group, keyed by the raw value
+    /// of the one returned to the application.
+    static ref INSTANCES: RwLock<FxHashMap<u64, Vec<(Backend, u64)>>> =
+        RwLock::new(Default::default());
+    /// Surfaces of all the backends of a group, keyed by the raw value
+    /// of the one returned to the application.
+    static ref SURFACES: RwLock<FxHashMap<u64, Vec<(Backend, u64)>>> =
+        RwLock::new(Default::default());
+    /// Physical devices enumerated through a group, with the group they belong to.
+    static ref ADAPTERS: RwLock<FxHashMap<u64, u64>> = RwLock::new(Default::default());
+}
+
+/// Records the instances of a group, the first one being returned to the application.
+pub fn add_instances(instances: Vec<(Backend, u64)>) {
+    INSTANCES.write().unwrap().insert(instances[0].1, instances);
+}
+
+/// Returns the instances of the group, or nothing if the instance
+/// belongs to a single backend.
+pub fn instances(instance: u64) -> Vec<(Backend, u64)> {
+    INSTANCES
+        .read()
+        .unwrap()
+        .get(&instance)
+        .cloned()
+        .unwrap_or_default()
+}
+
+pub fn is_group(instance: u64) -> bool {
+    INSTANCES.read().unwrap().contains_key(&instance)
+}
+
+/// Forgets a group and its physical devices, returning its instances.
+pub fn remove_instances(instance: u64) -> Vec<(Backend, u64)> {
+    ADAPTERS
+        .write()
+        .unwrap()
+        .retain(|_, &mut group| group != instance);
+    INSTANCES
+        .write()
+        .unwrap()
+        .remove(&instance)
+        .unwrap_or_default()
+}
+
+/// Records the surfaces created by the backends of a group,
+/// the first one being returned to the application.
+pub fn add_surfaces(surfaces: Vec<(Backend, u64)>) {
+    SURFACES.write().unwrap().insert(surfaces[0].1, surfaces);
+}
+
+/// Forgets the surfaces of a group, returning them.
+pub fn remove_surfaces(surface: u64) -> Option<Vec<(Backend, u64)>> {
+    SURFACES.write().unwrap().remove(&surface)
+}
+
+/// Returns the surface to be used with the given backend, `None` if
+/// the surface belongs to a group and the backend failed to create it.
+pub fn surface(surface: u64, backend: Backend) -> Option<u64> {
+    match SURFACES.read().unwrap().get(&surface) {
+        Some(surfaces) => surfaces
+            .iter()
+            .find(|&&(owner, _)| owner == backend)
+            .map(|&(_, raw)| raw),
+        None => Some(surface),
+    }
+}
+
+pub fn add_adapter(adapter: u64, instance: u64) {
+    ADAPTERS.write().unwrap().insert(adapter, instance);
+}
+
+/// Returns true if the physical device was enumerated through a group.
+pub fn is_grouped(adapter: u64) -> bool {
+    ADAPTERS.read().unwrap().contains_key(&adapter)
+}
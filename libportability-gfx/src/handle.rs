@@ -1,52 +1,273 @@
-use crate::VK_NULL_HANDLE;
-#[cfg(feature = "nightly")]
-use std::{collections::HashMap, hash::BuildHasherDefault, sync::{Arc, Mutex}};
-use std::{borrow, fmt, ops};
+//! Handles of the objects given to the application, shared by all the backends.
+
+use crate::{
+    ffi::{
+        VkAllocationCallbacks, VkDebugReportObjectTypeEXT, VkInternalAllocationType,
+        VkSystemAllocationScope, VK_NULL_HANDLE,
+    },
+    leaks, validation,
+};
+#[cfg(feature = "generational-handles")]
+use std::marker::PhantomData;
+use std::{
+    any::type_name, backtrace::Backtrace, borrow, collections::HashMap, fmt,
+    hash::BuildHasherDefault, mem, ops, os::raw::c_void, ptr, sync::Mutex,
+};
 
 use copyless::{BoxAllocation, BoxHelper};
-
-#[cfg(feature = "nightly")]
 use lazy_static::lazy_static;
 
-#[cfg(feature = "nightly")]
 lazy_static! {
-    static ref REGISTRY: Arc<Mutex<HashMap<usize, &'static str, BuildHasherDefault<fxhash::FxHasher>>>> =
-        Arc::new(Mutex::new(HashMap::default()));
+    /// Live handles, populated when validation or leak tracking is enabled.
+    static ref REGISTRY: Mutex<HashMap<u64, Entry, BuildHasherDefault<fxhash::FxHasher>>> =
+        Mutex::new(HashMap::default());
+}
+
+struct Entry {
+    type_name: &'static str,
+    /// Debug name given by the application.
+    name: Option<String>,
+    backtrace: Option<Backtrace>,
 }
 
+/// A handle that is still alive, as seen by `live_handles`.
+pub struct LiveHandle {
+    pub type_name: &'static str,
+    pub name: Option<String>,
+    pub backtrace: Option<String>,
+}
+
+#[cfg(not(feature = "generational-handles"))]
 #[repr(C)]
 pub struct Handle<T>(*mut T);
 
-#[cfg(feature = "nightly")]
-impl Handle<()> {
-    pub fn report_leaks() {
-        println!("Leaked handles:");
-        let mut map = REGISTRY.lock().unwrap();
-        for (_, type_id) in map.drain() {
-            println!("\t{:?}", type_id);
+/// With generational handles, the value is an index into the slab of objects
+/// in the lower 32 bits, and the generation of the slot in the upper 32 bits.
+#[cfg(feature = "generational-handles")]
+#[repr(C)]
+pub struct Handle<T>(u64, PhantomData<*mut T>);
+
+fn is_tracking() -> bool {
+    validation::is_enabled() || leaks::is_enabled()
+}
+
+fn register(raw: u64, type_name: &'static str) {
+    if is_tracking() {
+        let entry = Entry {
+            type_name,
+            name: None,
+            backtrace: if leaks::capture_backtraces() {
+                Some(Backtrace::force_capture())
+            } else {
+                None
+            },
+        };
+        REGISTRY.lock().unwrap().insert(raw, entry);
+    }
+}
+
+/// Removes a handle from the registry. Returns `false` if it's not alive,
+/// in which case it must not be freed again.
+fn unregister(raw: u64, type_name: &'static str) -> bool {
+    if !is_tracking() {
+        return true;
+    }
+    let known = REGISTRY.lock().unwrap().remove(&raw).is_some();
+    if !known {
+        report_dead(raw, type_name, "destroyed");
+    }
+    known
+}
+
+fn report_dead(raw: u64, type_name: &'static str, action: &str) {
+    validation::error(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_UNKNOWN_EXT,
+        raw,
+        &format!("{} {:#x} is {}, but it's not alive", type_name, raw, action),
+    );
+}
+
+/// Returns `true` if the handle with the given raw value is alive,
+/// or if the handles aren't tracked.
+pub fn is_alive(raw: u64) -> bool {
+    !is_tracking() || REGISTRY.lock().unwrap().contains_key(&raw)
+}
+
+/// Remembers the debug name of a handle, to be shown in the leak reports.
+pub fn set_name(raw: u64, name: &str) {
+    if let Some(entry) = REGISTRY.lock().unwrap().get_mut(&raw) {
+        entry.name = Some(name.to_string());
+    }
+}
+
+/// Returns all the handles that are currently alive.
+pub fn live_handles() -> Vec<LiveHandle> {
+    REGISTRY
+        .lock()
+        .unwrap()
+        .values()
+        .map(|entry| LiveHandle {
+            type_name: entry.type_name,
+            name: entry.name.clone(),
+            backtrace: entry.backtrace.as_ref().map(ToString::to_string),
+        })
+        .collect()
+}
+
+/// Reports the handle if it's not alive. The entry points skip the calls
+/// given such a handle, so this catches the ones nested in structures.
+#[inline]
+fn check(raw: u64, type_name: &'static str) {
+    debug_assert_ne!(raw, VK_NULL_HANDLE as u64);
+    if !is_alive(raw) {
+        report_dead(raw, type_name, "used");
+    }
+}
+
+/// Returns the application callbacks to be used for an object of type `T`.
+///
+/// Zero-sized objects never touch the allocator, so they always go through `Box`.
+unsafe fn host_callbacks<'a, T>(
+    pAllocator: *const VkAllocationCallbacks,
+) -> Option<&'a VkAllocationCallbacks> {
+    pAllocator.as_ref().filter(|callbacks| {
+        mem::size_of::<T>() != 0 && callbacks.pfnAllocation.is_some() && callbacks.pfnFree.is_some()
+    })
+}
+
+/// Storage for a handle payload that is not initialized yet.
+enum Storage<T> {
+    Heap(BoxAllocation<T>),
+    Host(HostAllocation<T>),
+}
+
+/// Memory obtained from `VkAllocationCallbacks::pfnAllocation`.
+struct HostAllocation<T> {
+    ptr: *mut T,
+    callbacks: VkAllocationCallbacks,
+}
+
+impl<T> Drop for HostAllocation<T> {
+    fn drop(&mut self) {
+        unsafe {
+            (self.callbacks.pfnFree.unwrap())(self.callbacks.pUserData, self.ptr as *mut c_void);
+        }
+    }
+}
+
+impl<T> Storage<T> {
+    unsafe fn new(
+        pAllocator: *const VkAllocationCallbacks,
+        scope: VkSystemAllocationScope,
+    ) -> Option<Self> {
+        match host_callbacks::<T>(pAllocator) {
+            Some(callbacks) => {
+                let ptr = (callbacks.pfnAllocation.unwrap())(
+                    callbacks.pUserData,
+                    mem::size_of::<T>(),
+                    mem::align_of::<T>(),
+                    scope,
+                ) as *mut T;
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(Storage::Host(HostAllocation {
+                        ptr,
+                        callbacks: *callbacks,
+                    }))
+                }
+            }
+            None => Some(Storage::Heap(Box::alloc())),
+        }
+    }
+
+    #[inline(always)]
+    fn init(self, value: T) -> *mut T {
+        match self {
+            Storage::Heap(allocation) => Box::into_raw(allocation.init(value)),
+            Storage::Host(allocation) => {
+                let ptr = allocation.ptr;
+                mem::forget(allocation);
+                unsafe { ptr::write(ptr, value) };
+                ptr
+            }
+        }
+    }
+}
+
+/// Moves the value out of its storage and releases the memory,
+/// using the same allocator the storage was obtained from.
+unsafe fn free_storage<T>(ptr: *mut T, pAllocator: *const VkAllocationCallbacks) -> T {
+    match host_callbacks::<T>(pAllocator) {
+        Some(callbacks) => {
+            let value = ptr::read(ptr);
+            (callbacks.pfnFree.unwrap())(callbacks.pUserData, ptr as *mut c_void);
+            value
         }
+        None => *Box::from_raw(ptr),
     }
 }
 
-pub struct HandleAllocation<T>(BoxAllocation<T>);
+/// Reports an allocation made by the backend on behalf of an object,
+/// which doesn't go through `pfnAllocation`.
+pub unsafe fn notify_internal_allocation(
+    pAllocator: *const VkAllocationCallbacks,
+    size: usize,
+    scope: VkSystemAllocationScope,
+) {
+    if let Some(callbacks) = pAllocator.as_ref() {
+        if let Some(notify) = callbacks.pfnInternalAllocation {
+            notify(
+                callbacks.pUserData,
+                size,
+                VkInternalAllocationType::VK_INTERNAL_ALLOCATION_TYPE_EXECUTABLE,
+                scope,
+            );
+        }
+    }
+}
+
+/// Reports the release of an allocation given to `notify_internal_allocation`.
+pub unsafe fn notify_internal_free(
+    pAllocator: *const VkAllocationCallbacks,
+    size: usize,
+    scope: VkSystemAllocationScope,
+) {
+    if let Some(callbacks) = pAllocator.as_ref() {
+        if let Some(notify) = callbacks.pfnInternalFree {
+            notify(
+                callbacks.pUserData,
+                size,
+                VkInternalAllocationType::VK_INTERNAL_ALLOCATION_TYPE_EXECUTABLE,
+                scope,
+            );
+        }
+    }
+}
+
+pub struct HandleAllocation<T>(Storage<T>);
 
 impl<T> HandleAllocation<T> {
     #[inline(always)]
     pub fn init(self, value: T) -> Handle<T> {
-        let ptr = Box::into_raw(self.0.init(value));
-        #[cfg(feature = "nightly")]
-        {
-            use std::intrinsics::type_name;
-            let name = type_name::<T>();
-            REGISTRY.lock().unwrap().insert(ptr as _, name);
-        }
-        Handle(ptr)
+        let handle = Handle::from_ptr(self.0.init(value));
+        register(handle.as_raw(), type_name::<T>());
+        handle
     }
 }
 
 impl<T: 'static> Handle<T> {
     pub fn alloc() -> HandleAllocation<T> {
-        HandleAllocation(Box::alloc())
+        HandleAllocation(Storage::Heap(Box::alloc()))
+    }
+
+    /// Allocates the storage with the application-provided callbacks, if any.
+    /// Returns `None` if the application allocator is out of memory.
+    pub unsafe fn alloc_in(
+        pAllocator: *const VkAllocationCallbacks,
+        scope: VkSystemAllocationScope,
+    ) -> Option<HandleAllocation<T>> {
+        Storage::new(pAllocator, scope).map(HandleAllocation)
     }
 
     // Note: ideally this constructor isn't used
@@ -54,47 +275,155 @@ impl<T: 'static> Handle<T> {
         Self::alloc().init(value)
     }
 
-    pub fn null() -> Self {
-        Handle(VK_NULL_HANDLE as *mut _)
+    pub fn unbox(self) -> Option<T> {
+        unsafe { self.unbox_in(ptr::null()) }
     }
 
-    pub fn unbox(self) -> Option<T> {
-        if self.0 == VK_NULL_HANDLE as *mut T {
+    /// Destroys the handle, releasing the storage with the callbacks
+    /// compatible with the ones it was allocated with.
+    pub unsafe fn unbox_in(self, pAllocator: *const VkAllocationCallbacks) -> Option<T> {
+        if self.is_null() || !unregister(self.as_raw(), type_name::<T>()) {
             None
         } else {
-            #[cfg(feature = "nightly")]
-            {
-                REGISTRY.lock().unwrap().remove(&(self.0 as _)).unwrap();
-            }
-            Some(*unsafe { Box::from_raw(self.0) })
+            self.release().map(|ptr| free_storage(ptr, pAllocator))
         }
     }
 
     pub fn as_ref(&self) -> Option<&T> {
-        unsafe { self.0.as_ref() }
+        unsafe { self.lookup().as_ref() }
     }
 
     pub fn as_mut(&self) -> Option<&mut T> {
-        unsafe { self.0.as_mut() }
+        unsafe { self.lookup().as_mut() }
+    }
+}
+
+impl<T> Handle<T> {
+    /// Returns `false` if the handle is neither null nor alive, reporting it.
+    /// The calls given such a handle are skipped instead of touching the object.
+    pub fn is_valid(&self) -> bool {
+        if self.is_null() || self.is_live() {
+            true
+        } else {
+            report_dead(self.as_raw(), type_name::<T>(), "used");
+            false
+        }
     }
 }
 
+#[cfg(not(feature = "generational-handles"))]
 impl<T> Handle<T> {
-    #[cfg(feature = "nightly")]
+    pub fn null() -> Self {
+        Handle(VK_NULL_HANDLE as *mut _)
+    }
+
+    fn from_ptr(ptr: *mut T) -> Self {
+        Handle(ptr)
+    }
+
+    /// Returns the raw value of the handle, as seen by the application.
+    pub fn as_raw(&self) -> u64 {
+        self.0 as u64
+    }
+
+    /// Rebuilds a handle from the value returned by `as_raw`.
+    pub fn from_raw(raw: u64) -> Self {
+        Handle(raw as *mut T)
+    }
+
+    fn is_null(&self) -> bool {
+        self.0.is_null()
+    }
+
+    fn lookup(&self) -> *mut T {
+        self.0
+    }
+
+    fn is_live(&self) -> bool {
+        is_alive(self.as_raw())
+    }
+
+    /// Returns the object pointer, reporting the handle if it's not alive.
     #[inline]
-    fn check(&self) {
-        assert!(REGISTRY.lock().unwrap().contains_key(&(self.0 as _)));
+    fn resolve(&self) -> *mut T {
+        check(self.as_raw(), type_name::<T>());
+        self.0
+    }
+
+    fn release(self) -> Option<*mut T> {
+        Some(self.0)
+    }
+}
+
+#[cfg(feature = "generational-handles")]
+impl<T> Handle<T> {
+    pub fn null() -> Self {
+        Handle(VK_NULL_HANDLE as u64, PhantomData)
+    }
+
+    fn from_ptr(ptr: *mut T) -> Self {
+        Handle(slab::insert(ptr as usize, type_name::<T>()), PhantomData)
+    }
+
+    /// Returns the raw value of the handle, as seen by the application.
+    pub fn as_raw(&self) -> u64 {
+        self.0
+    }
+
+    /// Rebuilds a handle from the value returned by `as_raw`.
+    pub fn from_raw(raw: u64) -> Self {
+        Handle(raw, PhantomData)
+    }
+
+    fn is_null(&self) -> bool {
+        self.0 == VK_NULL_HANDLE as u64
+    }
+
+    /// Returns the object pointer, or null if the handle is not alive,
+    /// in which case it's also reported unless it's null.
+    fn lookup(&self) -> *mut T {
+        match slab::lookup(self.0, type_name::<T>()) {
+            Some(address) => address as *mut T,
+            None => {
+                if !self.is_null() {
+                    report_dead(self.0, type_name::<T>(), "used");
+                }
+                ptr::null_mut()
+            }
+        }
     }
-    #[cfg(not(feature = "nightly"))]
+
+    /// Only checks the generation: the entry points see the handles with
+    /// the types of the `empty` backend, not the ones they were created with.
+    fn is_live(&self) -> bool {
+        slab::is_live(self.0)
+    }
+
+    /// Returns the object pointer, panicking if the handle is not alive.
+    ///
+    /// The entry points reject the stale handles they are given (see `is_valid`),
+    /// so this only catches the ones nested in the structures passed to them.
     #[inline]
-    fn check(&self) {
-        debug_assert!(!self.0.is_null());
+    fn resolve(&self) -> *mut T {
+        let ptr = self.lookup();
+        if ptr.is_null() {
+            panic!("{} {:#x} is not alive", type_name::<T>(), self.0);
+        }
+        ptr
+    }
+
+    fn release(self) -> Option<*mut T> {
+        let address = slab::remove(self.0, type_name::<T>());
+        if address.is_none() {
+            report_dead(self.0, type_name::<T>(), "destroyed");
+        }
+        address.map(|address| address as *mut T)
     }
 }
 
 impl<T> Clone for Handle<T> {
     fn clone(&self) -> Self {
-        Handle(self.0)
+        *self
     }
 }
 
@@ -103,70 +432,275 @@ impl<T> Copy for Handle<T> {}
 impl<T> ops::Deref for Handle<T> {
     type Target = T;
     fn deref(&self) -> &T {
-        self.check();
-        unsafe { &*self.0 }
+        unsafe { &*self.resolve() }
     }
 }
 
 impl<T> ops::DerefMut for Handle<T> {
     fn deref_mut(&mut self) -> &mut T {
-        self.check();
-        unsafe { &mut *self.0 }
+        unsafe { &mut *self.resolve() }
     }
 }
 
 impl<T> borrow::Borrow<T> for Handle<T> {
     fn borrow(&self) -> &T {
-        self.check();
-        unsafe { &*self.0 }
+        unsafe { &*self.resolve() }
     }
 }
 
 impl<T> PartialEq for Handle<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.0.eq(&other.0)
+        self.as_raw() == other.as_raw()
     }
 }
 
 impl<T> fmt::Debug for Handle<T> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "Handle({:p})", self.0)
+        write!(formatter, "Handle({:#x})", self.as_raw())
+    }
+}
+
+impl<T> crate::api_dump::Dump for Handle<T> {
+    fn dump(&self, out: &mut String) {
+        out.push_str(&format!("{:?}", self));
+    }
+}
+
+impl<T: 'static> crate::trace::Traced for Handle<T> {
+    unsafe fn write(&self, writer: &mut crate::trace::Writer) {
+        writer.u64(self.as_raw());
+    }
+
+    unsafe fn read(reader: &mut crate::trace::Reader) -> Self {
+        Handle::from_raw(reader.handle())
+    }
+
+    fn bind(&self, replayed: &Self, reader: &mut crate::trace::Reader) {
+        reader.bind(self.as_raw(), replayed.as_raw());
+    }
+
+    fn raw(&self) -> u64 {
+        self.as_raw()
+    }
+}
+
+/// Slots of the objects behind the generational handles.
+///
+/// A slot gets a new generation every time its object is destroyed,
+/// so the stale handles pointing to it can be told apart.
+///
+/// The slots are allocated in chunks that are never moved nor freed, so that
+/// looking up a handle doesn't lock anything. Only the creation and destruction
+/// of the objects go through the lock of the free list.
+#[cfg(feature = "generational-handles")]
+mod slab {
+    use std::{
+        convert::TryInto,
+        ptr, slice, str,
+        sync::{
+            atomic::{AtomicPtr, AtomicU32, AtomicUsize, Ordering},
+            Mutex,
+        },
+    };
+
+    const CHUNK_SIZE: usize = 4096;
+    const CHUNK_COUNT: usize = 16384;
+
+    #[derive(Default)]
+    struct Slot {
+        generation: AtomicU32,
+        /// Address of the object, zero if the slot is free.
+        address: AtomicUsize,
+        /// Type name of the object, as its pointer and length.
+        type_ptr: AtomicUsize,
+        type_len: AtomicUsize,
+    }
+
+    impl Slot {
+        fn type_name(&self) -> &'static str {
+            let ptr = self.type_ptr.load(Ordering::Acquire) as *const u8;
+            let len = self.type_len.load(Ordering::Acquire);
+            if ptr.is_null() {
+                return "";
+            }
+            unsafe { str::from_utf8_unchecked(slice::from_raw_parts(ptr, len)) }
+        }
+
+        fn set_type_name(&self, type_name: &'static str) {
+            self.type_ptr
+                .store(type_name.as_ptr() as usize, Ordering::Release);
+            self.type_len.store(type_name.len(), Ordering::Release);
+        }
+    }
+
+    type Chunk = [Slot; CHUNK_SIZE];
+
+    #[allow(clippy::declare_interior_mutable_const)]
+    const NO_CHUNK: AtomicPtr<Chunk> = AtomicPtr::new(ptr::null_mut());
+    static CHUNKS: [AtomicPtr<Chunk>; CHUNK_COUNT] = [NO_CHUNK; CHUNK_COUNT];
+
+    struct FreeList {
+        /// Number of slots handed out so far.
+        len: usize,
+        free: Vec<u32>,
+    }
+
+    static FREE: Mutex<FreeList> = Mutex::new(FreeList {
+        len: 0,
+        free: Vec::new(),
+    });
+
+    fn split(raw: u64) -> (usize, u32) {
+        (raw as u32 as usize, (raw >> 32) as u32)
+    }
+
+    fn slot(index: usize) -> Option<&'static Slot> {
+        let chunk = CHUNKS.get(index / CHUNK_SIZE)?.load(Ordering::Acquire);
+        unsafe { chunk.as_ref() }.map(|chunk| &chunk[index % CHUNK_SIZE])
+    }
+
+    /// Puts the object into a free slot, returning the handle value for it.
+    pub fn insert(address: usize, type_name: &'static str) -> u64 {
+        let mut list = FREE.lock().unwrap();
+        let index = match list.free.pop() {
+            Some(index) => index as usize,
+            None => {
+                let index = list.len;
+                let chunk = &CHUNKS[index / CHUNK_SIZE];
+                if chunk.load(Ordering::Acquire).is_null() {
+                    // Built on the heap, the chunk being too big for the stack of some threads.
+                    let slots: Box<[Slot]> = (0..CHUNK_SIZE).map(|_| Slot::default()).collect();
+                    let slots: Box<Chunk> = slots.try_into().ok().unwrap();
+                    chunk.store(Box::into_raw(slots), Ordering::Release);
+                }
+                list.len += 1;
+                index
+            }
+        };
+        let slot = slot(index).unwrap();
+        // Zero is never a valid generation, so that a null handle never matches.
+        let generation = slot.generation.load(Ordering::Acquire).max(1);
+        slot.generation.store(generation, Ordering::Release);
+        slot.set_type_name(type_name);
+        slot.address.store(address, Ordering::Release);
+        ((generation as u64) << 32) | index as u64
+    }
+
+    /// Returns `true` if the slot of the handle holds an object of its generation,
+    /// whatever its type.
+    pub fn is_live(raw: u64) -> bool {
+        let (index, generation) = split(raw);
+        slot(index).map_or(false, |slot| {
+            slot.generation.load(Ordering::Acquire) == generation
+                && slot.address.load(Ordering::Acquire) != 0
+        })
+    }
+
+    /// Returns the address of the object, if the handle is alive.
+    ///
+    /// Only debug builds check the type of the object, release builds
+    /// don't catch the handles of other types pointing to live objects.
+    pub fn lookup(raw: u64, type_name: &'static str) -> Option<usize> {
+        let (index, generation) = split(raw);
+        let slot = slot(index)?;
+        if slot.generation.load(Ordering::Acquire) != generation {
+            return None;
+        }
+        let address = slot.address.load(Ordering::Acquire);
+        if address == 0 || (cfg!(debug_assertions) && slot.type_name() != type_name) {
+            None
+        } else {
+            Some(address)
+        }
+    }
+
+    /// Frees the slot of the object, returning its address if the handle was alive.
+    pub fn remove(raw: u64, type_name: &'static str) -> Option<usize> {
+        let (index, generation) = split(raw);
+        let mut list = FREE.lock().unwrap();
+        let slot = slot(index)?;
+        if slot.generation.load(Ordering::Acquire) != generation
+            || slot.address.load(Ordering::Acquire) == 0
+            || slot.type_name() != type_name
+        {
+            return None;
+        }
+        slot.generation
+            .store(generation.wrapping_add(1).max(1), Ordering::Release);
+        let address = slot.address.swap(0, Ordering::AcqRel);
+        list.free.push(index as u32);
+        Some(address)
     }
 }
 
-#[cfg(feature = "dispatch")]
 pub use self::dispatch::DispatchHandle;
-#[cfg(not(feature = "dispatch"))]
-pub type DispatchHandle<T> = Handle<T>;
 
-#[cfg(feature = "dispatch")]
 mod dispatch {
-    use crate::VK_NULL_HANDLE;
-    use copyless::{BoxAllocation, BoxHelper};
-    use std::{borrow, fmt, ops};
-
+    use super::{
+        check, free_storage, is_alive, register, report_dead, unregister, Storage,
+        VkAllocationCallbacks, VkSystemAllocationScope, VK_NULL_HANDLE,
+    };
+    use crate::backend::Backend;
+    use copyless::BoxHelper;
+    use std::{any::type_name, borrow, fmt, ops, ptr};
+
+    #[cfg(feature = "dispatch")]
     const ICD_LOADER_MAGIC: u64 = 0x01CDC0DE;
 
+    /// Prefix of every dispatchable object.
+    #[repr(C)]
+    struct Header {
+        /// Overwritten by the loader with its dispatch table.
+        #[cfg(feature = "dispatch")]
+        loader: u64,
+        /// Backend the object was created by, used to route the calls made with it.
+        owner: Backend,
+        /// Whether the object comes from an instance that enabled the API dump.
+        dumped: bool,
+    }
+
+    #[repr(C)]
+    struct Dispatchable<T> {
+        header: Header,
+        value: T,
+    }
+
     #[repr(C)]
-    pub struct DispatchHandle<T>(*mut (u64, T));
+    pub struct DispatchHandle<T>(*mut Dispatchable<T>);
 
-    pub struct DisplatchHandleAllocation<T>(BoxAllocation<(u64, T)>);
+    pub struct DisplatchHandleAllocation<T>(Storage<Dispatchable<T>>);
 
     impl<T> DisplatchHandleAllocation<T> {
         #[inline(always)]
-        pub fn init(self, value: T) -> DispatchHandle<T> {
-            let ptr = Box::into_raw(self.0.init((ICD_LOADER_MAGIC, value)));
+        pub fn init(self, owner: Backend, dumped: bool, value: T) -> DispatchHandle<T> {
+            let ptr = self.0.init(Dispatchable {
+                header: Header {
+                    #[cfg(feature = "dispatch")]
+                    loader: ICD_LOADER_MAGIC,
+                    owner,
+                    dumped,
+                },
+                value,
+            });
+            register(ptr as u64, type_name::<T>());
             DispatchHandle(ptr)
         }
     }
 
     impl<T> DispatchHandle<T> {
         pub fn alloc() -> DisplatchHandleAllocation<T> {
-            DisplatchHandleAllocation(Box::alloc())
+            DisplatchHandleAllocation(Storage::Heap(Box::alloc()))
         }
 
-        pub fn new(value: T) -> Self {
-            Self::alloc().init(value)
+        pub unsafe fn alloc_in(
+            pAllocator: *const VkAllocationCallbacks,
+            scope: VkSystemAllocationScope,
+        ) -> Option<DisplatchHandleAllocation<T>> {
+            Storage::new(pAllocator, scope).map(DisplatchHandleAllocation)
+        }
+
+        pub fn new(owner: Backend, dumped: bool, value: T) -> Self {
+            Self::alloc().init(owner, dumped, value)
         }
 
         pub fn null() -> Self {
@@ -174,20 +708,66 @@ mod dispatch {
         }
 
         pub fn unbox(self) -> Option<T> {
-            if self.0 == VK_NULL_HANDLE as *mut (u64, T) {
+            unsafe { self.unbox_in(ptr::null()) }
+        }
+
+        pub unsafe fn unbox_in(self, pAllocator: *const VkAllocationCallbacks) -> Option<T> {
+            if self.0 == VK_NULL_HANDLE as *mut Dispatchable<T>
+                || !unregister(self.0 as u64, type_name::<T>())
+            {
                 None
             } else {
-                Some(unsafe { Box::from_raw(self.0) }.1)
+                Some(free_storage(self.0, pAllocator).value)
             }
         }
 
         pub fn as_ref(&self) -> Option<&T> {
-            if self.0 == VK_NULL_HANDLE as *mut (u64, T) {
+            if self.0 == VK_NULL_HANDLE as *mut Dispatchable<T> {
+                None
+            } else {
+                Some(unsafe { &(*self.0).value })
+            }
+        }
+
+        /// Returns `false` if the handle is neither null nor alive, reporting it.
+        pub fn is_valid(&self) -> bool {
+            if self.0 == VK_NULL_HANDLE as *mut Dispatchable<T> || is_alive(self.0 as u64) {
+                true
+            } else {
+                report_dead(self.0 as u64, type_name::<T>(), "used");
+                false
+            }
+        }
+
+        /// Returns the backend that created the object, `None` for a null handle.
+        pub fn backend(&self) -> Option<Backend> {
+            if self.0 == VK_NULL_HANDLE as *mut Dispatchable<T> {
                 None
             } else {
-                Some(unsafe { &(*self.0).1 })
+                Some(unsafe { (*self.0).header.owner })
             }
         }
+
+        /// Returns `true` if the calls made with the object are written to the API dump.
+        pub fn is_dumped(&self) -> bool {
+            self.0 != VK_NULL_HANDLE as *mut Dispatchable<T>
+                && is_alive(self.0 as u64)
+                && unsafe { (*self.0).header.dumped }
+        }
+
+        pub fn as_raw(&self) -> u64 {
+            self.0 as u64
+        }
+
+        /// Rebuilds a handle from the value returned by `as_raw`.
+        pub fn from_raw(raw: u64) -> Self {
+            DispatchHandle(raw as *mut _)
+        }
+
+        #[inline]
+        fn check(&self) {
+            check(self.0 as u64, type_name::<T>());
+        }
     }
 
     impl<T> Clone for DispatchHandle<T> {
@@ -201,19 +781,22 @@ mod dispatch {
     impl<T> ops::Deref for DispatchHandle<T> {
         type Target = T;
         fn deref(&self) -> &T {
-            unsafe { &(*self.0).1 }
+            self.check();
+            unsafe { &(*self.0).value }
         }
     }
 
     impl<T> ops::DerefMut for DispatchHandle<T> {
         fn deref_mut(&mut self) -> &mut T {
-            unsafe { &mut (*self.0).1 }
+            self.check();
+            unsafe { &mut (*self.0).value }
         }
     }
 
     impl<T> borrow::Borrow<T> for DispatchHandle<T> {
         fn borrow(&self) -> &T {
-            unsafe { &(*self.0).1 }
+            self.check();
+            unsafe { &(*self.0).value }
         }
     }
 
@@ -228,4 +811,98 @@ mod dispatch {
             write!(formatter, "DispatchHandle({:p})", self.0)
         }
     }
+
+    impl<T> crate::api_dump::Dump for DispatchHandle<T> {
+        fn dump(&self, out: &mut String) {
+            out.push_str(&format!("{:?}", self));
+        }
+    }
+
+    impl<T: 'static> crate::trace::Traced for DispatchHandle<T> {
+        unsafe fn write(&self, writer: &mut crate::trace::Writer) {
+            writer.u64(self.as_raw());
+        }
+
+        unsafe fn read(reader: &mut crate::trace::Reader) -> Self {
+            DispatchHandle::from_raw(reader.handle())
+        }
+
+        fn bind(&self, replayed: &Self, reader: &mut crate::trace::Reader) {
+            reader.bind(self.as_raw(), replayed.as_raw());
+        }
+
+        fn raw(&self) -> u64 {
+            self.as_raw()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Handle;
+
+    /// Types of the same name in a backend module and in the `empty` one,
+    /// which the entry points check the handles with.
+    mod backend {
+        pub struct Image(pub u32);
+    }
+    mod empty {
+        pub struct Image(pub u32);
+    }
+
+    #[test]
+    fn entry_point_types() {
+        let handle = Handle::new(backend::Image(1));
+        let entry = Handle::<empty::Image>::from_raw(handle.as_raw());
+        assert!(entry.is_valid());
+        assert_eq!(handle.unbox().map(|image| image.0), Some(1));
+        if cfg!(feature = "generational-handles") {
+            assert!(!entry.is_valid());
+        }
+    }
+
+    #[cfg(feature = "generational-handles")]
+    mod slab {
+        use super::super::slab;
+
+        #[test]
+        fn stale_handles() {
+            let first = slab::insert(0x10, "first");
+            assert_eq!(slab::lookup(first, "first"), Some(0x10));
+            assert!(slab::is_live(first));
+            assert_eq!(slab::remove(first, "first"), Some(0x10));
+
+            assert!(!slab::is_live(first));
+            assert_eq!(slab::lookup(first, "first"), None);
+            assert_eq!(slab::remove(first, "first"), None);
+
+            // The other tests may take the freed slot first.
+            let second = slab::insert(0x20, "second");
+            assert_ne!(first, second);
+            if second as u32 == first as u32 {
+                assert_eq!(second >> 32, (first >> 32) + 1);
+            }
+            assert_eq!(slab::lookup(first, "first"), None);
+            assert_eq!(slab::lookup(second, "second"), Some(0x20));
+            assert_eq!(slab::remove(second, "second"), Some(0x20));
+        }
+
+        #[test]
+        fn null_handle() {
+            assert!(!slab::is_live(0));
+            assert_eq!(slab::lookup(0, "null"), None);
+            assert_eq!(slab::remove(0, "null"), None);
+        }
+
+        #[test]
+        fn object_types() {
+            let raw = slab::insert(0x30, "image");
+            assert!(slab::is_live(raw));
+            if cfg!(debug_assertions) {
+                assert_eq!(slab::lookup(raw, "buffer"), None);
+            }
+            assert_eq!(slab::remove(raw, "buffer"), None);
+            assert_eq!(slab::remove(raw, "image"), Some(0x30));
+        }
+    }
 }
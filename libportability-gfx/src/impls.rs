@@ -14,15 +14,30 @@ use hal::{
 use std::{
     borrow::{Borrow, Cow},
     cell::Cell,
-    env,
     ffi::{CStr, CString},
-    mem,
-    os::raw::{c_int, c_void},
+    iter, mem,
+    os::raw::{c_char, c_int, c_void},
     ptr,
 };
 
-const VERSION: (u32, u32, u32) = (1, 0, 66);
+const VERSION: (u32, u32, u32) = (1, 1, 70);
+const API_VERSION: u32 = (VERSION.0 << 22) | (VERSION.1 << 12) | VERSION.2;
+/// Version of the physical devices. Vulkan 1.1 requires multiview,
+/// which gfx-hal doesn't expose, so they stay at 1.0 under a 1.1 instance.
+const DEVICE_API_VERSION: u32 = VK_API_VERSION_1_0 | VERSION.2;
 const DRIVER_VERSION: u32 = 1;
+const DRIVER_UUID: [u8; VK_UUID_SIZE as usize] = *b"gfx-portability\0";
+/// Guaranteed minimum of `maxPerSetDescriptors`, there is no way to query it from gfx-hal.
+const MAX_PER_SET_DESCRIPTORS: u32 = 1024;
+
+/// Copies a string into a fixed-size array, truncating it to keep the terminator.
+fn copy_c_str(dst: &mut [c_char], src: &str) {
+    let len = src.len().min(dst.len() - 1);
+    for (d, &s) in dst.iter_mut().zip(&src.as_bytes()[..len]) {
+        *d = s as c_char;
+    }
+    dst[len] = 0;
+}
 
 unsafe fn make_slice<'a, T: 'a>(pointer: *const T, count: usize) -> &'a [T] {
     if count == 0 {
@@ -54,77 +69,146 @@ fn map_alloc_error(alloc_error: hal::device::AllocationError) -> VkResult {
     }
 }
 
-#[macro_export]
-macro_rules! proc_addr {
-    ($name:expr, $($vk:ident, $pfn_vk:ident => $gfx:expr,)*) => (
-        match $name {
-            $(
-                stringify!($vk) => mem::transmute::<$pfn_vk, _>(Some(*&$gfx)),
-            )*
-            _ => None
+fn map_bind_error(bind_error: hal::device::BindError) -> VkResult {
+    match bind_error {
+        hal::device::BindError::OutOfMemory(oom) => map_oom(oom),
+        other => {
+            error!("Unable to bind memory: {:?}", other);
+            VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY
         }
-    );
+    }
+}
+
+fn map_map_error(map_error: hal::device::MapError) -> VkResult {
+    match map_error {
+        hal::device::MapError::OutOfMemory(oom) => map_oom(oom),
+        other => {
+            error!("Unable to map memory: {:?}", other);
+            VkResult::VK_ERROR_MEMORY_MAP_FAILED
+        }
+    }
+}
+
+fn map_buffer_creation_error(creation_error: hal::buffer::CreationError) -> VkResult {
+    match creation_error {
+        hal::buffer::CreationError::OutOfMemory(oom) => map_oom(oom),
+        other => {
+            // Not a legal result of `vkCreateBuffer`, which only fails with OOM.
+            error!("Unable to create buffer: {:?}", other);
+            VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY
+        }
+    }
+}
+
+/// Neither `vkCreateImage` nor `vkCreateImageView` may fail for other reasons
+/// than OOM, so the actual reason is reported as a validation error.
+fn map_image_creation_error(device: u64, creation_error: hal::image::CreationError) -> VkResult {
+    match creation_error {
+        hal::image::CreationError::OutOfMemory(oom) => map_oom(oom),
+        other => {
+            validation::error(
+                VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+                device,
+                &format!("vkCreateImage: {:?}", other),
+            );
+            VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY
+        }
+    }
+}
+
+fn map_view_creation_error(device: u64, creation_error: hal::image::ViewCreationError) -> VkResult {
+    match creation_error {
+        hal::image::ViewCreationError::OutOfMemory(oom) => map_oom(oom),
+        other => {
+            validation::error(
+                VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+                device,
+                &format!("vkCreateImageView: {:?}", other),
+            );
+            VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY
+        }
+    }
+}
+
+fn map_shader_error(shader_error: hal::device::ShaderError) -> VkResult {
+    match shader_error {
+        hal::device::ShaderError::OutOfMemory(oom) => map_oom(oom),
+        other => {
+            error!("Unable to create shader module: {:?}", other);
+            VkResult::VK_ERROR_INVALID_SHADER_NV
+        }
+    }
+}
+
+/// Allocates the storage for a handle with the application-provided callbacks,
+/// returning `VK_ERROR_OUT_OF_HOST_MEMORY` from the caller on failure.
+macro_rules! alloc_handle {
+    ($handle:ident, $allocator:expr, $scope:ident) => {
+        match $handle::alloc_in($allocator, VkSystemAllocationScope::$scope) {
+            Some(allocation) => allocation,
+            None => return VkResult::VK_ERROR_OUT_OF_HOST_MEMORY,
+        }
+    };
 }
 
 #[inline]
 pub unsafe extern "C" fn gfxCreateInstance(
     pCreateInfo: *const VkInstanceCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pInstance: *mut VkInstance,
 ) -> VkResult {
-    #[cfg(feature = "env_logger")]
-    {
-        let _ = env_logger::try_init();
-        let backend = if cfg!(feature = "gfx-backend-vulkan") {
-            "Vulkan"
-        } else if cfg!(feature = "gfx-backend-dx12") {
-            "DX12"
-        } else if cfg!(feature = "gfx-backend-metal") {
-            "Metal"
-        } else {
-            "Other"
-        };
-        println!("gfx-portability backend: {}", backend);
+    debug::init_logger();
+    let create_info = &*pCreateInfo;
+    let application_info = create_info.pApplicationInfo.as_ref();
+    let settings = crate::config::for_application(application_info);
+    crate::config::report_warnings();
+    let workarounds = &settings.workarounds;
+    if *workarounds != Default::default() {
+        info!("Applying the workarounds {:?}", workarounds);
     }
+    #[cfg(feature = "env_logger")]
+    println!("gfx-portability backend: {}", BACKEND.name());
+
+    let instance = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_INSTANCE);
 
     #[allow(unused_mut)]
     // Metal branch performs mutation, so we silence the warning on other backends.
-    let mut backend =
-        back::Instance::create("portability", 1).expect("failed to create backend instance");
+    let mut backend = match back::Instance::create("portability", 1) {
+        Ok(backend) => backend,
+        Err(_) => {
+            error!("Unable to create the {} backend instance", BACKEND.name());
+            return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+        }
+    };
 
-    #[cfg(feature = "gfx-backend-metal")]
-    {
-        if let Ok(value) = env::var("GFX_METAL_ARGUMENTS") {
-            backend.experiments.argument_buffers = match value.to_lowercase().as_str() {
-                "yes" => true,
-                "no" => false,
-                other => panic!("unknown arguments option: {}", other),
-            };
+    if_backend!([metal] => {
+        if let Some(arguments) = settings.metal.arguments {
+            backend.experiments.argument_buffers = arguments;
             println!(
                 "GFX: arguments override {:?}",
                 backend.experiments.argument_buffers
             );
         }
-    }
-
-    let adapters = backend
-        .enumerate_adapters()
-        .into_iter()
-        .map(Handle::new)
-        .collect();
-
-    let create_info = &*pCreateInfo;
-    let application_info = create_info.pApplicationInfo.as_ref();
+    });
 
-    if let Some(ai) = application_info {
-        // Compare major and minor parts of version only - patch is ignored
-        let (supported_major, supported_minor, _) = VERSION;
-        let requested_major_minor = ai.apiVersion >> 12;
-        let version_supported = requested_major_minor & (supported_major << 10 | supported_minor)
-            == requested_major_minor;
-        if !version_supported {
-            return VkResult::VK_ERROR_INCOMPATIBLE_DRIVER;
-        }
+    // Compare major and minor parts of version only - patch is ignored.
+    // Unlike 1.0, a 1.1 implementation has to accept any newer version,
+    // and the application is then limited to what `vkEnumerateInstanceVersion` reports.
+    let api_version = match application_info.map_or(0, |ai| ai.apiVersion) {
+        0 => VK_API_VERSION_1_0,
+        version => version,
+    };
+    if api_version >> 12 < VK_API_VERSION_1_0 >> 12 {
+        return VkResult::VK_ERROR_INCOMPATIBLE_DRIVER;
+    }
+    if api_version >> 12 > API_VERSION >> 12 {
+        info!(
+            "Requested Vulkan {}.{}, only {}.{} is supported",
+            api_version >> 22,
+            (api_version >> 12) & 0x3ff,
+            VERSION.0,
+            VERSION.1
+        );
     }
 
     let mut enabled_extensions = Vec::new();
@@ -145,10 +229,29 @@ pub unsafe extern "C" fn gfxCreateInstance(
         }
     }
 
-    *pInstance = Handle::new(RawInstance {
+    let mut adapters = Vec::new();
+    for raw in backend.enumerate_adapters() {
+        let scope = VkSystemAllocationScope::VK_SYSTEM_ALLOCATION_SCOPE_INSTANCE;
+        match Handle::alloc_in(pAllocator, scope) {
+            Some(allocation) => adapters.push(allocation.init(Adapter {
+                raw,
+                settings: Arc::clone(&settings),
+                api_version,
+            })),
+            None => {
+                for adapter in adapters {
+                    let _ = adapter.unbox_in(pAllocator);
+                }
+                return VkResult::VK_ERROR_OUT_OF_HOST_MEMORY;
+            }
+        }
+    }
+
+    *pInstance = instance.init(RawInstance {
         backend,
         adapters,
         enabled_extensions,
+        settings,
     });
 
     VkResult::VK_SUCCESS
@@ -157,17 +260,17 @@ pub unsafe extern "C" fn gfxCreateInstance(
 #[inline]
 pub unsafe extern "C" fn gfxDestroyInstance(
     instance: VkInstance,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(i) = instance.unbox() {
+    if instance != Handle::null() {
+        debug::unregister_instance(instance);
+    }
+    if let Some(i) = instance.unbox_in(pAllocator) {
         for adapter in i.adapters {
-            let _ = adapter.unbox();
+            let _ = adapter.unbox_in(pAllocator);
         }
     }
-    #[cfg(feature = "nightly")]
-    {
-        Handle::report_leaks();
-    }
+    leaks::report_instance();
 }
 
 #[inline]
@@ -198,6 +301,41 @@ pub unsafe extern "C" fn gfxEnumeratePhysicalDevices(
     code
 }
 
+#[inline]
+pub unsafe extern "C" fn gfxEnumeratePhysicalDeviceGroups(
+    instance: VkInstance,
+    pPhysicalDeviceGroupCount: *mut u32,
+    pPhysicalDeviceGroupProperties: *mut VkPhysicalDeviceGroupPropertiesKHX,
+) -> VkResult {
+    // Every physical device is a group of its own.
+    let num_adapters = instance.adapters.len();
+
+    // If NULL, number of groups is returned.
+    if pPhysicalDeviceGroupProperties.is_null() {
+        *pPhysicalDeviceGroupCount = num_adapters as _;
+        return VkResult::VK_SUCCESS;
+    }
+
+    let output = make_slice_mut(
+        pPhysicalDeviceGroupProperties,
+        *pPhysicalDeviceGroupCount as _,
+    );
+    let (code, count) = if output.len() < num_adapters {
+        (VkResult::VK_INCOMPLETE, output.len())
+    } else {
+        (VkResult::VK_SUCCESS, num_adapters)
+    };
+
+    for (group, &adapter) in output.iter_mut().zip(&instance.adapters[..count]) {
+        group.physicalDeviceCount = 1;
+        group.physicalDevices[0] = adapter;
+        group.subsetAllocation = VK_FALSE;
+    }
+    *pPhysicalDeviceGroupCount = count as _;
+
+    code
+}
+
 #[inline]
 pub unsafe extern "C" fn gfxGetPhysicalDeviceQueueFamilyProperties(
     adapter: VkPhysicalDevice,
@@ -269,6 +407,53 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceFeatures(
     let features = adapter.physical_device.features();
     *pFeatures = conv::features_from_hal(features);
 }
+/// Returns the features of the portability subset supported by the adapter.
+fn portability_subset_features(
+    adapter: &hal::adapter::Adapter<B>,
+) -> VkPhysicalDevicePortabilitySubsetFeaturesKHR {
+    let features = adapter.physical_device.features();
+    let mut data = VkPhysicalDevicePortabilitySubsetFeaturesKHR {
+        sType: VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR,
+        pNext: ptr::null(),
+        constantAlphaColorBlendFactors: VK_FALSE,
+        events: VK_TRUE,
+        imageViewFormatReinterpretation: VK_FALSE,
+        imageViewFormatSwizzle: VK_FALSE,
+        imageView2DOn3DImage: VK_FALSE,
+        multisampleArrayImage: VK_FALSE,
+        mutableComparisonSamplers: VK_FALSE,
+        pointPolygons: VK_FALSE,
+        samplerMipLodBias: VK_FALSE,
+        separateStencilMaskRef: VK_FALSE,
+        shaderSampleRateInterpolationFunctions: VK_FALSE,
+        tessellationIsolines: VK_FALSE,
+        tessellationPointMode: VK_FALSE,
+        triangleFans: VK_FALSE,
+        vertexAttributeAccessBeyondStride: VK_FALSE,
+    };
+    if features.contains(hal::Features::TRIANGLE_FAN) {
+        data.triangleFans = VK_TRUE;
+    }
+    if features.contains(hal::Features::SEPARATE_STENCIL_REF_VALUES) {
+        data.separateStencilMaskRef = VK_TRUE;
+    }
+    if features.contains(hal::Features::SAMPLER_MIP_LOD_BIAS) {
+        data.samplerMipLodBias = VK_TRUE;
+    }
+    if features.contains(hal::Features::MUTABLE_COMPARISON_SAMPLER) {
+        data.mutableComparisonSamplers = VK_TRUE;
+    }
+    //TODO: turn these into a feature flags
+    if_backend!([metal] => {
+        data.constantAlphaColorBlendFactors = VK_TRUE;
+        data.imageViewFormatReinterpretation = VK_TRUE;
+    });
+    if_backend!([dx12] => {
+        data.vertexAttributeAccessBeyondStride = VK_TRUE;
+    });
+    data
+}
+
 #[inline]
 pub unsafe extern "C" fn gfxGetPhysicalDeviceFeatures2KHR(
     adapter: VkPhysicalDevice,
@@ -287,32 +472,28 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceFeatures2KHR(
                 let data = (ptr as *mut VkPhysicalDevicePortabilitySubsetFeaturesKHR)
                     .as_mut()
                     .unwrap();
-                data.events = VK_TRUE;
-                if features.contains(hal::Features::TRIANGLE_FAN) {
-                    data.triangleFans = VK_TRUE;
-                }
-                if features.contains(hal::Features::SEPARATE_STENCIL_REF_VALUES) {
-                    data.separateStencilMaskRef = VK_TRUE;
-                }
-                if features.contains(hal::Features::SAMPLER_MIP_LOD_BIAS) {
-                    data.samplerMipLodBias = VK_TRUE;
-                }
-                if features.contains(hal::Features::MUTABLE_COMPARISON_SAMPLER) {
-                    data.mutableComparisonSamplers = VK_TRUE;
-                }
-                //TODO: turn these into a feature flags
-                if cfg!(feature = "gfx-backend-metal") {
-                    data.constantAlphaColorBlendFactors = VK_TRUE;
-                    data.imageViewFormatReinterpretation = VK_TRUE;
-                }
-                if cfg!(feature = "gfx-backend-dx12") {
-                    data.vertexAttributeAccessBeyondStride = VK_TRUE;
-                }
+                *data = VkPhysicalDevicePortabilitySubsetFeaturesKHR {
+                    sType: data.sType,
+                    pNext: data.pNext,
+                    ..portability_subset_features(&adapter)
+                };
                 data.pNext
             }
             VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES_KHR => {
                 let data = (ptr as *mut VkPhysicalDeviceImagelessFramebufferFeaturesKHR).as_mut().unwrap();
-                data.imagelessFramebuffer = true;
+                data.imagelessFramebuffer = supports_graphics(&adapter);
+                data.pNext
+            }
+            VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_FEATURES => {
+                let data = (ptr as *mut VkPhysicalDeviceVulkan11Features)
+                    .as_mut()
+                    .unwrap();
+                // None of the optional 1.1 features can be expressed through gfx-hal yet.
+                *data = VkPhysicalDeviceVulkan11Features {
+                    sType: data.sType,
+                    pNext: data.pNext,
+                    ..mem::zeroed()
+                };
                 data.pNext
             }
             other => {
@@ -435,7 +616,6 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceProperties(
     let adapter_info = &adapter.info;
     let limits = conv::limits_from_hal(adapter.physical_device.properties().limits);
     let sparse_properties = mem::zeroed(); // TODO
-    let (major, minor, patch) = VERSION;
 
     let device_name = {
         let c_string = CString::new(adapter_info.name.clone()).unwrap();
@@ -456,7 +636,7 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceProperties(
     };
 
     *pProperties = VkPhysicalDeviceProperties {
-        apiVersion: (major << 22) | (minor << 12) | patch,
+        apiVersion: DEVICE_API_VERSION,
         driverVersion: DRIVER_VERSION,
         vendorID: adapter_info.vendor as _,
         deviceID: adapter_info.device as _,
@@ -490,6 +670,65 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceProperties2KHR(
                 data.minVertexInputBindingStrideAlignment = limits.min_vertex_input_binding_stride_alignment as u32;
                 data.pNext
             }
+            VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES => {
+                let data = (ptr as *mut VkPhysicalDeviceVulkan11Properties)
+                    .as_mut()
+                    .unwrap();
+                let mut device_uuid = [0; VK_UUID_SIZE as usize];
+                device_uuid[..4].copy_from_slice(&(adapter.info.vendor as u32).to_le_bytes());
+                device_uuid[4..8].copy_from_slice(&(adapter.info.device as u32).to_le_bytes());
+                let max_heap_size = adapter
+                    .physical_device
+                    .memory_properties()
+                    .memory_heaps
+                    .iter()
+                    .map(|heap| heap.size)
+                    .max()
+                    .unwrap_or(0);
+                data.deviceUUID = device_uuid;
+                data.driverUUID = DRIVER_UUID;
+                data.deviceLUID = [0; VK_LUID_SIZE_KHX as usize];
+                data.deviceNodeMask = 0;
+                data.deviceLUIDValid = VK_FALSE;
+                // Subgroup operations aren't exposed by gfx-hal, so report
+                // the minimum: every invocation is a subgroup of its own.
+                data.subgroupSize = 1;
+                data.subgroupSupportedStages = VkShaderStageFlagBits::VK_SHADER_STAGE_COMPUTE_BIT as _;
+                data.subgroupSupportedOperations = VkSubgroupFeatureFlagBits::VK_SUBGROUP_FEATURE_BASIC_BIT as _;
+                data.subgroupQuadOperationsInAllStages = VK_FALSE;
+                data.pointClippingBehavior = VkPointClippingBehavior::VK_POINT_CLIPPING_BEHAVIOR_ALL_CLIP_PLANES;
+                data.maxMultiviewViewCount = 0;
+                data.maxMultiviewInstanceIndex = 0;
+                data.protectedNoFault = VK_FALSE;
+                data.maxPerSetDescriptors = MAX_PER_SET_DESCRIPTORS;
+                data.maxMemoryAllocationSize = max_heap_size;
+                data.pNext
+            }
+            VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR => {
+                let data = (ptr as *mut VkPhysicalDeviceDriverPropertiesKHR)
+                    .as_mut()
+                    .unwrap();
+                // No driver ID is registered for gfx-portability, and zero matches
+                // none of the registered ones. The backends are told apart by
+                // the name of the driver instead, e.g. "gfx-portability Vulkan".
+                *(&mut data.driverID as *mut VkDriverIdKHR as *mut u32) = 0;
+                copy_c_str(
+                    &mut data.driverName,
+                    &format!("gfx-portability {}", BACKEND.name()),
+                );
+                copy_c_str(
+                    &mut data.driverInfo,
+                    &format!("{} backend, version {}", BACKEND.name(), env!("CARGO_PKG_VERSION")),
+                );
+                // Not tested for conformance.
+                data.conformanceVersion = VkConformanceVersionKHR {
+                    major: 0,
+                    minor: 0,
+                    subminor: 0,
+                    patch: 0,
+                };
+                data.pNext
+            }
             other => {
                 warn!("Unrecognized {:?}, skipping", other);
                     (ptr as *const VkBaseStruct).as_ref().unwrap()
@@ -549,49 +788,7 @@ pub unsafe extern "C" fn gfxGetInstanceProcAddr(
         return device_addr;
     }
 
-    proc_addr! { name,
-        vkCreateInstance, PFN_vkCreateInstance => gfxCreateInstance,
-        vkDestroyInstance, PFN_vkDestroyInstance => gfxDestroyInstance,
-        vkCreateDevice, PFN_vkCreateDevice => gfxCreateDevice,
-        vkGetDeviceProcAddr, PFN_vkGetDeviceProcAddr => gfxGetDeviceProcAddr,
-
-        vkEnumeratePhysicalDevices, PFN_vkEnumeratePhysicalDevices => gfxEnumeratePhysicalDevices,
-        vkEnumerateInstanceLayerProperties, PFN_vkEnumerateInstanceLayerProperties => gfxEnumerateInstanceLayerProperties,
-        vkEnumerateInstanceExtensionProperties, PFN_vkEnumerateInstanceExtensionProperties => gfxEnumerateInstanceExtensionProperties,
-        vkEnumerateDeviceExtensionProperties, PFN_vkEnumerateDeviceExtensionProperties => gfxEnumerateDeviceExtensionProperties,
-        vkEnumerateDeviceLayerProperties, PFN_vkEnumerateDeviceLayerProperties => gfxEnumerateDeviceLayerProperties,
-
-        vkGetPhysicalDeviceFeatures, PFN_vkGetPhysicalDeviceFeatures => gfxGetPhysicalDeviceFeatures,
-        vkGetPhysicalDeviceFeatures2KHR, PFN_vkGetPhysicalDeviceFeatures2KHR => gfxGetPhysicalDeviceFeatures2KHR,
-        vkGetPhysicalDeviceProperties, PFN_vkGetPhysicalDeviceProperties => gfxGetPhysicalDeviceProperties,
-        vkGetPhysicalDeviceProperties2KHR, PFN_vkGetPhysicalDeviceProperties2KHR => gfxGetPhysicalDeviceProperties2KHR,
-        vkGetPhysicalDeviceFormatProperties, PFN_vkGetPhysicalDeviceFormatProperties => gfxGetPhysicalDeviceFormatProperties,
-        vkGetPhysicalDeviceFormatProperties2KHR, PFN_vkGetPhysicalDeviceFormatProperties2KHR => gfxGetPhysicalDeviceFormatProperties2KHR,
-        vkGetPhysicalDeviceImageFormatProperties, PFN_vkGetPhysicalDeviceImageFormatProperties => gfxGetPhysicalDeviceImageFormatProperties,
-        vkGetPhysicalDeviceImageFormatProperties2KHR, PFN_vkGetPhysicalDeviceImageFormatProperties2KHR => gfxGetPhysicalDeviceImageFormatProperties2KHR,
-        vkGetPhysicalDeviceMemoryProperties, PFN_vkGetPhysicalDeviceMemoryProperties => gfxGetPhysicalDeviceMemoryProperties,
-        vkGetPhysicalDeviceMemoryProperties2KHR, PFN_vkGetPhysicalDeviceMemoryProperties2KHR => gfxGetPhysicalDeviceMemoryProperties2KHR,
-        vkGetPhysicalDeviceQueueFamilyProperties, PFN_vkGetPhysicalDeviceQueueFamilyProperties => gfxGetPhysicalDeviceQueueFamilyProperties,
-        vkGetPhysicalDeviceQueueFamilyProperties2KHR, PFN_vkGetPhysicalDeviceQueueFamilyProperties2KHR => gfxGetPhysicalDeviceQueueFamilyProperties2KHR,
-        vkGetPhysicalDeviceSparseImageFormatProperties, PFN_vkGetPhysicalDeviceSparseImageFormatProperties => gfxGetPhysicalDeviceSparseImageFormatProperties,
-        vkGetPhysicalDeviceSparseImageFormatProperties2KHR, PFN_vkGetPhysicalDeviceSparseImageFormatProperties2KHR => gfxGetPhysicalDeviceSparseImageFormatProperties2KHR,
-
-        vkGetPhysicalDeviceSurfaceSupportKHR, PFN_vkGetPhysicalDeviceSurfaceSupportKHR => gfxGetPhysicalDeviceSurfaceSupportKHR,
-        vkGetPhysicalDeviceSurfaceCapabilitiesKHR, PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR => gfxGetPhysicalDeviceSurfaceCapabilitiesKHR,
-        vkGetPhysicalDeviceSurfaceCapabilities2KHR, PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR => gfxGetPhysicalDeviceSurfaceCapabilities2KHR,
-        vkGetPhysicalDeviceSurfaceFormatsKHR, PFN_vkGetPhysicalDeviceSurfaceFormatsKHR => gfxGetPhysicalDeviceSurfaceFormatsKHR,
-        vkGetPhysicalDeviceSurfaceFormats2KHR, PFN_vkGetPhysicalDeviceSurfaceFormats2KHR => gfxGetPhysicalDeviceSurfaceFormats2KHR,
-        vkGetPhysicalDeviceSurfacePresentModesKHR, PFN_vkGetPhysicalDeviceSurfacePresentModesKHR => gfxGetPhysicalDeviceSurfacePresentModesKHR,
-        vkGetPhysicalDeviceWin32PresentationSupportKHR, PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR => gfxGetPhysicalDeviceWin32PresentationSupportKHR,
-
-        vkCreateXlibSurfaceKHR, PFN_vkCreateXlibSurfaceKHR => gfxCreateXlibSurfaceKHR,
-        vkCreateXcbSurfaceKHR, PFN_vkCreateXcbSurfaceKHR => gfxCreateXcbSurfaceKHR,
-        vkCreateWin32SurfaceKHR, PFN_vkCreateWin32SurfaceKHR => gfxCreateWin32SurfaceKHR,
-        vkCreateMetalSurfaceEXT, PFN_vkCreateMetalSurfaceEXT => gfxCreateMetalSurfaceEXT,
-        vkCreateMacOSSurfaceMVK, PFN_vkCreateMacOSSurfaceMVK => gfxCreateMacOSSurfaceMVK,
-
-        vkDestroySurfaceKHR, PFN_vkDestroySurfaceKHR => gfxDestroySurfaceKHR,
-    }
+    instance_commands!(name)
 }
 
 #[inline]
@@ -622,257 +819,188 @@ pub unsafe extern "C" fn gfxGetDeviceProcAddr(
         }
     }
 
-    proc_addr! { name,
-        vkGetDeviceProcAddr, PFN_vkGetDeviceProcAddr => gfxGetDeviceProcAddr,
-        vkDestroyDevice, PFN_vkDestroyDevice => gfxDestroyDevice,
-        vkGetDeviceMemoryCommitment, PFN_vkGetDeviceMemoryCommitment => gfxGetDeviceMemoryCommitment,
-
-        vkCreateSwapchainKHR, PFN_vkCreateSwapchainKHR => gfxCreateSwapchainKHR,
-        vkDestroySwapchainKHR, PFN_vkDestroySwapchainKHR => gfxDestroySwapchainKHR,
-        vkGetSwapchainImagesKHR, PFN_vkGetSwapchainImagesKHR => gfxGetSwapchainImagesKHR,
-        vkAcquireNextImageKHR, PFN_vkAcquireNextImageKHR => gfxAcquireNextImageKHR,
-        vkQueuePresentKHR, PFN_vkQueuePresentKHR => gfxQueuePresentKHR,
-
-        vkCreateSampler, PFN_vkCreateSampler => gfxCreateSampler,
-        vkDestroySampler, PFN_vkDestroySampler => gfxDestroySampler,
-        vkCreateShaderModule, PFN_vkCreateShaderModule => gfxCreateShaderModule,
-        vkDestroyShaderModule, PFN_vkDestroyShaderModule => gfxDestroyShaderModule,
-        vkGetDeviceQueue, PFN_vkGetDeviceQueue => gfxGetDeviceQueue,
-
-        vkAllocateMemory, PFN_vkAllocateMemory => gfxAllocateMemory,
-        vkFreeMemory, PFN_vkFreeMemory => gfxFreeMemory,
-        vkMapMemory, PFN_vkMapMemory => gfxMapMemory,
-        vkUnmapMemory, PFN_vkUnmapMemory => gfxUnmapMemory,
-        vkFlushMappedMemoryRanges, PFN_vkFlushMappedMemoryRanges => gfxFlushMappedMemoryRanges,
-        vkInvalidateMappedMemoryRanges, PFN_vkInvalidateMappedMemoryRanges => gfxInvalidateMappedMemoryRanges,
-
-        vkCreateBuffer, PFN_vkCreateBuffer => gfxCreateBuffer,
-        vkDestroyBuffer, PFN_vkDestroyBuffer => gfxDestroyBuffer,
-        vkGetBufferMemoryRequirements, PFN_vkGetBufferMemoryRequirements => gfxGetBufferMemoryRequirements,
-        vkBindBufferMemory, PFN_vkBindBufferMemory => gfxBindBufferMemory,
-        vkCreateBufferView, PFN_vkCreateBufferView => gfxCreateBufferView,
-        vkDestroyBufferView, PFN_vkDestroyBufferView => gfxDestroyBufferView,
-
-        vkCreateImage, PFN_vkCreateImage => gfxCreateImage,
-        vkDestroyImage, PFN_vkDestroyImage => gfxDestroyImage,
-        vkGetImageMemoryRequirements, PFN_vkGetImageMemoryRequirements => gfxGetImageMemoryRequirements,
-        //vkGetImageMemoryRequirements2KHR, PFN_vkGetImageMemoryRequirements2KHR => gfxGetImageMemoryRequirements2KHR,
-        vkGetImageSparseMemoryRequirements, PFN_vkGetImageSparseMemoryRequirements => gfxGetImageSparseMemoryRequirements,
-        vkBindImageMemory, PFN_vkBindImageMemory => gfxBindImageMemory,
-        vkCreateImageView, PFN_vkCreateImageView => gfxCreateImageView,
-        vkDestroyImageView, PFN_vkDestroyImageView => gfxDestroyImageView,
-        vkGetImageSubresourceLayout, PFN_vkGetImageSubresourceLayout => gfxGetImageSubresourceLayout,
-
-        vkCreateRenderPass, PFN_vkCreateRenderPass => gfxCreateRenderPass,
-        vkDestroyRenderPass, PFN_vkDestroyRenderPass => gfxDestroyRenderPass,
-        vkCreateFramebuffer, PFN_vkCreateFramebuffer => gfxCreateFramebuffer,
-        vkDestroyFramebuffer, PFN_vkDestroyFramebuffer => gfxDestroyFramebuffer,
-        vkGetRenderAreaGranularity, PFN_vkGetRenderAreaGranularity => gfxGetRenderAreaGranularity,
-
-        vkCreatePipelineLayout, PFN_vkCreatePipelineLayout => gfxCreatePipelineLayout,
-        vkDestroyPipelineLayout, PFN_vkDestroyPipelineLayout => gfxDestroyPipelineLayout,
-        vkCreateGraphicsPipelines, PFN_vkCreateGraphicsPipelines => gfxCreateGraphicsPipelines,
-        vkCreateComputePipelines, PFN_vkCreateComputePipelines => gfxCreateComputePipelines,
-        vkDestroyPipeline, PFN_vkDestroyPipeline => gfxDestroyPipeline,
-        vkCreatePipelineCache, PFN_vkCreatePipelineCache => gfxCreatePipelineCache,
-        vkDestroyPipelineCache, PFN_vkDestroyPipelineCache => gfxDestroyPipelineCache,
-        vkGetPipelineCacheData, PFN_vkGetPipelineCacheData => gfxGetPipelineCacheData,
-        vkMergePipelineCaches, PFN_vkMergePipelineCaches => gfxMergePipelineCaches,
-
-        vkCreateCommandPool, PFN_vkCreateCommandPool => gfxCreateCommandPool,
-        vkDestroyCommandPool, PFN_vkDestroyCommandPool => gfxDestroyCommandPool,
-        vkResetCommandPool, PFN_vkResetCommandPool => gfxResetCommandPool,
-        vkTrimCommandPoolKHR, PFN_vkTrimCommandPoolKHR => gfxTrimCommandPoolKHR,
-        vkAllocateCommandBuffers, PFN_vkAllocateCommandBuffers => gfxAllocateCommandBuffers,
-        vkFreeCommandBuffers, PFN_vkFreeCommandBuffers => gfxFreeCommandBuffers,
-        vkBeginCommandBuffer, PFN_vkBeginCommandBuffer => gfxBeginCommandBuffer,
-        vkEndCommandBuffer, PFN_vkEndCommandBuffer => gfxEndCommandBuffer,
-        vkResetCommandBuffer, PFN_vkResetCommandBuffer => gfxResetCommandBuffer,
-
-        vkCreateDescriptorSetLayout, PFN_vkCreateDescriptorSetLayout => gfxCreateDescriptorSetLayout,
-        vkDestroyDescriptorSetLayout, PFN_vkDestroyDescriptorSetLayout => gfxDestroyDescriptorSetLayout,
-        vkCreateDescriptorPool, PFN_vkCreateDescriptorPool => gfxCreateDescriptorPool,
-        vkDestroyDescriptorPool, PFN_vkDestroyDescriptorPool => gfxDestroyDescriptorPool,
-        vkResetDescriptorPool, PFN_vkResetDescriptorPool => gfxResetDescriptorPool,
-        vkAllocateDescriptorSets, PFN_vkAllocateDescriptorSets => gfxAllocateDescriptorSets,
-        vkFreeDescriptorSets, PFN_vkFreeDescriptorSets => gfxFreeDescriptorSets,
-        vkUpdateDescriptorSets, PFN_vkUpdateDescriptorSets => gfxUpdateDescriptorSets,
-
-        vkCreateFence, PFN_vkCreateFence => gfxCreateFence,
-        vkDestroyFence, PFN_vkDestroyFence => gfxDestroyFence,
-        vkWaitForFences, PFN_vkWaitForFences => gfxWaitForFences,
-        vkResetFences, PFN_vkResetFences => gfxResetFences,
-        vkGetFenceStatus, PFN_vkGetFenceStatus => gfxGetFenceStatus,
-
-        vkCreateSemaphore, PFN_vkCreateSemaphore => gfxCreateSemaphore,
-        vkDestroySemaphore, PFN_vkDestroySemaphore => gfxDestroySemaphore,
-
-        vkCreateEvent, PFN_vkCreateEvent => gfxCreateEvent,
-        vkDestroyEvent, PFN_vkDestroyEvent => gfxDestroyEvent,
-        vkGetEventStatus, PFN_vkGetEventStatus => gfxGetEventStatus,
-        vkSetEvent, PFN_vkSetEvent => gfxSetEvent,
-        vkResetEvent, PFN_vkResetEvent => gfxResetEvent,
-
-        vkQueueSubmit, PFN_vkQueueSubmit => gfxQueueSubmit,
-        vkQueueBindSparse, PFN_vkQueueBindSparse => gfxQueueBindSparse,
-        vkQueueWaitIdle, PFN_vkQueueWaitIdle => gfxQueueWaitIdle,
-        vkDeviceWaitIdle, PFN_vkDeviceWaitIdle => gfxDeviceWaitIdle,
-
-        vkCreateQueryPool, PFN_vkCreateQueryPool => gfxCreateQueryPool,
-        vkDestroyQueryPool, PFN_vkDestroyQueryPool => gfxDestroyQueryPool,
-        vkGetQueryPoolResults, PFN_vkGetQueryPoolResults => gfxGetQueryPoolResults,
-
-        vkDebugMarkerSetObjectTagEXT, PFN_vkDebugMarkerSetObjectTagEXT => gfxDebugMarkerSetObjectTagEXT,
-        vkDebugMarkerSetObjectNameEXT, PFN_vkDebugMarkerSetObjectNameEXT => gfxDebugMarkerSetObjectNameEXT,
-
-        vkCmdBindPipeline, PFN_vkCmdBindPipeline => gfxCmdBindPipeline,
-        vkCmdSetViewport, PFN_vkCmdSetViewport => gfxCmdSetViewport,
-        vkCmdSetScissor, PFN_vkCmdSetScissor => gfxCmdSetScissor,
-        vkCmdSetLineWidth, PFN_vkCmdSetLineWidth => gfxCmdSetLineWidth,
-        vkCmdSetDepthBias, PFN_vkCmdSetDepthBias => gfxCmdSetDepthBias,
-        vkCmdSetBlendConstants, PFN_vkCmdSetBlendConstants => gfxCmdSetBlendConstants,
-        vkCmdSetDepthBounds, PFN_vkCmdSetDepthBounds => gfxCmdSetDepthBounds,
-        vkCmdSetStencilCompareMask, PFN_vkCmdSetStencilCompareMask => gfxCmdSetStencilCompareMask,
-        vkCmdSetStencilWriteMask, PFN_vkCmdSetStencilWriteMask => gfxCmdSetStencilWriteMask,
-        vkCmdSetStencilReference, PFN_vkCmdSetStencilReference => gfxCmdSetStencilReference,
-        vkCmdBindDescriptorSets, PFN_vkCmdBindDescriptorSets => gfxCmdBindDescriptorSets,
-        vkCmdBindIndexBuffer, PFN_vkCmdBindIndexBuffer => gfxCmdBindIndexBuffer,
-        vkCmdBindVertexBuffers, PFN_vkCmdBindVertexBuffers => gfxCmdBindVertexBuffers,
-        vkCmdDraw, PFN_vkCmdDraw => gfxCmdDraw,
-        vkCmdDrawIndexed, PFN_vkCmdDrawIndexed => gfxCmdDrawIndexed,
-        vkCmdDrawIndirect, PFN_vkCmdDrawIndirect => gfxCmdDrawIndirect,
-        vkCmdDrawIndexedIndirect, PFN_vkCmdDrawIndexedIndirect => gfxCmdDrawIndexedIndirect,
-        vkCmdDispatch, PFN_vkCmdDispatch => gfxCmdDispatch,
-        vkCmdDispatchIndirect, PFN_vkCmdDispatchIndirect => gfxCmdDispatchIndirect,
-        vkCmdCopyBuffer, PFN_vkCmdCopyBuffer => gfxCmdCopyBuffer,
-        vkCmdCopyImage, PFN_vkCmdCopyImage => gfxCmdCopyImage,
-        vkCmdBlitImage, PFN_vkCmdBlitImage => gfxCmdBlitImage,
-        vkCmdCopyBufferToImage, PFN_vkCmdCopyBufferToImage => gfxCmdCopyBufferToImage,
-        vkCmdCopyImageToBuffer, PFN_vkCmdCopyImageToBuffer => gfxCmdCopyImageToBuffer,
-        vkCmdUpdateBuffer, PFN_vkCmdUpdateBuffer => gfxCmdUpdateBuffer,
-        vkCmdFillBuffer, PFN_vkCmdFillBuffer => gfxCmdFillBuffer,
-        vkCmdClearColorImage, PFN_vkCmdClearColorImage => gfxCmdClearColorImage,
-        vkCmdClearDepthStencilImage, PFN_vkCmdClearDepthStencilImage => gfxCmdClearDepthStencilImage,
-        vkCmdClearAttachments, PFN_vkCmdClearAttachments => gfxCmdClearAttachments,
-        vkCmdResolveImage, PFN_vkCmdResolveImage => gfxCmdResolveImage,
-        vkCmdSetEvent, PFN_vkCmdSetEvent => gfxCmdSetEvent,
-        vkCmdResetEvent, PFN_vkCmdResetEvent => gfxCmdResetEvent,
-        vkCmdWaitEvents, PFN_vkCmdWaitEvents => gfxCmdWaitEvents,
-        vkCmdBeginQuery, PFN_vkCmdBeginQuery => gfxCmdBeginQuery,
-        vkCmdEndQuery, PFN_vkCmdEndQuery => gfxCmdEndQuery,
-        vkCmdResetQueryPool, PFN_vkCmdResetQueryPool => gfxCmdResetQueryPool,
-        vkCmdWriteTimestamp, PFN_vkCmdWriteTimestamp => gfxCmdWriteTimestamp,
-        vkCmdCopyQueryPoolResults, PFN_vkCmdCopyQueryPoolResults => gfxCmdCopyQueryPoolResults,
-        vkCmdPushConstants, PFN_vkCmdPushConstants => gfxCmdPushConstants,
-        vkCmdNextSubpass, PFN_vkCmdNextSubpass => gfxCmdNextSubpass,
-        vkCmdExecuteCommands, PFN_vkCmdExecuteCommands => gfxCmdExecuteCommands,
-        vkCmdPipelineBarrier, PFN_vkCmdPipelineBarrier => gfxCmdPipelineBarrier,
-        vkCmdBeginRenderPass, PFN_vkCmdBeginRenderPass => gfxCmdBeginRenderPass,
-        vkCmdEndRenderPass, PFN_vkCmdEndRenderPass => gfxCmdEndRenderPass,
-        vkCmdDebugMarkerBeginEXT, PFN_vkCmdDebugMarkerBeginEXT => gfxCmdDebugMarkerBeginEXT,
-        vkCmdDebugMarkerEndEXT, PFN_vkCmdDebugMarkerEndEXT => gfxCmdDebugMarkerEndEXT,
-        vkCmdDebugMarkerInsertEXT, PFN_vkCmdDebugMarkerInsertEXT => gfxCmdDebugMarkerInsertEXT,
-    }
+    device_commands!(name)
 }
 
 #[inline]
 pub unsafe extern "C" fn gfxCreateDevice(
     adapter: VkPhysicalDevice,
     pCreateInfo: *const VkDeviceCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pDevice: *mut VkDevice,
 ) -> VkResult {
+    let _object = debug::ObjectScope::enter(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_PHYSICAL_DEVICE_EXT,
+        adapter.as_raw(),
+    );
     let dev_info = &*pCreateInfo;
+    let enabled_extensions = match check_device_extensions(
+        &adapter,
+        make_slice(
+            dev_info.ppEnabledExtensionNames,
+            dev_info.enabledExtensionCount as _,
+        ),
+    ) {
+        Ok(extensions) => extensions,
+        Err(result) => return result,
+    };
+
     let queue_infos = slice::from_raw_parts(
         dev_info.pQueueCreateInfos,
         dev_info.queueCreateInfoCount as _,
     );
-    let max_queue_count = queue_infos
-        .iter()
-        .map(|info| info.queueCount as usize)
-        .max()
-        .unwrap_or(0);
-    let priorities = vec![1.0; max_queue_count];
-    let request_infos = queue_infos
-        .iter()
-        .map(|info| {
-            let family = &adapter.queue_families[info.queueFamilyIndex as usize];
-            (family, &priorities[..info.queueCount as usize])
-        })
-        .collect::<Vec<_>>();
-
-    let enabled = if let Some(ef) = dev_info.pEnabledFeatures.as_ref() {
-        fn feat(on: u32, flag: Features) -> Features {
-            if on != 0 {
-                flag
-            } else {
-                Features::empty()
+    let mut request_infos = Vec::with_capacity(queue_infos.len());
+    for (i, info) in queue_infos.iter().enumerate() {
+        let family = match adapter.queue_families.get(info.queueFamilyIndex as usize) {
+            Some(family) => family,
+            None => {
+                error!("Queue family {} doesn't exist", info.queueFamilyIndex);
+                return VkResult::VK_ERROR_INITIALIZATION_FAILED;
             }
+        };
+        if queue_infos[..i]
+            .iter()
+            .any(|other| other.queueFamilyIndex == info.queueFamilyIndex)
+        {
+            error!("Queue family {} is requested twice", info.queueFamilyIndex);
+            return VkResult::VK_ERROR_INITIALIZATION_FAILED;
         }
-
-        // Attributes on expressions are experimental for now. Use function as workaround.
-        #[rustfmt::skip]
-        fn feats(ef: &VkPhysicalDeviceFeatures) -> Features {
-            feat(ef.robustBufferAccess, Features::ROBUST_BUFFER_ACCESS) |
-            feat(ef.fullDrawIndexUint32, Features::FULL_DRAW_INDEX_U32) |
-            feat(ef.imageCubeArray, Features::IMAGE_CUBE_ARRAY) |
-            feat(ef.independentBlend, Features::INDEPENDENT_BLENDING) |
-            feat(ef.geometryShader, Features::GEOMETRY_SHADER) |
-            feat(ef.tessellationShader, Features::TESSELLATION_SHADER) |
-            feat(ef.sampleRateShading, Features::SAMPLE_RATE_SHADING) |
-            feat(ef.dualSrcBlend, Features::DUAL_SRC_BLENDING) |
-            feat(ef.logicOp, Features::LOGIC_OP) |
-            feat(ef.multiDrawIndirect, Features::MULTI_DRAW_INDIRECT) |
-            feat(ef.drawIndirectFirstInstance, Features::DRAW_INDIRECT_FIRST_INSTANCE) |
-            feat(ef.depthClamp, Features::DEPTH_CLAMP) |
-            feat(ef.depthBiasClamp, Features::DEPTH_BIAS_CLAMP) |
-            feat(ef.fillModeNonSolid, Features::NON_FILL_POLYGON_MODE) |
-            feat(ef.depthBounds, Features::DEPTH_BOUNDS) |
-            feat(ef.wideLines, Features::LINE_WIDTH) |
-            feat(ef.largePoints, Features::POINT_SIZE) |
-            feat(ef.alphaToOne, Features::ALPHA_TO_ONE) |
-            feat(ef.multiViewport, Features::MULTI_VIEWPORTS) |
-            feat(ef.samplerAnisotropy, Features::SAMPLER_ANISOTROPY) |
-            feat(ef.textureCompressionETC2, Features::FORMAT_ETC2) |
-            feat(ef.textureCompressionASTC_LDR, Features::FORMAT_ASTC_LDR) |
-            feat(ef.textureCompressionBC, Features::FORMAT_BC) |
-            feat(ef.occlusionQueryPrecise, Features::PRECISE_OCCLUSION_QUERY) |
-            feat(ef.pipelineStatisticsQuery, Features::PIPELINE_STATISTICS_QUERY) |
-            feat(ef.vertexPipelineStoresAndAtomics, Features::VERTEX_STORES_AND_ATOMICS) |
-            feat(ef.fragmentStoresAndAtomics, Features::FRAGMENT_STORES_AND_ATOMICS) |
-            feat(ef.shaderTessellationAndGeometryPointSize, Features::SHADER_TESSELLATION_AND_GEOMETRY_POINT_SIZE) |
-            feat(ef.shaderImageGatherExtended, Features::SHADER_IMAGE_GATHER_EXTENDED) |
-            feat(ef.shaderStorageImageExtendedFormats, Features::SHADER_STORAGE_IMAGE_EXTENDED_FORMATS) |
-            feat(ef.shaderStorageImageMultisample, Features::SHADER_STORAGE_IMAGE_MULTISAMPLE) |
-            feat(ef.shaderStorageImageReadWithoutFormat, Features::SHADER_STORAGE_IMAGE_READ_WITHOUT_FORMAT) |
-            feat(ef.shaderStorageImageWriteWithoutFormat, Features::SHADER_STORAGE_IMAGE_WRITE_WITHOUT_FORMAT) |
-            feat(ef.shaderUniformBufferArrayDynamicIndexing, Features::SHADER_UNIFORM_BUFFER_ARRAY_DYNAMIC_INDEXING) |
-            feat(ef.shaderSampledImageArrayDynamicIndexing, Features::SHADER_SAMPLED_IMAGE_ARRAY_DYNAMIC_INDEXING) |
-            feat(ef.shaderStorageBufferArrayDynamicIndexing, Features::SHADER_STORAGE_BUFFER_ARRAY_DYNAMIC_INDEXING) |
-            feat(ef.shaderStorageImageArrayDynamicIndexing, Features::SHADER_STORAGE_IMAGE_ARRAY_DYNAMIC_INDEXING) |
-            feat(ef.shaderClipDistance, Features::SHADER_CLIP_DISTANCE) |
-            feat(ef.shaderCullDistance, Features::SHADER_CULL_DISTANCE) |
-            feat(ef.shaderFloat64, Features::SHADER_FLOAT64) |
-            feat(ef.shaderInt64, Features::SHADER_INT64) |
-            feat(ef.shaderInt16, Features::SHADER_INT16) |
-            feat(ef.shaderResourceResidency, Features::SHADER_RESOURCE_RESIDENCY) |
-            feat(ef.shaderResourceMinLod, Features::SHADER_RESOURCE_MIN_LOD) |
-            feat(ef.sparseBinding, Features::SPARSE_BINDING) |
-            feat(ef.sparseResidencyBuffer, Features::SPARSE_RESIDENCY_BUFFER) |
-            feat(ef.sparseResidencyImage2D, Features::SPARSE_RESIDENCY_IMAGE_2D) |
-            feat(ef.sparseResidencyImage3D, Features::SPARSE_RESIDENCY_IMAGE_3D) |
-            feat(ef.sparseResidency2Samples, Features::SPARSE_RESIDENCY_2_SAMPLES) |
-            feat(ef.sparseResidency4Samples, Features::SPARSE_RESIDENCY_4_SAMPLES) |
-            feat(ef.sparseResidency8Samples, Features::SPARSE_RESIDENCY_8_SAMPLES) |
-            feat(ef.sparseResidency16Samples, Features::SPARSE_RESIDENCY_16_SAMPLES) |
-            feat(ef.sparseResidencyAliased, Features::SPARSE_RESIDENCY_ALIASED) |
-            feat(ef.variableMultisampleRate, Features::VARIABLE_MULTISAMPLE_RATE) |
-            feat(ef.inheritedQueries, Features::INHERITED_QUERIES)
+        if info.queueCount == 0 || info.queueCount as usize > family.max_queues() {
+            error!(
+                "Requested {} queues of the family {}, which has {}",
+                info.queueCount,
+                info.queueFamilyIndex,
+                family.max_queues()
+            );
+            return VkResult::VK_ERROR_INITIALIZATION_FAILED;
         }
-        feats(&ef)
-    } else {
-        Features::empty()
-    };
+        let priorities = make_slice(info.pQueuePriorities, info.queueCount as usize);
+        if let Some(priority) = priorities
+            .iter()
+            .find(|&&priority| !(0.0..=1.0).contains(&priority))
+        {
+            error!("Queue priority {} is out of the [0, 1] range", priority);
+            return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+        }
+        request_infos.push((family, priorities));
+    }
+
+    let mut enabled = dev_info
+        .pEnabledFeatures
+        .as_ref()
+        .map_or(Features::empty(), conv::features_to_hal);
+    let mut ptr = dev_info.pNext as *const VkStructureType;
+    while !ptr.is_null() {
+        ptr = match *ptr {
+            VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2_KHR => {
+                let data = (ptr as *const VkPhysicalDeviceFeatures2KHR).as_ref().unwrap();
+                enabled |= conv::features_to_hal(&data.features);
+                data.pNext
+            }
+            VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR => {
+                let data = (ptr as *const VkPhysicalDevicePortabilitySubsetFeaturesKHR)
+                    .as_ref()
+                    .unwrap();
+                let available = portability_subset_features(&adapter);
+                #[rustfmt::skip]
+                let requested = [
+                    (data.constantAlphaColorBlendFactors, available.constantAlphaColorBlendFactors),
+                    (data.events, available.events),
+                    (data.imageViewFormatReinterpretation, available.imageViewFormatReinterpretation),
+                    (data.imageViewFormatSwizzle, available.imageViewFormatSwizzle),
+                    (data.imageView2DOn3DImage, available.imageView2DOn3DImage),
+                    (data.multisampleArrayImage, available.multisampleArrayImage),
+                    (data.mutableComparisonSamplers, available.mutableComparisonSamplers),
+                    (data.pointPolygons, available.pointPolygons),
+                    (data.samplerMipLodBias, available.samplerMipLodBias),
+                    (data.separateStencilMaskRef, available.separateStencilMaskRef),
+                    (data.shaderSampleRateInterpolationFunctions, available.shaderSampleRateInterpolationFunctions),
+                    (data.tessellationIsolines, available.tessellationIsolines),
+                    (data.tessellationPointMode, available.tessellationPointMode),
+                    (data.triangleFans, available.triangleFans),
+                    (data.vertexAttributeAccessBeyondStride, available.vertexAttributeAccessBeyondStride),
+                ];
+                if requested.iter().any(|&(on, available)| on != 0 && available == 0) {
+                    error!("Portability subset features {:?} are not supported", data);
+                    return VkResult::VK_ERROR_FEATURE_NOT_PRESENT;
+                }
+                // The ones that gfx-hal only allows once enabled.
+                if data.triangleFans != 0 {
+                    enabled |= Features::TRIANGLE_FAN;
+                }
+                if data.separateStencilMaskRef != 0 {
+                    enabled |= Features::SEPARATE_STENCIL_REF_VALUES;
+                }
+                if data.samplerMipLodBias != 0 {
+                    enabled |= Features::SAMPLER_MIP_LOD_BIAS;
+                }
+                if data.mutableComparisonSamplers != 0 {
+                    enabled |= Features::MUTABLE_COMPARISON_SAMPLER;
+                }
+                data.pNext
+            }
+            VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES_KHR => {
+                let data = (ptr as *const VkPhysicalDeviceImagelessFramebufferFeaturesKHR)
+                    .as_ref()
+                    .unwrap();
+                if data.imagelessFramebuffer && !supports_graphics(&adapter) {
+                    error!("Imageless framebuffers are not supported");
+                    return VkResult::VK_ERROR_FEATURE_NOT_PRESENT;
+                }
+                data.pNext
+            }
+            VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_FEATURES => {
+                let data = (ptr as *const VkPhysicalDeviceVulkan11Features)
+                    .as_ref()
+                    .unwrap();
+                // None of them is supported, see `gfxGetPhysicalDeviceFeatures2KHR`.
+                let requested = [
+                    data.storageBuffer16BitAccess,
+                    data.uniformAndStorageBuffer16BitAccess,
+                    data.storagePushConstant16,
+                    data.storageInputOutput16,
+                    data.multiview,
+                    data.multiviewGeometryShader,
+                    data.multiviewTessellationShader,
+                    data.variablePointersStorageBuffer,
+                    data.variablePointers,
+                    data.protectedMemory,
+                    data.samplerYcbcrConversion,
+                    data.shaderDrawParameters,
+                ];
+                if requested.iter().any(|&on| on != 0) {
+                    error!("Vulkan 1.1 features {:?} are not supported", data);
+                    return VkResult::VK_ERROR_FEATURE_NOT_PRESENT;
+                }
+                data.pNext
+            }
+            other => {
+                warn!("Unrecognized {:?}, skipping", other);
+                (ptr as *const VkBaseStruct).as_ref().unwrap().pNext
+            }
+        } as *const VkStructureType;
+    }
+    let supported = adapter.physical_device.features();
+    if !supported.contains(enabled) {
+        error!("Features {:?} are not supported", enabled - supported);
+        return VkResult::VK_ERROR_FEATURE_NOT_PRESENT;
+    }
+
+    // Reserve the storage for the device and all of its queues up front,
+    // so that we don't have to unwind the opened device on host OOM.
+    let device_allocation = alloc_handle!(
+        DispatchHandle,
+        pAllocator,
+        VK_SYSTEM_ALLOCATION_SCOPE_DEVICE
+    );
+    let mut queue_allocations = Vec::new();
+    for _ in 0..queue_infos.iter().map(|info| info.queueCount).sum::<u32>() {
+        queue_allocations.push(alloc_handle!(
+            DispatchHandle,
+            pAllocator,
+            VK_SYSTEM_ALLOCATION_SCOPE_DEVICE
+        ));
+    }
 
     #[cfg(feature = "renderdoc")]
     let mut renderdoc = {
@@ -881,23 +1009,23 @@ pub unsafe extern "C" fn gfxCreateDevice(
     };
 
     let gpu = adapter.physical_device.open(&request_infos, enabled);
+    let dumped = api_dump::is_dumped(adapter.as_raw());
 
     match gpu {
         Ok(mut gpu) => {
-            #[cfg(feature = "gfx-backend-metal")]
-            {
+            if_backend!([metal] => {
+                use crate::config::Recording;
                 use back::OnlineRecording;
 
-                if let Ok(value) = env::var("GFX_METAL_RECORDING") {
-                    gpu.device.online_recording = match value.to_lowercase().as_str() {
-                        "immediate" => OnlineRecording::Immediate,
-                        "deferred" => OnlineRecording::Deferred,
-                        //"remote" => OnlineRecording::Remote(dispatch::QueuePriority::Default),
-                        other => panic!("unknown recording option: {}", other),
+                if let Some(recording) = adapter.settings.metal.recording {
+                    gpu.device.online_recording = match recording {
+                        Recording::Immediate => OnlineRecording::Immediate,
+                        Recording::Deferred => OnlineRecording::Deferred,
+                        //Recording::Remote => OnlineRecording::Remote(dispatch::QueuePriority::Default),
                     };
                     println!("GFX: recording override {:?}", gpu.device.online_recording);
                 }
-            }
+            });
 
             let queues = queue_infos
                 .iter()
@@ -910,10 +1038,15 @@ pub unsafe extern "C" fn gfxCreateDevice(
                         .unwrap()
                         .into_iter()
                         .map(|raw| {
-                            DispatchHandle::new(Queue {
-                                raw,
-                                temp_semaphores: Vec::new(),
-                            })
+                            queue_allocations.pop().unwrap().init(
+                                BACKEND,
+                                dumped,
+                                Queue {
+                                    raw,
+                                    temp_semaphores: Vec::new(),
+                                    labels: Vec::new(),
+                                },
+                            )
                         })
                         .collect();
 
@@ -930,35 +1063,21 @@ pub unsafe extern "C" fn gfxCreateDevice(
                 rd_device
             };
 
-            let mut enabled_extensions = Vec::new();
-            if dev_info.enabledExtensionCount != 0 {
-                for raw in slice::from_raw_parts(
-                    dev_info.ppEnabledExtensionNames,
-                    dev_info.enabledExtensionCount as _,
-                ) {
-                    let cstr = CStr::from_ptr(*raw);
-                    if !DEVICE_EXTENSIONS
-                        .iter()
-                        .any(|&(ref name, _)| name == &cstr.to_bytes_with_nul())
-                    {
-                        return VkResult::VK_ERROR_EXTENSION_NOT_PRESENT;
-                    }
-                    let owned = cstr.to_str().expect("Invalid extension name").to_owned();
-                    enabled_extensions.push(owned);
-                }
-            }
-
             let gpu = Gpu {
+                adapter,
+                settings: Arc::clone(&adapter.settings),
                 device: gpu.device,
                 queues,
+                queue_family_count: adapter.queue_families.len(),
                 enabled_extensions,
+                memory_types: adapter.physical_device.memory_properties().memory_types,
                 #[cfg(feature = "renderdoc")]
                 renderdoc,
                 #[cfg(feature = "renderdoc")]
                 capturing: rd_device as *mut _,
             };
 
-            *pDevice = DispatchHandle::new(gpu);
+            *pDevice = device_allocation.init(BACKEND, dumped, gpu);
 
             VkResult::VK_SUCCESS
         }
@@ -970,12 +1089,9 @@ pub unsafe extern "C" fn gfxCreateDevice(
 }
 
 #[inline]
-pub unsafe extern "C" fn gfxDestroyDevice(
-    gpu: VkDevice,
-    _pAllocator: *const VkAllocationCallbacks,
-) {
+pub unsafe extern "C" fn gfxDestroyDevice(gpu: VkDevice, pAllocator: *const VkAllocationCallbacks) {
     // release all the owned command queues
-    if let Some(mut d) = gpu.unbox() {
+    if let Some(mut d) = gpu.unbox_in(pAllocator) {
         #[cfg(feature = "renderdoc")]
         {
             use renderdoc::api::RenderDocV100;
@@ -985,10 +1101,16 @@ pub unsafe extern "C" fn gfxDestroyDevice(
 
         for (_, family) in d.queues.drain() {
             for queue in family {
-                let _ = queue.unbox();
+                let _ = queue.unbox_in(pAllocator);
             }
         }
     }
+    leaks::report_device(&[
+        std::any::type_name::<RawInstance>(),
+        std::any::type_name::<Adapter<B>>(),
+        std::any::type_name::<Surface<B>>(),
+        std::any::type_name::<Gpu<B>>(),
+    ]);
 }
 
 const INSTANCE_EXTENSIONS: &[(&'static [u8], u32)] = &[
@@ -1003,6 +1125,11 @@ const INSTANCE_EXTENSIONS: &[(&'static [u8], u32)] = &[
         VK_KHR_XCB_SURFACE_EXTENSION_NAME,
         VK_KHR_XCB_SURFACE_SPEC_VERSION,
     ),
+    #[cfg(target_os = "linux")]
+    (
+        VK_KHR_WAYLAND_SURFACE_EXTENSION_NAME,
+        VK_KHR_WAYLAND_SURFACE_SPEC_VERSION,
+    ),
     #[cfg(target_os = "windows")]
     (
         VK_KHR_WIN32_SURFACE_EXTENSION_NAME,
@@ -1018,6 +1145,10 @@ const INSTANCE_EXTENSIONS: &[(&'static [u8], u32)] = &[
         VK_MVK_MACOS_SURFACE_EXTENSION_NAME,
         VK_MVK_MACOS_SURFACE_SPEC_VERSION,
     ),
+    (
+        VK_EXT_HEADLESS_SURFACE_EXTENSION_NAME,
+        VK_EXT_HEADLESS_SURFACE_SPEC_VERSION,
+    ),
     (
         VK_KHR_GET_PHYSICAL_DEVICE_PROPERTIES_2_EXTENSION_NAME,
         VK_KHR_GET_PHYSICAL_DEVICE_PROPERTIES_2_SPEC_VERSION,
@@ -1026,31 +1157,198 @@ const INSTANCE_EXTENSIONS: &[(&'static [u8], u32)] = &[
         VK_KHR_GET_SURFACE_CAPABILITIES_2_EXTENSION_NAME,
         VK_KHR_GET_SURFACE_CAPABILITIES_2_SPEC_VERSION,
     ),
-];
-
-const DEVICE_EXTENSIONS: &[(&'static [u8], u32)] = &[
-    (
-        VK_KHR_SWAPCHAIN_EXTENSION_NAME,
-        VK_KHR_SWAPCHAIN_SPEC_VERSION,
-    ),
-    (
-        VK_KHR_MAINTENANCE1_EXTENSION_NAME,
-        VK_KHR_MAINTENANCE1_SPEC_VERSION,
-    ),
-    (
-        VK_EXT_DEBUG_MARKER_EXTENSION_NAME,
-        VK_EXT_DEBUG_MARKER_SPEC_VERSION,
-    ),
     (
-        VK_KHR_IMAGELESS_FRAMEBUFFER_EXTENSION_NAME,
-        VK_KHR_IMAGELESS_FRAMEBUFFER_SPEC_VERSION,
+        VK_EXT_DEBUG_UTILS_EXTENSION_NAME,
+        VK_EXT_DEBUG_UTILS_SPEC_VERSION,
     ),
     (
-        VK_KHR_PORTABILITY_SUBSET_EXTENSION_NAME,
-        VK_KHR_PORTABILITY_SUBSET_SPEC_VERSION,
+        VK_EXT_DEBUG_REPORT_EXTENSION_NAME,
+        VK_EXT_DEBUG_REPORT_SPEC_VERSION,
     ),
 ];
 
+/// A device extension, exposed by the adapters supporting it.
+struct DeviceExtension {
+    name: &'static [u8],
+    spec_version: u32,
+    /// Device extensions that have to be enabled along with it,
+    /// besides the ones promoted to the API version of the application.
+    dependencies: &'static [&'static [u8]],
+    /// Version of the core API the extension was promoted to, 0 if it wasn't.
+    promoted: u32,
+    supported: fn(&hal::adapter::Adapter<B>) -> bool,
+}
+
+fn always(_adapter: &hal::adapter::Adapter<B>) -> bool {
+    true
+}
+
+/// Framebuffers are only used by the adapters able to render.
+fn supports_graphics(adapter: &hal::adapter::Adapter<B>) -> bool {
+    adapter
+        .queue_families
+        .iter()
+        .any(|family| family.queue_type().supports_graphics())
+}
+
+/// The portability subset is exposed by the adapters that are not fully conformant.
+fn requires_portability_subset(adapter: &hal::adapter::Adapter<B>) -> bool {
+    let data = portability_subset_features(adapter);
+    let limits = adapter.physical_device.properties().limits;
+    let features = [
+        data.constantAlphaColorBlendFactors,
+        data.events,
+        data.imageViewFormatReinterpretation,
+        data.imageViewFormatSwizzle,
+        data.imageView2DOn3DImage,
+        data.multisampleArrayImage,
+        data.mutableComparisonSamplers,
+        data.pointPolygons,
+        data.samplerMipLodBias,
+        data.separateStencilMaskRef,
+        data.shaderSampleRateInterpolationFunctions,
+        data.tessellationIsolines,
+        data.tessellationPointMode,
+        data.triangleFans,
+        data.vertexAttributeAccessBeyondStride,
+    ];
+    features.contains(&VK_FALSE) || limits.min_vertex_input_binding_stride_alignment > 1
+}
+
+const DEVICE_EXTENSIONS: &[DeviceExtension] = &[
+    DeviceExtension {
+        name: VK_KHR_SWAPCHAIN_EXTENSION_NAME,
+        spec_version: VK_KHR_SWAPCHAIN_SPEC_VERSION,
+        dependencies: &[],
+        promoted: 0,
+        supported: always,
+    },
+    DeviceExtension {
+        name: VK_KHR_MAINTENANCE1_EXTENSION_NAME,
+        spec_version: VK_KHR_MAINTENANCE1_SPEC_VERSION,
+        dependencies: &[],
+        promoted: VK_API_VERSION_1_1,
+        supported: always,
+    },
+    DeviceExtension {
+        name: VK_EXT_DEBUG_MARKER_EXTENSION_NAME,
+        spec_version: VK_EXT_DEBUG_MARKER_SPEC_VERSION,
+        dependencies: &[],
+        promoted: 0,
+        supported: always,
+    },
+    DeviceExtension {
+        name: VK_KHR_IMAGELESS_FRAMEBUFFER_EXTENSION_NAME,
+        spec_version: VK_KHR_IMAGELESS_FRAMEBUFFER_SPEC_VERSION,
+        dependencies: &[],
+        promoted: 0,
+        supported: supports_graphics,
+    },
+    DeviceExtension {
+        name: VK_KHR_GET_MEMORY_REQUIREMENTS_2_EXTENSION_NAME,
+        spec_version: VK_KHR_GET_MEMORY_REQUIREMENTS_2_SPEC_VERSION,
+        dependencies: &[],
+        promoted: VK_API_VERSION_1_1,
+        supported: always,
+    },
+    DeviceExtension {
+        name: VK_KHR_DEDICATED_ALLOCATION_EXTENSION_NAME,
+        spec_version: VK_KHR_DEDICATED_ALLOCATION_SPEC_VERSION,
+        dependencies: &[VK_KHR_GET_MEMORY_REQUIREMENTS_2_EXTENSION_NAME],
+        promoted: VK_API_VERSION_1_1,
+        supported: always,
+    },
+    DeviceExtension {
+        name: VK_KHR_BIND_MEMORY_2_EXTENSION_NAME,
+        spec_version: VK_KHR_BIND_MEMORY_2_SPEC_VERSION,
+        dependencies: &[],
+        promoted: VK_API_VERSION_1_1,
+        supported: always,
+    },
+    DeviceExtension {
+        name: VK_KHR_PORTABILITY_SUBSET_EXTENSION_NAME,
+        spec_version: VK_KHR_PORTABILITY_SUBSET_SPEC_VERSION,
+        dependencies: &[],
+        promoted: 0,
+        supported: requires_portability_subset,
+    },
+    DeviceExtension {
+        name: VK_KHR_DRIVER_PROPERTIES_EXTENSION_NAME,
+        spec_version: VK_KHR_DRIVER_PROPERTIES_SPEC_VERSION,
+        dependencies: &[],
+        promoted: 0,
+        supported: always,
+    },
+];
+
+/// Returns the device extensions exposed by the adapter to the application,
+/// without the ones hidden by its workarounds.
+fn device_extensions(adapter: &Adapter<B>) -> Vec<&'static DeviceExtension> {
+    let hidden = &adapter.settings.workarounds.hidden_extensions;
+    DEVICE_EXTENSIONS
+        .iter()
+        .filter(|extension| (extension.supported)(adapter))
+        .filter(|extension| {
+            let name = &extension.name[..extension.name.len() - 1];
+            !hidden.iter().any(|hidden| hidden.as_bytes() == name)
+        })
+        .collect()
+}
+
+/// Checks the extensions enabled by the application, returning their names.
+unsafe fn check_device_extensions(
+    adapter: &Adapter<B>,
+    names: &[*const c_char],
+) -> Result<Vec<String>, VkResult> {
+    let available = device_extensions(adapter);
+    let names = names
+        .iter()
+        .map(|&raw| CStr::from_ptr(raw))
+        .collect::<Vec<_>>();
+    for name in &names {
+        let extension = match available
+            .iter()
+            .find(|extension| extension.name == name.to_bytes_with_nul())
+        {
+            Some(extension) => extension,
+            None => {
+                error!("Extension {:?} is not supported by the device", name);
+                return Err(VkResult::VK_ERROR_EXTENSION_NOT_PRESENT);
+            }
+        };
+        for &dependency in extension.dependencies {
+            let promoted = DEVICE_EXTENSIONS
+                .iter()
+                .find(|extension| extension.name == dependency)
+                .map_or(0, |extension| extension.promoted);
+            if promoted != 0 && adapter.api_version >> 12 >= promoted >> 12 {
+                continue;
+            }
+            if !names
+                .iter()
+                .any(|name| name.to_bytes_with_nul() == dependency)
+            {
+                error!(
+                    "Extension {:?} requires {:?} to be enabled",
+                    name,
+                    CStr::from_bytes_with_nul(dependency).unwrap()
+                );
+                return Err(VkResult::VK_ERROR_EXTENSION_NOT_PRESENT);
+            }
+        }
+    }
+    Ok(names
+        .iter()
+        .map(|name| name.to_str().expect("Invalid extension name").to_owned())
+        .collect())
+}
+
+#[inline]
+pub unsafe extern "C" fn gfxEnumerateInstanceVersion(pApiVersion: *mut u32) -> VkResult {
+    *pApiVersion = API_VERSION;
+    VkResult::VK_SUCCESS
+}
+
 #[inline]
 pub unsafe extern "C" fn gfxEnumerateInstanceExtensionProperties(
     _pLayerName: *const ::std::os::raw::c_char,
@@ -1085,13 +1383,14 @@ pub unsafe extern "C" fn gfxEnumerateInstanceExtensionProperties(
 
 #[inline]
 pub unsafe extern "C" fn gfxEnumerateDeviceExtensionProperties(
-    _physicalDevice: VkPhysicalDevice,
+    adapter: VkPhysicalDevice,
     _pLayerName: *const ::std::os::raw::c_char,
     pPropertyCount: *mut u32,
     pProperties: *mut VkExtensionProperties,
 ) -> VkResult {
     let property_count = &mut *pPropertyCount;
-    let num_extensions = DEVICE_EXTENSIONS.len() as u32;
+    let extensions = device_extensions(&adapter);
+    let num_extensions = extensions.len() as u32;
 
     if pProperties.is_null() {
         *property_count = num_extensions;
@@ -1100,12 +1399,12 @@ pub unsafe extern "C" fn gfxEnumerateDeviceExtensionProperties(
             *property_count = num_extensions;
         }
         let properties = slice::from_raw_parts_mut(pProperties, *property_count as usize);
-        for (property, &(name, specVersion)) in properties.iter_mut().zip(DEVICE_EXTENSIONS) {
+        for (property, extension) in properties.iter_mut().zip(&extensions) {
             let mut extensionName = [0i8; 256];
-            extensionName[..name.len()].copy_from_slice(mem::transmute(name));
+            extensionName[..extension.name.len()].copy_from_slice(mem::transmute(extension.name));
             *property = VkExtensionProperties {
                 extensionName,
-                specVersion,
+                specVersion: extension.spec_version,
             };
         }
 
@@ -1121,7 +1420,7 @@ pub unsafe extern "C" fn gfxEnumerateInstanceLayerProperties(
     pPropertyCount: *mut u32,
     _pProperties: *mut VkLayerProperties,
 ) -> VkResult {
-    warn!("TODO: gfxEnumerateInstanceLayerProperties");
+    // The layers are provided by the entry points, see `api_dump`.
     *pPropertyCount = 0;
 
     VkResult::VK_SUCCESS
@@ -1132,35 +1431,100 @@ pub unsafe extern "C" fn gfxEnumerateDeviceLayerProperties(
     pPropertyCount: *mut u32,
     _pProperties: *mut VkLayerProperties,
 ) -> VkResult {
-    warn!("TODO: gfxEnumerateDeviceLayerProperties");
+    // The layers are provided by the entry points, see `api_dump`.
     *pPropertyCount = 0;
 
     VkResult::VK_SUCCESS
 }
 #[inline]
-pub unsafe extern "C" fn gfxGetDeviceQueue(
+pub unsafe extern "C" fn gfxGetDeviceQueue2(
     gpu: VkDevice,
-    queueFamilyIndex: u32,
-    queueIndex: u32,
+    pQueueInfo: *const VkDeviceQueueInfo2,
     pQueue: *mut VkQueue,
 ) {
-    let queue = gpu.queues.get(&queueFamilyIndex).unwrap()[queueIndex as usize];
-
-    #[cfg(feature = "gfx-backend-metal")]
+    let info = &*pQueueInfo;
+    // Protected queues are never created, and the other queues have no flags.
+    if info.flags != 0 {
+        *pQueue = DispatchHandle::null();
+        return;
+    }
+    gfxGetDeviceQueue(gpu, info.queueFamilyIndex, info.queueIndex, pQueue);
+}
+#[inline]
+pub unsafe extern "C" fn gfxGetDeviceQueue(
+    gpu: VkDevice,
+    queueFamilyIndex: u32,
+    queueIndex: u32,
+    pQueue: *mut VkQueue,
+) {
+    let queue = match gpu
+        .queues
+        .get(&queueFamilyIndex)
+        .and_then(|queues| queues.get(queueIndex as usize))
     {
-        if let Ok(value) = env::var("GFX_METAL_STITCHING") {
+        Some(&queue) => queue,
+        None => {
+            error!(
+                "Queue {} of the family {} was not created with the device",
+                queueIndex, queueFamilyIndex
+            );
+            *pQueue = DispatchHandle::null();
+            return;
+        }
+    };
+
+    if_backend!([metal] => {
+        if let Some(stitching) = gpu.settings.metal.stitching {
             let mut q = queue;
-            q.raw.stitch_deferred = match value.to_lowercase().as_str() {
-                "yes" => true,
-                "no" => false,
-                other => panic!("unknown stitching option: {}", other),
-            };
+            q.raw.stitch_deferred = stitching;
             println!("GFX: stitching override {:?}", q.raw.stitch_deferred);
         }
-    }
+    });
 
     *pQueue = queue;
 }
+/// Checks the liveness of all the objects referenced by the submissions,
+/// and the states of the command buffers.
+unsafe fn validate_submits(submits: &[VkSubmitInfo], fence: VkFence) -> bool {
+    use super::VkDebugReportObjectTypeEXT::*;
+
+    let mut valid = fence == Handle::null()
+        || validation::check_alive(
+            VK_DEBUG_REPORT_OBJECT_TYPE_FENCE_EXT,
+            fence.as_raw(),
+            "vkQueueSubmit",
+        );
+    for submission in submits {
+        let semaphores = make_slice(
+            submission.pWaitSemaphores,
+            submission.waitSemaphoreCount as usize,
+        )
+        .iter()
+        .chain(make_slice(
+            submission.pSignalSemaphores,
+            submission.signalSemaphoreCount as usize,
+        ));
+        for semaphore in semaphores {
+            valid &= validation::check_alive(
+                VK_DEBUG_REPORT_OBJECT_TYPE_SEMAPHORE_EXT,
+                semaphore.as_raw(),
+                "vkQueueSubmit",
+            );
+        }
+        let cmd_buffers = make_slice(
+            submission.pCommandBuffers,
+            submission.commandBufferCount as usize,
+        );
+        for cmd_buf in cmd_buffers {
+            valid &= validation::check_alive(
+                VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT,
+                cmd_buf.as_raw(),
+                "vkQueueSubmit",
+            ) && validation::submit_command_buffer(*cmd_buf, "vkQueueSubmit");
+        }
+    }
+    valid
+}
 #[inline]
 pub unsafe extern "C" fn gfxQueueSubmit(
     mut queue: VkQueue,
@@ -1168,6 +1532,11 @@ pub unsafe extern "C" fn gfxQueueSubmit(
     pSubmits: *const VkSubmitInfo,
     fence: VkFence,
 ) -> VkResult {
+    let _scope = debug::QueueScope::enter(&queue.labels);
+    if validation::is_enabled() && !validate_submits(make_slice(pSubmits, submitCount as _), fence)
+    {
+        return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+    }
     if submitCount == 0 {
         use std::iter::empty;
         // sometimes, all you need is a fence...
@@ -1219,6 +1588,7 @@ pub unsafe extern "C" fn gfxQueueSubmit(
             let Queue {
                 ref mut raw,
                 ref temp_semaphores,
+                ..
             } = *queue;
             raw.submit(
                 cmd_slice.iter().map(|cmd_buf| &**cmd_buf),
@@ -1247,28 +1617,48 @@ pub unsafe extern "C" fn gfxDeviceWaitIdle(gpu: VkDevice) -> VkResult {
 pub unsafe extern "C" fn gfxAllocateMemory(
     gpu: VkDevice,
     pAllocateInfo: *const VkMemoryAllocateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pMemory: *mut VkDeviceMemory,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pAllocateInfo;
-    let memory = gpu
-        .device
-        .allocate_memory(
-            hal::MemoryTypeId(info.memoryTypeIndex as _),
-            info.allocationSize,
-        )
-        .unwrap(); // TODO:
 
-    *pMemory = Handle::new(memory);
+    let mut ptr = info.pNext as *const VkStructureType;
+    while !ptr.is_null() {
+        ptr = match *ptr {
+            VkStructureType::VK_STRUCTURE_TYPE_MEMORY_DEDICATED_ALLOCATE_INFO_KHR => {
+                // Every allocation is already a separate backend object,
+                // so dedicated allocations need no special treatment.
+                let data = (ptr as *const VkMemoryDedicatedAllocateInfoKHR)
+                    .as_ref()
+                    .unwrap();
+                data.pNext
+            }
+            other => {
+                warn!("Unrecognized {:?}, skipping", other);
+                (ptr as *const VkBaseStruct).as_ref().unwrap().pNext
+            }
+        } as *const VkStructureType;
+    }
+
+    let memory = match gpu.device.allocate_memory(
+        hal::MemoryTypeId(info.memoryTypeIndex as _),
+        info.allocationSize,
+    ) {
+        Ok(memory) => memory,
+        Err(alloc_error) => return map_alloc_error(alloc_error),
+    };
+
+    *pMemory = allocation.init(memory);
     VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxFreeMemory(
     gpu: VkDevice,
     memory: VkDeviceMemory,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(mem) = memory.unbox() {
+    if let Some(mem) = memory.unbox_in(pAllocator) {
         gpu.device.free_memory(mem);
     }
 }
@@ -1289,9 +1679,16 @@ pub unsafe extern "C" fn gfxMapMemory(
             Some(size)
         },
     };
-    *ppData = gpu.device.map_memory(&mut memory, range).unwrap() as *mut _; // TODO
-
-    VkResult::VK_SUCCESS
+    match gpu.device.map_memory(&mut memory, range) {
+        Ok(ptr) => {
+            *ppData = ptr as *mut _;
+            VkResult::VK_SUCCESS
+        }
+        Err(map_error) => {
+            *ppData = ptr::null_mut();
+            map_map_error(map_error)
+        }
+    }
 }
 #[inline]
 pub unsafe extern "C" fn gfxUnmapMemory(gpu: VkDevice, mut memory: VkDeviceMemory) {
@@ -1362,28 +1759,205 @@ pub unsafe extern "C" fn gfxBindBufferMemory(
     memory: VkDeviceMemory,
     memoryOffset: VkDeviceSize,
 ) -> VkResult {
-    gpu.device
+    match gpu
+        .device
         .bind_buffer_memory(&memory, memoryOffset, &mut *buffer)
-        .unwrap(); //TODO
-    VkResult::VK_SUCCESS
+    {
+        Ok(()) => VkResult::VK_SUCCESS,
+        Err(bind_error) => map_bind_error(bind_error),
+    }
 }
 #[inline]
 pub unsafe extern "C" fn gfxBindImageMemory(
     gpu: VkDevice,
-    mut image: VkImage,
+    image: VkImage,
     memory: VkDeviceMemory,
     memoryOffset: VkDeviceSize,
 ) -> VkResult {
+    bind_image_memory(gpu, image, memory, memoryOffset, "vkBindImageMemory")
+}
+
+unsafe fn bind_image_memory(
+    gpu: VkDevice,
+    mut image: VkImage,
+    memory: VkDeviceMemory,
+    offset: VkDeviceSize,
+    command: &str,
+) -> VkResult {
+    let object = image.as_raw();
     let raw = match *image {
         Image::Native { ref mut raw, .. } => raw,
-        Image::SwapchainFrame { .. } => panic!("Unexpected swapchain image"),
+        Image::SwapchainFrame { .. } => {
+            if !validation::is_enabled() {
+                // The swapchain images have their memory already.
+                return VkResult::VK_SUCCESS;
+            }
+            validation::error(
+                VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT,
+                object,
+                &format!("{}: swapchain images can't be bound to memory", command),
+            );
+            return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+        }
     };
-    gpu.device
-        .bind_image_memory(&memory, memoryOffset, raw)
-        .unwrap(); //TODO
+    match gpu.device.bind_image_memory(&memory, offset, raw) {
+        Ok(()) => VkResult::VK_SUCCESS,
+        Err(bind_error) => map_bind_error(bind_error),
+    }
+}
+
+/// Checks that a device group binding only refers to the first device,
+/// since every logical device is made of a single physical one.
+/// Without validation, the binding is done on the only device.
+unsafe fn check_device_indices(
+    object_type: VkDebugReportObjectTypeEXT,
+    object: u64,
+    count: u32,
+    indices: *const u32,
+    command: &str,
+) -> bool {
+    if !validation::is_enabled() {
+        return true;
+    }
+    match make_slice(indices, count as _)
+        .iter()
+        .find(|&&index| index != 0)
+    {
+        Some(index) => {
+            validation::error(
+                object_type,
+                object,
+                &format!(
+                    "{}: device index {} is out of the device group",
+                    command, index
+                ),
+            );
+            false
+        }
+        None => true,
+    }
+}
+
+#[inline]
+pub unsafe extern "C" fn gfxBindBufferMemory2KHR(
+    gpu: VkDevice,
+    bindInfoCount: u32,
+    pBindInfos: *const VkBindBufferMemoryInfoKHR,
+) -> VkResult {
+    for info in make_slice(pBindInfos, bindInfoCount as _) {
+        let mut ptr = info.pNext as *const VkStructureType;
+        while !ptr.is_null() {
+            ptr = match *ptr {
+                VkStructureType::VK_STRUCTURE_TYPE_BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO_KHR => {
+                    let data = (ptr as *const VkBindBufferMemoryDeviceGroupInfoKHR)
+                        .as_ref()
+                        .unwrap();
+                    if !check_device_indices(
+                        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_EXT,
+                        info.buffer.as_raw(),
+                        data.deviceIndexCount,
+                        data.pDeviceIndices,
+                        "vkBindBufferMemory2KHR",
+                    ) {
+                        return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+                    }
+                    data.pNext
+                }
+                other => {
+                    warn!("Unrecognized {:?}, skipping", other);
+                    (ptr as *const VkBaseStruct).as_ref().unwrap().pNext
+                }
+            } as *const VkStructureType;
+        }
+
+        match gfxBindBufferMemory(gpu, info.buffer, info.memory, info.memoryOffset) {
+            VkResult::VK_SUCCESS => (),
+            error => return error,
+        }
+    }
+    VkResult::VK_SUCCESS
+}
+#[inline]
+pub unsafe extern "C" fn gfxBindImageMemory2KHR(
+    gpu: VkDevice,
+    bindInfoCount: u32,
+    pBindInfos: *const VkBindImageMemoryInfoKHR,
+) -> VkResult {
+    for info in make_slice(pBindInfos, bindInfoCount as _) {
+        let mut ptr = info.pNext as *const VkStructureType;
+        while !ptr.is_null() {
+            ptr = match *ptr {
+                VkStructureType::VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO_KHR => {
+                    let data = (ptr as *const VkBindImageMemoryDeviceGroupInfoKHR)
+                        .as_ref()
+                        .unwrap();
+                    if !check_device_indices(
+                        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT,
+                        info.image.as_raw(),
+                        data.deviceIndexCount,
+                        data.pDeviceIndices,
+                        "vkBindImageMemory2KHR",
+                    ) {
+                        return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+                    }
+                    if validation::is_enabled() && data.splitInstanceBindRegionCount != 0 {
+                        validation::error(
+                            VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT,
+                            info.image.as_raw(),
+                            "vkBindImageMemory2KHR: split instance bind regions are not supported",
+                        );
+                        return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+                    }
+                    data.pNext
+                }
+                VkStructureType::VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHX => {
+                    let data = (ptr as *const VkBindImageMemorySwapchainInfoKHX)
+                        .as_ref()
+                        .unwrap();
+                    // Images aliasing the swapchain memory can't be created yet,
+                    // see `VkImageSwapchainCreateInfoKHR`.
+                    error!(
+                        "Unable to bind an image to the memory of swapchain image {}",
+                        data.imageIndex
+                    );
+                    return VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY;
+                }
+                other => {
+                    warn!("Unrecognized {:?}, skipping", other);
+                    (ptr as *const VkBaseStruct).as_ref().unwrap().pNext
+                }
+            } as *const VkStructureType;
+        }
+
+        match bind_image_memory(
+            gpu,
+            info.image,
+            info.memory,
+            info.memoryOffset,
+            "vkBindImageMemory2KHR",
+        ) {
+            VkResult::VK_SUCCESS => (),
+            error => return error,
+        }
+    }
     VkResult::VK_SUCCESS
 }
 #[inline]
+pub unsafe extern "C" fn gfxGetDeviceGroupPeerMemoryFeatures(
+    _gpu: VkDevice,
+    _heapIndex: u32,
+    _localDeviceIndex: u32,
+    _remoteDeviceIndex: u32,
+    pPeerMemoryFeatures: *mut VkPeerMemoryFeatureFlagsKHX,
+) {
+    // The only device of the group is its own peer.
+    *pPeerMemoryFeatures = VkPeerMemoryFeatureFlagBitsKHX::VK_PEER_MEMORY_FEATURE_COPY_SRC_BIT_KHX
+        as u32
+        | VkPeerMemoryFeatureFlagBitsKHX::VK_PEER_MEMORY_FEATURE_COPY_DST_BIT_KHX as u32
+        | VkPeerMemoryFeatureFlagBitsKHX::VK_PEER_MEMORY_FEATURE_GENERIC_SRC_BIT_KHX as u32
+        | VkPeerMemoryFeatureFlagBitsKHX::VK_PEER_MEMORY_FEATURE_GENERIC_DST_BIT_KHX as u32;
+}
+#[inline]
 pub unsafe extern "C" fn gfxGetBufferMemoryRequirements(
     gpu: VkDevice,
     buffer: VkBuffer,
@@ -1413,32 +1987,90 @@ pub unsafe extern "C" fn gfxGetImageMemoryRequirements(
     };
 }
 
-/*#[inline]
-pub unsafe extern "C" fn gfxGetImageMemoryRequirements2KHR(
-    gpu: VkDevice,
-    image: VkImage,
+/// Fills the structures chained to `VkMemoryRequirements2KHR`.
+unsafe fn fill_memory_requirements2(
     pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+    requirements: VkMemoryRequirements,
+    prefers_dedicated: bool,
 ) {
     let mut ptr = pMemoryRequirements as *const VkStructureType;
     while !ptr.is_null() {
-        ptr = match *ptr } {
+        ptr = match *ptr {
             VkStructureType::VK_STRUCTURE_TYPE_MEMORY_REQUIREMENTS_2_KHR => {
-                let data = unsafe {
-                    (ptr as *mut VkMemoryRequirements2KHR).as_mut().unwrap()
-                };
-                gfxGetImageMemoryRequirements(gpu, image, &mut data.memoryRequirements);
-                data.features = conv::features_from_hal(features);
+                let data = (ptr as *mut VkMemoryRequirements2KHR).as_mut().unwrap();
+                data.memoryRequirements = requirements;
+                data.pNext
+            }
+            VkStructureType::VK_STRUCTURE_TYPE_MEMORY_DEDICATED_REQUIREMENTS_KHR => {
+                let data = (ptr as *mut VkMemoryDedicatedRequirementsKHR)
+                    .as_mut()
+                    .unwrap();
+                data.prefersDedicatedAllocation = prefers_dedicated as _;
+                data.requiresDedicatedAllocation = VK_FALSE;
                 data.pNext
             }
             other => {
                 warn!("Unrecognized {:?}, skipping", other);
-                unsafe {
-                    (ptr as *const VkBaseStruct).as_ref().unwrap()
-               .pNext
+                (ptr as *const VkBaseStruct).as_ref().unwrap().pNext
             }
         } as *const VkStructureType;
     }
-}*/
+}
+
+/// Skips over the structures chained to a `Vk*MemoryRequirementsInfo2KHR`,
+/// none of which are supported.
+unsafe fn skip_requirements_info_chain(mut ptr: *const c_void) {
+    while !ptr.is_null() {
+        let base = (ptr as *const VkBaseStruct).as_ref().unwrap();
+        warn!("Unrecognized {:?}, skipping", base.sType);
+        ptr = base.pNext;
+    }
+}
+
+#[inline]
+pub unsafe extern "C" fn gfxGetBufferMemoryRequirements2KHR(
+    gpu: VkDevice,
+    pInfo: *const VkBufferMemoryRequirementsInfo2KHR,
+    pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+) {
+    let info = &*pInfo;
+    skip_requirements_info_chain(info.pNext);
+
+    let mut requirements = mem::zeroed();
+    gfxGetBufferMemoryRequirements(gpu, info.buffer, &mut requirements);
+    fill_memory_requirements2(pMemoryRequirements, requirements, false);
+}
+#[inline]
+pub unsafe extern "C" fn gfxGetImageMemoryRequirements2KHR(
+    gpu: VkDevice,
+    pInfo: *const VkImageMemoryRequirementsInfo2KHR,
+    pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+) {
+    let info = &*pInfo;
+    skip_requirements_info_chain(info.pNext);
+
+    let mut requirements = mem::zeroed();
+    gfxGetImageMemoryRequirements(gpu, info.image, &mut requirements);
+    // Render targets are the most likely to benefit from their own allocation,
+    // as the drivers may apply compression or other optimizations to them.
+    let prefers_dedicated = match *info.image {
+        Image::Native { usage, .. } => usage.intersects(
+            hal::image::Usage::COLOR_ATTACHMENT | hal::image::Usage::DEPTH_STENCIL_ATTACHMENT,
+        ),
+        Image::SwapchainFrame { .. } => false,
+    };
+    fill_memory_requirements2(pMemoryRequirements, requirements, prefers_dedicated);
+}
+#[inline]
+pub unsafe extern "C" fn gfxGetImageSparseMemoryRequirements2KHR(
+    _gpu: VkDevice,
+    _pInfo: *const VkImageSparseMemoryRequirementsInfo2KHR,
+    pSparseMemoryRequirementCount: *mut u32,
+    _pSparseMemoryRequirements: *mut VkSparseImageMemoryRequirements2KHR,
+) {
+    // Sparse resources are not supported
+    *pSparseMemoryRequirementCount = 0;
+}
 
 #[inline]
 pub unsafe extern "C" fn gfxGetImageSparseMemoryRequirements(
@@ -1472,6 +2104,39 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceSparseImageFormatProperties2KHR(
     *pPropertyCount = 0;
 }
 #[inline]
+pub unsafe extern "C" fn gfxGetPhysicalDeviceExternalBufferProperties(
+    _physicalDevice: VkPhysicalDevice,
+    _pExternalBufferInfo: *const VkPhysicalDeviceExternalBufferInfoKHX,
+    pExternalBufferProperties: *mut VkExternalBufferPropertiesKHX,
+) {
+    // External memory is not supported
+    (*pExternalBufferProperties).externalMemoryProperties = mem::zeroed();
+}
+#[inline]
+pub unsafe extern "C" fn gfxGetPhysicalDeviceExternalFenceProperties(
+    _physicalDevice: VkPhysicalDevice,
+    _pExternalFenceInfo: *const VkPhysicalDeviceExternalFenceInfo,
+    pExternalFenceProperties: *mut VkExternalFenceProperties,
+) {
+    // External fences are not supported
+    let properties = &mut *pExternalFenceProperties;
+    properties.exportFromImportedHandleTypes = 0;
+    properties.compatibleHandleTypes = 0;
+    properties.externalFenceFeatures = 0;
+}
+#[inline]
+pub unsafe extern "C" fn gfxGetPhysicalDeviceExternalSemaphoreProperties(
+    _physicalDevice: VkPhysicalDevice,
+    _pExternalSemaphoreInfo: *const VkPhysicalDeviceExternalSemaphoreInfoKHX,
+    pExternalSemaphoreProperties: *mut VkExternalSemaphorePropertiesKHX,
+) {
+    // External semaphores are not supported
+    let properties = &mut *pExternalSemaphoreProperties;
+    properties.exportFromImportedHandleTypes = 0;
+    properties.compatibleHandleTypes = 0;
+    properties.externalSemaphoreFeatures = 0;
+}
+#[inline]
 pub unsafe extern "C" fn gfxQueueBindSparse(
     _queue: VkQueue,
     _bindInfoCount: u32,
@@ -1484,9 +2149,10 @@ pub unsafe extern "C" fn gfxQueueBindSparse(
 pub unsafe extern "C" fn gfxCreateFence(
     gpu: VkDevice,
     pCreateInfo: *const VkFenceCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pFence: *mut VkFence,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let flags = (*pCreateInfo).flags;
     let signalled = flags & VkFenceCreateFlagBits::VK_FENCE_CREATE_SIGNALED_BIT as u32 != 0;
 
@@ -1498,7 +2164,7 @@ pub unsafe extern "C" fn gfxCreateFence(
         Err(oom) => return map_oom(oom),
     };
 
-    *pFence = Handle::new(fence);
+    *pFence = allocation.init(fence);
 
     VkResult::VK_SUCCESS
 }
@@ -1506,9 +2172,9 @@ pub unsafe extern "C" fn gfxCreateFence(
 pub unsafe extern "C" fn gfxDestroyFence(
     gpu: VkDevice,
     fence: VkFence,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(fence) = fence.unbox() {
+    if let Some(fence) = fence.unbox_in(pAllocator) {
         gpu.device.destroy_fence(fence.raw);
     }
 }
@@ -1518,7 +2184,17 @@ pub unsafe extern "C" fn gfxResetFences(
     fenceCount: u32,
     pFences: *const VkFence,
 ) -> VkResult {
-    for fence in make_slice_mut(pFences as *mut VkFence, fenceCount as usize) {
+    let fences = make_slice_mut(pFences as *mut VkFence, fenceCount as usize);
+    if !fences.iter().all(|fence| {
+        validation::check_alive(
+            VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_FENCE_EXT,
+            fence.as_raw(),
+            "vkResetFences",
+        )
+    }) {
+        return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+    }
+    for fence in fences {
         fence.as_mut().unwrap().is_fake = false;
         if let Err(oom) = gpu.device.reset_fence(&mut fence.raw) {
             return map_oom(oom);
@@ -1546,6 +2222,15 @@ pub unsafe extern "C" fn gfxWaitForFences(
     waitAll: VkBool32,
     timeout: u64,
 ) -> VkResult {
+    if !make_slice(pFences, fenceCount as _).iter().all(|fence| {
+        validation::check_alive(
+            VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_FENCE_EXT,
+            fence.as_raw(),
+            "vkWaitForFences",
+        )
+    }) {
+        return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+    }
     let result = match fenceCount {
         0 => Ok(true),
         1 if !(*pFences).is_fake => gpu.device.wait_for_fence(&(*pFences).raw, timeout),
@@ -1579,9 +2264,10 @@ pub unsafe extern "C" fn gfxWaitForFences(
 pub unsafe extern "C" fn gfxCreateSemaphore(
     gpu: VkDevice,
     _pCreateInfo: *const VkSemaphoreCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pSemaphore: *mut VkSemaphore,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let semaphore = match gpu.device.create_semaphore() {
         Ok(raw) => Semaphore {
             raw,
@@ -1590,16 +2276,16 @@ pub unsafe extern "C" fn gfxCreateSemaphore(
         Err(oom) => return map_oom(oom),
     };
 
-    *pSemaphore = Handle::new(semaphore);
+    *pSemaphore = allocation.init(semaphore);
     VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxDestroySemaphore(
     gpu: VkDevice,
     semaphore: VkSemaphore,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(sem) = semaphore.unbox() {
+    if let Some(sem) = semaphore.unbox_in(pAllocator) {
         gpu.device.destroy_semaphore(sem.raw);
     }
 }
@@ -1607,24 +2293,25 @@ pub unsafe extern "C" fn gfxDestroySemaphore(
 pub unsafe extern "C" fn gfxCreateEvent(
     gpu: VkDevice,
     _pCreateInfo: *const VkEventCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pEvent: *mut VkEvent,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let event = match gpu.device.create_event() {
         Ok(e) => e,
         Err(oom) => return map_oom(oom),
     };
 
-    *pEvent = Handle::new(event);
+    *pEvent = allocation.init(event);
     VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxDestroyEvent(
     gpu: VkDevice,
     event: VkEvent,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(event) = event.unbox() {
+    if let Some(event) = event.unbox_in(pAllocator) {
         gpu.device.destroy_event(event);
     }
 }
@@ -1657,9 +2344,10 @@ pub unsafe extern "C" fn gfxResetEvent(gpu: VkDevice, mut event: VkEvent) -> VkR
 pub unsafe extern "C" fn gfxCreateQueryPool(
     gpu: VkDevice,
     pCreateInfo: *const VkQueryPoolCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pQueryPool: *mut VkQueryPool,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
     let pool = gpu.device.create_query_pool(
         conv::map_query_type(info.queryType, info.pipelineStatistics),
@@ -1668,7 +2356,12 @@ pub unsafe extern "C" fn gfxCreateQueryPool(
 
     match pool {
         Ok(pool) => {
-            *pQueryPool = Handle::new(pool);
+            *pQueryPool = allocation.init(pool);
+            if info.queryType == VkQueryType::VK_QUERY_TYPE_OCCLUSION
+                && gpu.settings.workarounds.disable_occlusion_queries
+            {
+                workarounds::ignore_query_pool(pQueryPool.as_raw());
+            }
             VkResult::VK_SUCCESS
         }
         Err(_) => {
@@ -1681,9 +2374,10 @@ pub unsafe extern "C" fn gfxCreateQueryPool(
 pub unsafe extern "C" fn gfxDestroyQueryPool(
     gpu: VkDevice,
     queryPool: VkQueryPool,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(pool) = queryPool.unbox() {
+    workarounds::forget_query_pool(queryPool.as_raw());
+    if let Some(pool) = queryPool.unbox_in(pAllocator) {
         gpu.device.destroy_query_pool(pool);
     }
 }
@@ -1698,6 +2392,10 @@ pub unsafe extern "C" fn gfxGetQueryPoolResults(
     stride: VkDeviceSize,
     flags: VkQueryResultFlags,
 ) -> VkResult {
+    if workarounds::is_ignored(queryPool.as_raw()) {
+        workarounds::write_results(pData as *mut u8, queryCount, stride as usize, flags);
+        return VkResult::VK_SUCCESS;
+    }
     let result = gpu.device.get_query_pool_results(
         &*queryPool,
         firstQuery..firstQuery + queryCount,
@@ -1711,31 +2409,102 @@ pub unsafe extern "C" fn gfxGetQueryPoolResults(
         Err(_) => VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY,
     }
 }
+/// Checks the queue families a resource is going to be shared by,
+/// when validation is enabled.
+///
+/// gfx-hal doesn't take the sharing mode, and the backends having queue family
+/// ownership create every resource exclusive. The ownership transfers of the
+/// barriers are passed to them (see `make_barriers`), but a concurrent resource
+/// used by several queue families without any transfer is left to the backend.
+unsafe fn check_sharing_mode(
+    gpu: VkDevice,
+    mode: VkSharingMode,
+    family_count: u32,
+    families: *const u32,
+    command: &str,
+) -> Result<(), VkResult> {
+    if !validation::is_enabled() || mode != VkSharingMode::VK_SHARING_MODE_CONCURRENT {
+        return Ok(());
+    }
+    let families = make_slice(families, family_count as _);
+    let error = if families.len() < 2 {
+        Some(format!(
+            "{}: concurrent sharing needs at least 2 queue families, got {}",
+            command,
+            families.len()
+        ))
+    } else if let Some(family) = families
+        .iter()
+        .find(|&&family| family as usize >= gpu.queue_family_count)
+    {
+        Some(format!(
+            "{}: queue family {} doesn't exist",
+            command, family
+        ))
+    } else if let Some((i, family)) = families
+        .iter()
+        .enumerate()
+        .find(|&(i, family)| families[..i].contains(family))
+    {
+        Some(format!(
+            "{}: queue family {} is listed twice, at index {}",
+            command, family, i
+        ))
+    } else {
+        None
+    };
+
+    match error {
+        Some(text) => {
+            validation::error(
+                VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+                gpu.as_raw(),
+                &text,
+            );
+            Err(VkResult::VK_ERROR_VALIDATION_FAILED_EXT)
+        }
+        None => Ok(()),
+    }
+}
+
 #[inline]
 pub unsafe extern "C" fn gfxCreateBuffer(
     gpu: VkDevice,
     pCreateInfo: *const VkBufferCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pBuffer: *mut VkBuffer,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
-    assert_eq!(info.sharingMode, VkSharingMode::VK_SHARING_MODE_EXCLUSIVE); // TODO
+    if let Err(code) = check_sharing_mode(
+        gpu,
+        info.sharingMode,
+        info.queueFamilyIndexCount,
+        info.pQueueFamilyIndices,
+        "vkCreateBuffer",
+    ) {
+        return code;
+    }
 
     let sparse_flags = hal::memory::SparseFlags::from_bits_truncate(info.flags);
-    let buffer = gpu
-        .device
-        .create_buffer(info.size, conv::map_buffer_usage(info.usage), sparse_flags)
-        .expect("Error on creating buffer");
-    *pBuffer = Handle::new(buffer);
+    let buffer =
+        match gpu
+            .device
+            .create_buffer(info.size, conv::map_buffer_usage(info.usage), sparse_flags)
+        {
+            Ok(buffer) => buffer,
+            Err(creation_error) => return map_buffer_creation_error(creation_error),
+        };
+    *pBuffer = allocation.init(buffer);
     VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxDestroyBuffer(
     gpu: VkDevice,
     buffer: VkBuffer,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(buffer) = buffer.unbox() {
+    if let Some(buffer) = buffer.unbox_in(pAllocator) {
         gpu.device.destroy_buffer(buffer);
     }
 }
@@ -1743,9 +2512,14 @@ pub unsafe extern "C" fn gfxDestroyBuffer(
 pub unsafe extern "C" fn gfxCreateBufferView(
     gpu: VkDevice,
     pCreateInfo: *const VkBufferViewCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pView: *mut VkBufferView,
 ) -> VkResult {
+    let _object = debug::ObjectScope::enter(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+        gpu.as_raw(),
+    );
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
     let view_result = gpu.device.create_buffer_view(
         &info.buffer,
@@ -1762,7 +2536,7 @@ pub unsafe extern "C" fn gfxCreateBufferView(
 
     match view_result {
         Ok(view) => {
-            *pView = Handle::new(view);
+            *pView = allocation.init(view);
             VkResult::VK_SUCCESS
         }
         Err(e) => {
@@ -1775,9 +2549,9 @@ pub unsafe extern "C" fn gfxCreateBufferView(
 pub unsafe extern "C" fn gfxDestroyBufferView(
     gpu: VkDevice,
     view: VkBufferView,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(v) = view.unbox() {
+    if let Some(v) = view.unbox_in(pAllocator) {
         gpu.device.destroy_buffer_view(v);
     }
 }
@@ -1785,11 +2559,24 @@ pub unsafe extern "C" fn gfxDestroyBufferView(
 pub unsafe extern "C" fn gfxCreateImage(
     gpu: VkDevice,
     pCreateInfo: *const VkImageCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pImage: *mut VkImage,
 ) -> VkResult {
+    let _object = debug::ObjectScope::enter(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+        gpu.as_raw(),
+    );
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
-    assert_eq!(info.sharingMode, VkSharingMode::VK_SHARING_MODE_EXCLUSIVE); // TODO
+    if let Err(code) = check_sharing_mode(
+        gpu,
+        info.sharingMode,
+        info.queueFamilyIndexCount,
+        info.pQueueFamilyIndices,
+        "vkCreateImage",
+    ) {
+        return code;
+    }
     if info.initialLayout != VkImageLayout::VK_IMAGE_LAYOUT_UNDEFINED {
         warn!("unexpected initial layout: {:?}", info.initialLayout);
     }
@@ -1803,28 +2590,37 @@ pub unsafe extern "C" fn gfxCreateImage(
     );
     let usage = conv::map_image_usage(info.usage);
     let view_caps = conv::map_image_create_flags(info.flags);
-    let format = conv::map_format(info.format)
-        .unwrap_or_else(|| panic!("Unsupported image format: {:?}", info.format));
+    let format = match conv::map_format(info.format) {
+        Some(format) => format,
+        None => {
+            validation::error(
+                VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+                gpu.as_raw(),
+                &format!("vkCreateImage: unsupported format {:?}", info.format),
+            );
+            return VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY;
+        }
+    };
     let fb_attachment = hal::image::FramebufferAttachment {
         usage,
         view_caps,
         format,
     };
 
-    let image = gpu
-        .device
-        .create_image(
-            kind,
-            info.mipLevels as _,
-            format,
-            conv::map_tiling(info.tiling),
-            usage,
-            sparse_flags,
-            view_caps,
-        )
-        .expect("Error on creating image");
+    let image = match gpu.device.create_image(
+        kind,
+        info.mipLevels as _,
+        format,
+        conv::map_tiling(info.tiling),
+        usage,
+        sparse_flags,
+        view_caps,
+    ) {
+        Ok(image) => image,
+        Err(creation_error) => return map_image_creation_error(gpu.as_raw(), creation_error),
+    };
 
-    *pImage = Handle::new(Image::Native {
+    *pImage = allocation.init(Image::Native {
         raw: image,
         fb_attachment,
         usage,
@@ -1836,9 +2632,17 @@ pub unsafe extern "C" fn gfxCreateImage(
 pub unsafe extern "C" fn gfxDestroyImage(
     gpu: VkDevice,
     image: VkImage,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(Image::Native { raw, .. }) = image.unbox() {
+    if let Some(&Image::SwapchainFrame { .. }) = image.as_ref() {
+        validation::error(
+            VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT,
+            image.as_raw(),
+            "vkDestroyImage: swapchain images are destroyed with their swapchain",
+        );
+        return;
+    }
+    if let Some(Image::Native { raw, .. }) = image.unbox_in(pAllocator) {
         gpu.device.destroy_image(raw);
     }
 }
@@ -1868,9 +2672,14 @@ pub unsafe extern "C" fn gfxGetImageSubresourceLayout(
 pub unsafe extern "C" fn gfxCreateImageView(
     gpu: VkDevice,
     pCreateInfo: *const VkImageViewCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pView: *mut VkImageView,
 ) -> VkResult {
+    let _object = debug::ObjectScope::enter(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+        gpu.as_raw(),
+    );
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let mut view_usage = None;
     let mut ptr = pCreateInfo as *const VkStructureType;
     while !ptr.is_null() {
@@ -1899,10 +2708,21 @@ pub unsafe extern "C" fn gfxCreateImageView(
             ref fb_attachment,
             usage,
         } => {
+            let format = match conv::map_format(info.format) {
+                Some(format) => format,
+                None => {
+                    validation::error(
+                        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+                        gpu.as_raw(),
+                        &format!("vkCreateImageView: unsupported format {:?}", info.format),
+                    );
+                    return VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY;
+                }
+            };
             match gpu.device.create_image_view(
                 raw,
                 conv::map_view_kind(info.viewType),
-                conv::map_format(info.format).unwrap(),
+                format,
                 conv::map_swizzle(info.components),
                 view_usage.unwrap_or(usage),
                 conv::map_subresource_range(info.subresourceRange),
@@ -1911,23 +2731,25 @@ pub unsafe extern "C" fn gfxCreateImageView(
                     raw,
                     fb_attachment: fb_attachment.clone(),
                 },
-                Err(err) => panic!("Unexpected image view creation error: {:?}", err),
+                Err(creation_error) => {
+                    return map_view_creation_error(gpu.as_raw(), creation_error)
+                }
             }
         }
         Image::SwapchainFrame { swapchain, frame } => {
             ImageView::SwapchainFrame { swapchain, frame }
         }
     };
-    *pView = Handle::new(view);
+    *pView = allocation.init(view);
     return VkResult::VK_SUCCESS;
 }
 #[inline]
 pub unsafe extern "C" fn gfxDestroyImageView(
     gpu: VkDevice,
     imageView: VkImageView,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(ImageView::Native { raw, .. }) = imageView.unbox() {
+    if let Some(ImageView::Native { raw, .. }) = imageView.unbox_in(pAllocator) {
         gpu.device.destroy_image_view(raw);
     }
 }
@@ -1935,12 +2757,12 @@ pub unsafe extern "C" fn gfxDestroyImageView(
 pub unsafe extern "C" fn gfxCreateShaderModule(
     gpu: VkDevice,
     pCreateInfo: *const VkShaderModuleCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pShaderModule: *mut VkShaderModule,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
-    if let Ok(value) = env::var("GFX_SHADER_DUMP") {
-        let base = std::path::PathBuf::from(value.as_str());
+    if let Some(base) = &gpu.settings.shader_dump {
         if base.is_dir() {
             let code_u8 = slice::from_raw_parts(info.pCode as *const u8, info.codeSize);
             for i in 1 .. {
@@ -1956,30 +2778,46 @@ pub unsafe extern "C" fn gfxCreateShaderModule(
     }
 
     let code = slice::from_raw_parts(info.pCode, info.codeSize / 4);
-    let shader_module = gpu
-        .device
-        .create_shader_module(code)
-        .expect("Error creating shader module"); // TODO
-    *pShaderModule = Handle::new(shader_module);
+    let raw = match gpu.device.create_shader_module(code) {
+        Ok(raw) => raw,
+        Err(shader_error) => return map_shader_error(shader_error),
+    };
+    // The backends keep the code they compiled the module to, out of the
+    // application callbacks.
+    handle::notify_internal_allocation(
+        pAllocator,
+        info.codeSize,
+        VkSystemAllocationScope::VK_SYSTEM_ALLOCATION_SCOPE_OBJECT,
+    );
+    *pShaderModule = allocation.init(ShaderModule {
+        raw,
+        code_size: info.codeSize,
+    });
     VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxDestroyShaderModule(
     gpu: VkDevice,
     shaderModule: VkShaderModule,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(module) = shaderModule.unbox() {
-        gpu.device.destroy_shader_module(module);
+    if let Some(module) = shaderModule.unbox_in(pAllocator) {
+        gpu.device.destroy_shader_module(module.raw);
+        handle::notify_internal_free(
+            pAllocator,
+            module.code_size,
+            VkSystemAllocationScope::VK_SYSTEM_ALLOCATION_SCOPE_OBJECT,
+        );
     }
 }
 #[inline]
 pub unsafe extern "C" fn gfxCreatePipelineCache(
     gpu: VkDevice,
     pCreateInfo: *const VkPipelineCacheCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pPipelineCache: *mut VkPipelineCache,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_CACHE);
     let info = &*pCreateInfo;
     let data = if info.initialDataSize != 0 {
         Some(slice::from_raw_parts(
@@ -1994,7 +2832,7 @@ pub unsafe extern "C" fn gfxCreatePipelineCache(
         Ok(cache) => cache,
         Err(oom) => return map_oom(oom),
     };
-    *pPipelineCache = Handle::new(cache);
+    *pPipelineCache = allocation.init(cache);
 
     VkResult::VK_SUCCESS
 }
@@ -2002,9 +2840,9 @@ pub unsafe extern "C" fn gfxCreatePipelineCache(
 pub unsafe extern "C" fn gfxDestroyPipelineCache(
     gpu: VkDevice,
     pipelineCache: VkPipelineCache,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(cache) = pipelineCache.unbox() {
+    if let Some(cache) = pipelineCache.unbox_in(pAllocator) {
         gpu.device.destroy_pipeline_cache(cache);
     }
 }
@@ -2052,9 +2890,13 @@ pub unsafe extern "C" fn gfxCreateGraphicsPipelines(
     pipelineCache: VkPipelineCache,
     createInfoCount: u32,
     pCreateInfos: *const VkGraphicsPipelineCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pPipelines: *mut VkPipeline,
 ) -> VkResult {
+    let _object = debug::ObjectScope::enter(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+        gpu.as_raw(),
+    );
     let infos = make_slice(pCreateInfos, createInfoCount as _);
 
     let mut spec_constants = Vec::new();
@@ -2089,6 +2931,7 @@ pub unsafe extern "C" fn gfxCreateGraphicsPipelines(
     let mut vertex_attributes = Vec::new();
 
     for (out_pipeline, info) in out_pipelines.iter_mut().zip(infos) {
+        let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
         let rasterizer_discard = (*info.pRasterizationState).rasterizerDiscardEnable == VK_TRUE;
 
         let empty_dyn_states = [];
@@ -2157,7 +3000,7 @@ pub unsafe extern "C" fn gfxCreateGraphicsPipelines(
                     .unwrap_or(0);
                 let entry_point = pso::EntryPoint {
                     entry: name.to_str().unwrap(),
-                    module: &*stage.module,
+                    module: &stage.module.raw,
                     specialization: pso::Specialization {
                         constants: Cow::from(
                             &spec_constants[cur_specialization..cur_specialization + spec_count],
@@ -2516,7 +3359,7 @@ pub unsafe extern "C" fn gfxCreateGraphicsPipelines(
             .device
             .create_graphics_pipeline(&desc, pipelineCache.as_ref())
         {
-            Ok(raw) => Handle::new(Pipeline::Graphics(raw)),
+            Ok(raw) => allocation.init(Pipeline::Graphics(raw)),
             Err(e) => {
                 error!("{:?}", e);
                 Handle::null()
@@ -2536,9 +3379,13 @@ pub unsafe extern "C" fn gfxCreateComputePipelines(
     pipelineCache: VkPipelineCache,
     createInfoCount: u32,
     pCreateInfos: *const VkComputePipelineCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pPipelines: *mut VkPipeline,
 ) -> VkResult {
+    let _object = debug::ObjectScope::enter(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+        gpu.as_raw(),
+    );
     let infos = slice::from_raw_parts(pCreateInfos, createInfoCount as _);
 
     // Collect all information which we will borrow later. Need to work around
@@ -2570,6 +3417,7 @@ pub unsafe extern "C" fn gfxCreateComputePipelines(
     let mut cur_specialization = 0;
 
     for (out_pipeline, info) in out_pipelines.iter_mut().zip(infos) {
+        let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
         let name = CStr::from_ptr(info.stage.pName);
         let spec_count = info
             .stage
@@ -2579,7 +3427,7 @@ pub unsafe extern "C" fn gfxCreateComputePipelines(
             .unwrap_or(0);
         let shader = pso::EntryPoint {
             entry: name.to_str().unwrap(),
-            module: &*info.stage.module,
+            module: &info.stage.module.raw,
             specialization: pso::Specialization {
                 constants: Cow::from(
                     &spec_constants[cur_specialization..cur_specialization + spec_count],
@@ -2640,7 +3488,7 @@ pub unsafe extern "C" fn gfxCreateComputePipelines(
             .device
             .create_compute_pipeline(&desc, pipelineCache.as_ref())
         {
-            Ok(raw) => Handle::new(Pipeline::Compute(raw)),
+            Ok(raw) => allocation.init(Pipeline::Compute(raw)),
             Err(e) => {
                 error!("{:?}", e);
                 Handle::null()
@@ -2658,9 +3506,9 @@ pub unsafe extern "C" fn gfxCreateComputePipelines(
 pub unsafe extern "C" fn gfxDestroyPipeline(
     gpu: VkDevice,
     pipeline: VkPipeline,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    match pipeline.unbox() {
+    match pipeline.unbox_in(pAllocator) {
         Some(Pipeline::Graphics(pipeline)) => gpu.device.destroy_graphics_pipeline(pipeline),
         Some(Pipeline::Compute(pipeline)) => gpu.device.destroy_compute_pipeline(pipeline),
         None => (),
@@ -2670,9 +3518,10 @@ pub unsafe extern "C" fn gfxDestroyPipeline(
 pub unsafe extern "C" fn gfxCreatePipelineLayout(
     gpu: VkDevice,
     pCreateInfo: *const VkPipelineLayoutCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pPipelineLayout: *mut VkPipelineLayout,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
     let set_layouts = slice::from_raw_parts(info.pSetLayouts, info.setLayoutCount as _);
     let push_constants =
@@ -2690,16 +3539,16 @@ pub unsafe extern "C" fn gfxCreatePipelineLayout(
         Err(oom) => return map_oom(oom),
     };
 
-    *pPipelineLayout = Handle::new(pipeline_layout);
+    *pPipelineLayout = allocation.init(pipeline_layout);
     VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxDestroyPipelineLayout(
     gpu: VkDevice,
     pipelineLayout: VkPipelineLayout,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(layout) = pipelineLayout.unbox() {
+    if let Some(layout) = pipelineLayout.unbox_in(pAllocator) {
         gpu.device.destroy_pipeline_layout(layout);
     }
 }
@@ -2707,9 +3556,10 @@ pub unsafe extern "C" fn gfxDestroyPipelineLayout(
 pub unsafe extern "C" fn gfxCreateSampler(
     gpu: VkDevice,
     pCreateInfo: *const VkSamplerCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pSampler: *mut VkSampler,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
     let gfx_info = hal::image::SamplerDesc {
         min_filter: conv::map_filter(info.minFilter),
@@ -2740,16 +3590,16 @@ pub unsafe extern "C" fn gfxCreateSampler(
         Ok(s) => s,
         Err(alloc) => return map_alloc_error(alloc),
     };
-    *pSampler = Handle::new(sampler);
+    *pSampler = allocation.init(sampler);
     VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxDestroySampler(
     gpu: VkDevice,
     sampler: VkSampler,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(sam) = sampler.unbox() {
+    if let Some(sam) = sampler.unbox_in(pAllocator) {
         gpu.device.destroy_sampler(sam);
     }
 }
@@ -2757,9 +3607,10 @@ pub unsafe extern "C" fn gfxDestroySampler(
 pub unsafe extern "C" fn gfxCreateDescriptorSetLayout(
     gpu: VkDevice,
     pCreateInfo: *const VkDescriptorSetLayoutCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pSetLayout: *mut VkDescriptorSetLayout,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
     let layout_bindings = make_slice(info.pBindings, info.bindingCount as usize);
 
@@ -2790,34 +3641,107 @@ pub unsafe extern "C" fn gfxCreateDescriptorSetLayout(
         Err(oom) => return map_oom(oom),
     };
 
-    *pSetLayout = Handle::new(set_layout);
+    *pSetLayout = allocation.init(set_layout);
     VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxDestroyDescriptorSetLayout(
     gpu: VkDevice,
     descriptorSetLayout: VkDescriptorSetLayout,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(layout) = descriptorSetLayout.unbox() {
+    if let Some(layout) = descriptorSetLayout.unbox_in(pAllocator) {
         gpu.device.destroy_descriptor_set_layout(layout);
     }
 }
 #[inline]
+pub unsafe extern "C" fn gfxGetDescriptorSetLayoutSupport(
+    gpu: VkDevice,
+    pCreateInfo: *const VkDescriptorSetLayoutCreateInfo,
+    pSupport: *mut VkDescriptorSetLayoutSupport,
+) {
+    use VkDescriptorType::*;
+
+    // gfx-hal can't tell if a layout is supported without creating it,
+    // so the layouts within the limits of a set are the supported ones.
+    let info = &*pCreateInfo;
+    let dl = gpu
+        .adapter
+        .physical_device
+        .properties()
+        .limits
+        .descriptor_limits;
+    let (mut samplers, mut sampled_images, mut storage_images, mut input_attachments) =
+        (0, 0, 0, 0);
+    let (mut uniform_buffers, mut uniform_buffers_dynamic) = (0, 0);
+    let (mut storage_buffers, mut storage_buffers_dynamic) = (0, 0);
+    let mut total = 0;
+    for binding in make_slice(info.pBindings, info.bindingCount as usize) {
+        let count = binding.descriptorCount;
+        total += count;
+        match binding.descriptorType {
+            VK_DESCRIPTOR_TYPE_SAMPLER => samplers += count,
+            VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER => {
+                samplers += count;
+                sampled_images += count;
+            }
+            VK_DESCRIPTOR_TYPE_SAMPLED_IMAGE | VK_DESCRIPTOR_TYPE_UNIFORM_TEXEL_BUFFER => {
+                sampled_images += count
+            }
+            VK_DESCRIPTOR_TYPE_STORAGE_IMAGE | VK_DESCRIPTOR_TYPE_STORAGE_TEXEL_BUFFER => {
+                storage_images += count
+            }
+            VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER => uniform_buffers += count,
+            VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC => {
+                uniform_buffers += count;
+                uniform_buffers_dynamic += count;
+            }
+            VK_DESCRIPTOR_TYPE_STORAGE_BUFFER => storage_buffers += count,
+            VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC => {
+                storage_buffers += count;
+                storage_buffers_dynamic += count;
+            }
+            VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT => input_attachments += count,
+            _ => (),
+        }
+    }
+
+    let supported = total <= MAX_PER_SET_DESCRIPTORS
+        && samplers <= dl.max_descriptor_set_samplers
+        && sampled_images <= dl.max_descriptor_set_sampled_images
+        && storage_images <= dl.max_descriptor_set_storage_images
+        && input_attachments <= dl.max_descriptor_set_input_attachments
+        && uniform_buffers <= dl.max_descriptor_set_uniform_buffers
+        && uniform_buffers_dynamic <= dl.max_descriptor_set_uniform_buffers_dynamic.max(1)
+        && storage_buffers <= dl.max_descriptor_set_storage_buffers
+        && storage_buffers_dynamic <= dl.max_descriptor_set_storage_buffers_dynamic.max(1);
+    (*pSupport).supported = supported as VkBool32;
+}
+#[inline]
 pub unsafe extern "C" fn gfxCreateDescriptorPool(
     gpu: VkDevice,
     pCreateInfo: *const VkDescriptorPoolCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pDescriptorPool: *mut VkDescriptorPool,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
-    let max_sets = info.maxSets as usize;
+    // Capped pools keep the proportion of descriptors per set.
+    let cap = gpu.settings.workarounds.descriptor_pool_max_sets;
+    let capped = |count: u32| match cap {
+        Some(cap) if cap < info.maxSets => {
+            let max_sets = info.maxSets as u64;
+            ((count as u64 * cap as u64 + max_sets - 1) / max_sets) as u32
+        }
+        _ => count,
+    };
+    let max_sets = capped(info.maxSets) as usize;
 
     let pool_sizes = slice::from_raw_parts(info.pPoolSizes, info.poolSizeCount as _);
 
     let ranges = pool_sizes.iter().map(|pool| pso::DescriptorRangeDesc {
         ty: conv::map_descriptor_type(pool.type_),
-        count: pool.descriptorCount as _,
+        count: capped(pool.descriptorCount) as _,
     });
 
     let pool = super::DescriptorPool {
@@ -2841,16 +3765,16 @@ pub unsafe extern "C" fn gfxCreateDescriptorPool(
         },
     };
 
-    *pDescriptorPool = Handle::new(pool);
+    *pDescriptorPool = allocation.init(pool);
     VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxDestroyDescriptorPool(
     gpu: VkDevice,
     descriptorPool: VkDescriptorPool,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(pool) = descriptorPool.unbox() {
+    if let Some(pool) = descriptorPool.unbox_in(pAllocator) {
         gpu.device.destroy_descriptor_pool(pool.raw);
         if let Some(sets) = pool.set_handles {
             for set in sets {
@@ -3031,8 +3955,24 @@ pub unsafe extern "C" fn gfxUpdateDescriptorSets(
     pDescriptorCopies: *const VkCopyDescriptorSet,
 ) {
     for write in make_slice(pDescriptorWrites, descriptorWriteCount as _) {
+        let ty = conv::map_descriptor_type(write.descriptorType);
+        if let pso::DescriptorType::Image { .. } | pso::DescriptorType::InputAttachment = ty {
+            // gfx-hal only exposes the views of the acquired native images.
+            let image_infos = make_slice(write.pImageInfo, write.descriptorCount as _);
+            if let Some(image) = image_infos
+                .iter()
+                .find(|image| image.imageView.as_native().is_err())
+            {
+                validation::error(
+                    VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_VIEW_EXT,
+                    image.imageView.as_raw(),
+                    "vkUpdateDescriptorSets: the view of a native swapchain image can only be written while it's acquired",
+                );
+                continue;
+            }
+        }
         let descriptors = DescriptorIter {
-            ty: conv::map_descriptor_type(write.descriptorType),
+            ty,
             image_infos: slice::from_raw_parts(write.pImageInfo, write.descriptorCount as _).iter(),
             buffer_infos: slice::from_raw_parts(write.pBufferInfo, write.descriptorCount as _)
                 .iter(),
@@ -3066,12 +4006,95 @@ pub unsafe extern "C" fn gfxUpdateDescriptorSets(
     }
 }
 #[inline]
+pub unsafe extern "C" fn gfxCreateDescriptorUpdateTemplate(
+    _gpu: VkDevice,
+    pCreateInfo: *const VkDescriptorUpdateTemplateCreateInfoKHR,
+    pAllocator: *const VkAllocationCallbacks,
+    pDescriptorUpdateTemplate: *mut VkDescriptorUpdateTemplateKHR,
+) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
+    let info = &*pCreateInfo;
+    let entries = make_slice(
+        info.pDescriptorUpdateEntries,
+        info.descriptorUpdateEntryCount as _,
+    );
+
+    *pDescriptorUpdateTemplate = allocation.init(DescriptorUpdateTemplate {
+        entries: entries.to_vec(),
+    });
+    VkResult::VK_SUCCESS
+}
+#[inline]
+pub unsafe extern "C" fn gfxDestroyDescriptorUpdateTemplate(
+    _gpu: VkDevice,
+    descriptorUpdateTemplate: VkDescriptorUpdateTemplateKHR,
+    pAllocator: *const VkAllocationCallbacks,
+) {
+    let _ = descriptorUpdateTemplate.unbox_in(pAllocator);
+}
+#[inline]
+pub unsafe extern "C" fn gfxUpdateDescriptorSetWithTemplate(
+    gpu: VkDevice,
+    descriptorSet: VkDescriptorSet,
+    descriptorUpdateTemplate: VkDescriptorUpdateTemplateKHR,
+    pData: *const c_void,
+) {
+    // Each entry is turned into a regular descriptor write,
+    // with the descriptors gathered from the strided application data.
+    for entry in &descriptorUpdateTemplate.entries {
+        let element = |i: u32| (pData as *const u8).add(entry.offset + i as usize * entry.stride);
+        let mut image_infos = Vec::new();
+        let mut buffer_infos = Vec::new();
+        let mut texel_buffer_views = Vec::new();
+        match entry.descriptorType {
+            VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER
+            | VkDescriptorType::VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER
+            | VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLED_IMAGE
+            | VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_IMAGE
+            | VkDescriptorType::VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT => {
+                image_infos.extend(
+                    (0..entry.descriptorCount)
+                        .map(|i| ptr::read_unaligned(element(i) as *const VkDescriptorImageInfo)),
+                );
+            }
+            VkDescriptorType::VK_DESCRIPTOR_TYPE_UNIFORM_TEXEL_BUFFER
+            | VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_TEXEL_BUFFER => {
+                texel_buffer_views.extend(
+                    (0..entry.descriptorCount)
+                        .map(|i| ptr::read_unaligned(element(i) as *const VkBufferView)),
+                );
+            }
+            _ => {
+                buffer_infos.extend(
+                    (0..entry.descriptorCount)
+                        .map(|i| ptr::read_unaligned(element(i) as *const VkDescriptorBufferInfo)),
+                );
+            }
+        }
+
+        let write = VkWriteDescriptorSet {
+            sType: VkStructureType::VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET,
+            pNext: ptr::null(),
+            dstSet: descriptorSet,
+            dstBinding: entry.dstBinding,
+            dstArrayElement: entry.dstArrayElement,
+            descriptorCount: entry.descriptorCount,
+            descriptorType: entry.descriptorType,
+            pImageInfo: image_infos.as_ptr(),
+            pBufferInfo: buffer_infos.as_ptr(),
+            pTexelBufferView: texel_buffer_views.as_ptr(),
+        };
+        gfxUpdateDescriptorSets(gpu, 1, &write, 0, ptr::null());
+    }
+}
+#[inline]
 pub unsafe extern "C" fn gfxCreateFramebuffer(
     gpu: VkDevice,
     pCreateInfo: *const VkFramebufferCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pFramebuffer: *mut VkFramebuffer,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
     let extent = hal::image::Extent {
         width: info.width,
@@ -3079,7 +4102,7 @@ pub unsafe extern "C" fn gfxCreateFramebuffer(
         depth: info.layers,
     };
 
-    let framebuffer = if info.flags
+    let (fb_attachments, image_views) = if info.flags
         & VkFramebufferCreateFlagBits::VK_FRAMEBUFFER_CREATE_IMAGELESS_BIT_KHR as u32
         != 0
     {
@@ -3103,56 +4126,60 @@ pub unsafe extern "C" fn gfxCreateFramebuffer(
                 }
             } as *const VkStructureType;
         }
-        Framebuffer {
-            raw: match gpu.device.create_framebuffer(
-                &*info.renderPass,
-                raw_attachment_infos
-                    .iter()
-                    .map(|ai| hal::image::FramebufferAttachment {
-                        usage: conv::map_image_usage(ai.usage),
-                        view_caps: conv::map_image_create_flags(ai.flags),
-                        //TODO: properly support view format lists!
-                        format: ai
-                            .pViewFormats
-                            .as_ref()
-                            .cloned()
-                            .and_then(conv::map_format)
-                            .unwrap(),
-                    }),
-                extent,
-            ) {
-                Ok(fbo) => fbo,
-                Err(oom) => return map_oom(oom),
-            },
-            image_views: None,
-        }
+        let fb_attachments = raw_attachment_infos
+            .iter()
+            .map(|ai| hal::image::FramebufferAttachment {
+                usage: conv::map_image_usage(ai.usage),
+                view_caps: conv::map_image_create_flags(ai.flags),
+                //TODO: properly support view format lists!
+                format: ai
+                    .pViewFormats
+                    .as_ref()
+                    .cloned()
+                    .and_then(conv::map_format)
+                    .unwrap(),
+            })
+            .collect::<Vec<_>>();
+        (fb_attachments, None)
     } else {
         let attachments_slice = make_slice(info.pAttachments, info.attachmentCount as _);
-        Framebuffer {
-            raw: match gpu.device.create_framebuffer(
-                &*info.renderPass,
-                attachments_slice
-                    .iter()
-                    .map(|attachment| attachment.framebuffer_attachment()),
-                extent,
-            ) {
-                Ok(fbo) => fbo,
-                Err(oom) => return map_oom(oom),
-            },
-            image_views: Some(attachments_slice.to_vec().into_boxed_slice()),
-        }
+        let fb_attachments = attachments_slice
+            .iter()
+            .map(|attachment| attachment.framebuffer_attachment())
+            .collect::<Vec<_>>();
+        (
+            fb_attachments,
+            Some(attachments_slice.to_vec().into_boxed_slice()),
+        )
+    };
+
+    if !validation::check_framebuffer(info.renderPass, &fb_attachments, "vkCreateFramebuffer") {
+        return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+    }
+    let framebuffer = Framebuffer {
+        raw: match gpu.device.create_framebuffer(
+            &*info.renderPass,
+            fb_attachments.iter().cloned(),
+            extent,
+        ) {
+            Ok(fbo) => fbo,
+            Err(oom) => return map_oom(oom),
+        },
+        image_views,
     };
 
-    *pFramebuffer = Handle::new(framebuffer);
+    *pFramebuffer = allocation.init(framebuffer);
+    validation::register_framebuffer(*pFramebuffer, fb_attachments);
     VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxDestroyFramebuffer(
     gpu: VkDevice,
     framebuffer: VkFramebuffer,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(fbo) = framebuffer.unbox() {
+    validation::unregister_framebuffer(framebuffer);
+    if let Some(fbo) = framebuffer.unbox_in(pAllocator) {
         gpu.device.destroy_framebuffer(fbo.raw);
     }
 }
@@ -3160,9 +4187,10 @@ pub unsafe extern "C" fn gfxDestroyFramebuffer(
 pub unsafe extern "C" fn gfxCreateRenderPass(
     gpu: VkDevice,
     pCreateInfo: *const VkRenderPassCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pRenderPass: *mut VkRenderPass,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
 
     // Attachment descriptions
@@ -3302,7 +4330,13 @@ pub unsafe extern "C" fn gfxCreateRenderPass(
         Err(oom) => return map_oom(oom),
     };
 
-    *pRenderPass = Handle::new(render_pass);
+    *pRenderPass = allocation.init(render_pass);
+    validation::register_render_pass(
+        *pRenderPass,
+        raw_attachments
+            .iter()
+            .map(|attachment| conv::map_format(attachment.format)),
+    );
 
     VkResult::VK_SUCCESS
 }
@@ -3310,9 +4344,10 @@ pub unsafe extern "C" fn gfxCreateRenderPass(
 pub unsafe extern "C" fn gfxDestroyRenderPass(
     gpu: VkDevice,
     renderPass: VkRenderPass,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(rp) = renderPass.unbox() {
+    validation::unregister_render_pass(renderPass);
+    if let Some(rp) = renderPass.unbox_in(pAllocator) {
         gpu.device.destroy_render_pass(rp);
     }
 }
@@ -3333,9 +4368,10 @@ pub unsafe extern "C" fn gfxGetRenderAreaGranularity(
 pub unsafe extern "C" fn gfxCreateCommandPool(
     gpu: VkDevice,
     pCreateInfo: *const VkCommandPoolCreateInfo,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pCommandPool: *mut VkCommandPool,
 ) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     use hal::pool::CommandPoolCreateFlags;
 
     let info = &*pCreateInfo;
@@ -3359,7 +4395,7 @@ pub unsafe extern "C" fn gfxCreateCommandPool(
         },
         buffers: Vec::new(),
     };
-    *pCommandPool = Handle::new(pool);
+    *pCommandPool = allocation.init(pool);
     VkResult::VK_SUCCESS
 }
 
@@ -3367,10 +4403,11 @@ pub unsafe extern "C" fn gfxCreateCommandPool(
 pub unsafe extern "C" fn gfxDestroyCommandPool(
     gpu: VkDevice,
     commandPool: VkCommandPool,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(cp) = commandPool.unbox() {
+    if let Some(cp) = commandPool.unbox_in(pAllocator) {
         for cmd_buf in cp.buffers {
+            validation::unregister_command_buffer(cmd_buf);
             let _ = cmd_buf.unbox();
         }
         gpu.device.destroy_command_pool(cp.pool);
@@ -3386,6 +4423,9 @@ pub unsafe extern "C" fn gfxResetCommandPool(
     let release = (flags
         & VkCommandPoolResetFlagBits::VK_COMMAND_POOL_RESET_RELEASE_RESOURCES_BIT as u32)
         != 0;
+    for &cmd_buf in &commandPool.buffers {
+        validation::reset_command_buffer(cmd_buf);
+    }
     commandPool.pool.reset(release);
     VkResult::VK_SUCCESS
 }
@@ -3400,7 +4440,7 @@ pub unsafe extern "C" fn gfxTrimCommandPoolKHR(
 
 #[inline]
 pub unsafe extern "C" fn gfxAllocateCommandBuffers(
-    _gpu: VkDevice,
+    gpu: VkDevice,
     pAllocateInfo: *const VkCommandBufferAllocateInfo,
     pCommandBuffers: *mut VkCommandBuffer,
 ) -> VkResult {
@@ -3414,7 +4454,8 @@ pub unsafe extern "C" fn gfxAllocateCommandBuffers(
     let output = slice::from_raw_parts_mut(pCommandBuffers, info.commandBufferCount as usize);
     for out in output.iter_mut() {
         let cmd_buf = info.commandPool.pool.allocate_one(level);
-        *out = DispatchHandle::new(cmd_buf);
+        *out = DispatchHandle::new(BACKEND, gpu.is_dumped(), cmd_buf);
+        validation::register_command_buffer(*out);
     }
     info.commandPool.buffers.extend_from_slice(output);
 
@@ -3430,6 +4471,9 @@ pub unsafe extern "C" fn gfxFreeCommandBuffers(
 ) {
     let slice = slice::from_raw_parts(pCommandBuffers, commandBufferCount as _);
     commandPool.buffers.retain(|buf| !slice.contains(buf));
+    for &cmd_buf in slice {
+        validation::unregister_command_buffer(cmd_buf);
+    }
 
     let buffers = slice.iter().filter_map(|buffer| buffer.unbox());
     commandPool.pool.free(buffers);
@@ -3441,6 +4485,9 @@ pub unsafe extern "C" fn gfxBeginCommandBuffer(
     pBeginInfo: *const VkCommandBufferBeginInfo,
 ) -> VkResult {
     let info = &*pBeginInfo;
+    if !validation::begin_command_buffer(commandBuffer, info.flags) {
+        return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+    }
     let inheritance = match info.pInheritanceInfo.as_ref() {
         Some(ii) => com::CommandBufferInheritanceInfo {
             subpass: ii.renderPass.as_ref().map(|rp| pass::Subpass {
@@ -3459,6 +4506,9 @@ pub unsafe extern "C" fn gfxBeginCommandBuffer(
 }
 #[inline]
 pub unsafe extern "C" fn gfxEndCommandBuffer(mut commandBuffer: VkCommandBuffer) -> VkResult {
+    if !validation::end_command_buffer(commandBuffer) {
+        return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+    }
     commandBuffer.finish();
     VkResult::VK_SUCCESS
 }
@@ -3470,6 +4520,7 @@ pub unsafe extern "C" fn gfxResetCommandBuffer(
     let release_resources = flags
         & VkCommandBufferResetFlagBits::VK_COMMAND_BUFFER_RESET_RELEASE_RESOURCES_BIT as u32
         != 0;
+    validation::reset_command_buffer(commandBuffer);
     commandBuffer.reset(release_resources);
     VkResult::VK_SUCCESS
 }
@@ -3681,6 +4732,46 @@ pub unsafe extern "C" fn gfxCmdDispatch(
     commandBuffer.dispatch([groupCountX, groupCountY, groupCountZ]);
 }
 #[inline]
+pub unsafe extern "C" fn gfxCmdDispatchBase(
+    commandBuffer: VkCommandBuffer,
+    baseGroupX: u32,
+    baseGroupY: u32,
+    baseGroupZ: u32,
+    groupCountX: u32,
+    groupCountY: u32,
+    groupCountZ: u32,
+) {
+    let _object = debug::ObjectScope::enter(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT,
+        commandBuffer.as_raw(),
+    );
+    // A base needs `VK_PIPELINE_CREATE_DISPATCH_BASE`, from the device groups
+    // of Vulkan 1.1 that the physical devices don't support, see `DEVICE_API_VERSION`.
+    if baseGroupX != 0 || baseGroupY != 0 || baseGroupZ != 0 {
+        validation::error(
+            VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT,
+            commandBuffer.as_raw(),
+            &format!(
+                "vkCmdDispatchBase: base ({}, {}, {}) is not supported, the dispatch is skipped",
+                baseGroupX, baseGroupY, baseGroupZ
+            ),
+        );
+        return;
+    }
+    gfxCmdDispatch(commandBuffer, groupCountX, groupCountY, groupCountZ);
+}
+#[inline]
+pub unsafe extern "C" fn gfxCmdSetDeviceMask(commandBuffer: VkCommandBuffer, deviceMask: u32) {
+    let _object = debug::ObjectScope::enter(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT,
+        commandBuffer.as_raw(),
+    );
+    // There is a single device in each group
+    if deviceMask != 1 {
+        warn!("Unexpected device mask {:#x}", deviceMask);
+    }
+}
+#[inline]
 pub unsafe extern "C" fn gfxCmdDispatchIndirect(
     mut commandBuffer: VkCommandBuffer,
     buffer: VkBuffer,
@@ -3706,6 +4797,26 @@ pub unsafe extern "C" fn gfxCmdCopyBuffer(
 
     commandBuffer.copy_buffer(&*srcBuffer, &*dstBuffer, regions);
 }
+/// Returns the image given to a command, reporting the images of a native
+/// swapchain that aren't acquired: gfx-hal only exposes the acquired ones,
+/// so the commands using them have to be recorded after the acquisition.
+fn acquired_image<'a>(image: &'a VkImage, command: &str) -> Option<&'a <B as hal::Backend>::Image> {
+    match image.as_native() {
+        Ok(raw) => Some(raw),
+        Err(_) => {
+            validation::error(
+                VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT,
+                image.as_raw(),
+                &format!(
+                    "{}: the image of a native swapchain can only be used while it's acquired",
+                    command
+                ),
+            );
+            None
+        }
+    }
+}
+
 #[inline]
 pub unsafe extern "C" fn gfxCmdCopyImage(
     mut commandBuffer: VkCommandBuffer,
@@ -3716,19 +4827,20 @@ pub unsafe extern "C" fn gfxCmdCopyImage(
     regionCount: u32,
     pRegions: *const VkImageCopy,
 ) {
-    let src = match srcImage.as_native() {
-        Ok(img) => img,
-        Err(_) => {
-            warn!("Unable to copy from a swapchain image!");
-            return;
-        }
+    if validation::is_enabled()
+        && !(validation::check_recording(commandBuffer, "vkCmdCopyImage")
+            & validation::check_transfer_src(srcImage, srcImageLayout, "vkCmdCopyImage")
+            & validation::check_transfer_dst(dstImage, dstImageLayout, "vkCmdCopyImage"))
+    {
+        return;
+    }
+    let src = match acquired_image(&srcImage, "vkCmdCopyImage") {
+        Some(img) => img,
+        None => return,
     };
-    let dst = match dstImage.as_native() {
-        Ok(img) => img,
-        Err(_) => {
-            warn!("Unable to copy into a swapchain image!");
-            return;
-        }
+    let dst = match acquired_image(&dstImage, "vkCmdCopyImage") {
+        Some(img) => img,
+        None => return,
     };
 
     let regions = slice::from_raw_parts(pRegions, regionCount as _)
@@ -3760,19 +4872,20 @@ pub unsafe extern "C" fn gfxCmdBlitImage(
     pRegions: *const VkImageBlit,
     filter: VkFilter,
 ) {
-    let src = match srcImage.as_native() {
-        Ok(img) => img,
-        Err(_) => {
-            warn!("Unable to copy from a swapchain image!");
-            return;
-        }
+    if validation::is_enabled()
+        && !(validation::check_recording(commandBuffer, "vkCmdBlitImage")
+            & validation::check_transfer_src(srcImage, srcImageLayout, "vkCmdBlitImage")
+            & validation::check_transfer_dst(dstImage, dstImageLayout, "vkCmdBlitImage"))
+    {
+        return;
+    }
+    let src = match acquired_image(&srcImage, "vkCmdBlitImage") {
+        Some(img) => img,
+        None => return,
     };
-    let dst = match dstImage.as_native() {
-        Ok(img) => img,
-        Err(_) => {
-            warn!("Unable to copy into a swapchain image!");
-            return;
-        }
+    let dst = match acquired_image(&dstImage, "vkCmdBlitImage") {
+        Some(img) => img,
+        None => return,
     };
 
     let regions = slice::from_raw_parts(pRegions, regionCount as _)
@@ -3802,7 +4915,16 @@ pub unsafe extern "C" fn gfxCmdCopyBufferToImage(
     regionCount: u32,
     pRegions: *const VkBufferImageCopy,
 ) {
-    let dst = dstImage.as_native().unwrap();
+    if validation::is_enabled()
+        && !(validation::check_recording(commandBuffer, "vkCmdCopyBufferToImage")
+            & validation::check_transfer_dst(dstImage, dstImageLayout, "vkCmdCopyBufferToImage"))
+    {
+        return;
+    }
+    let dst = match acquired_image(&dstImage, "vkCmdCopyBufferToImage") {
+        Some(img) => img,
+        None => return,
+    };
 
     let regions = slice::from_raw_parts(pRegions, regionCount as _)
         .iter()
@@ -3831,7 +4953,16 @@ pub unsafe extern "C" fn gfxCmdCopyImageToBuffer(
     regionCount: u32,
     pRegions: *const VkBufferImageCopy,
 ) {
-    let src = srcImage.as_native().unwrap();
+    if validation::is_enabled()
+        && !(validation::check_recording(commandBuffer, "vkCmdCopyImageToBuffer")
+            & validation::check_transfer_src(srcImage, srcImageLayout, "vkCmdCopyImageToBuffer"))
+    {
+        return;
+    }
+    let src = match acquired_image(&srcImage, "vkCmdCopyImageToBuffer") {
+        Some(img) => img,
+        None => return,
+    };
 
     let regions = slice::from_raw_parts(pRegions, regionCount as _)
         .iter()
@@ -3892,12 +5023,15 @@ pub unsafe extern "C" fn gfxCmdClearColorImage(
     rangeCount: u32,
     pRanges: *const VkImageSubresourceRange,
 ) {
-    let img = match image.as_native() {
-        Ok(img) => img,
-        Err(_) => {
-            warn!("Unable to clear a swapchain image!");
-            return;
-        }
+    if validation::is_enabled()
+        && !(validation::check_recording(commandBuffer, "vkCmdClearColorImage")
+            & validation::check_transfer_dst(image, imageLayout, "vkCmdClearColorImage"))
+    {
+        return;
+    }
+    let img = match acquired_image(&image, "vkCmdClearColorImage") {
+        Some(img) => img,
+        None => return,
     };
     let subresource_ranges = slice::from_raw_parts(pRanges, rangeCount as _)
         .iter()
@@ -3946,7 +5080,7 @@ pub unsafe extern "C" fn gfxCmdClearAttachments(
     let attachments = slice::from_raw_parts(pAttachments, attachmentCount as _)
         .iter()
         .map(|at| {
-            use crate::VkImageAspectFlagBits::*;
+            use super::VkImageAspectFlagBits::*;
             if at.aspectMask & VK_IMAGE_ASPECT_COLOR_BIT as u32 != 0 {
                 com::AttachmentClear::Color {
                     index: at.colorAttachment as _,
@@ -3986,8 +5120,39 @@ pub unsafe extern "C" fn gfxCmdResolveImage(
     regionCount: u32,
     pRegions: *const VkImageResolve,
 ) {
-    let src = srcImage.as_native().unwrap();
-    let dst = dstImage.as_native().unwrap();
+    if validation::is_enabled()
+        && !(validation::check_recording(commandBuffer, "vkCmdResolveImage")
+            & validation::check_image(
+                srcImage,
+                srcImageLayout,
+                hal::image::Usage::empty(),
+                &[
+                    VkImageLayout::VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL,
+                    VkImageLayout::VK_IMAGE_LAYOUT_GENERAL,
+                ],
+                "vkCmdResolveImage",
+            )
+            & validation::check_image(
+                dstImage,
+                dstImageLayout,
+                hal::image::Usage::empty(),
+                &[
+                    VkImageLayout::VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL,
+                    VkImageLayout::VK_IMAGE_LAYOUT_GENERAL,
+                ],
+                "vkCmdResolveImage",
+            ))
+    {
+        return;
+    }
+    let src = match acquired_image(&srcImage, "vkCmdResolveImage") {
+        Some(img) => img,
+        None => return,
+    };
+    let dst = match acquired_image(&dstImage, "vkCmdResolveImage") {
+        Some(img) => img,
+        None => return,
+    };
 
     let regions = slice::from_raw_parts(pRegions, regionCount as _)
         .iter()
@@ -4029,6 +5194,7 @@ fn make_barriers<'a>(
     raw_globals: &'a [VkMemoryBarrier],
     raw_buffers: &'a [VkBufferMemoryBarrier],
     raw_images: &'a [VkImageMemoryBarrier],
+    command: &'static str,
 ) -> impl Iterator<Item = memory::Barrier<'a, back::Backend>> {
     let globals = raw_globals.iter().flat_map(|b| {
         let buf =
@@ -4050,7 +5216,7 @@ fn make_barriers<'a>(
     let buffers = raw_buffers.iter().map(|b| memory::Barrier::Buffer {
         states: conv::map_buffer_access(b.srcAccessMask)..conv::map_buffer_access(b.dstAccessMask),
         target: &*b.buffer,
-        families: None,
+        families: conv::map_queue_family_transfer(b.srcQueueFamilyIndex, b.dstQueueFamilyIndex),
         range: hal::buffer::SubRange {
             offset: b.offset,
             size: if b.size as i32 == VK_WHOLE_SIZE {
@@ -4060,8 +5226,8 @@ fn make_barriers<'a>(
             },
         },
     });
-    let images = raw_images.iter().flat_map(|b| {
-        let target = b.image.as_native().ok()?;
+    let images = raw_images.iter().flat_map(move |b| {
+        let target = acquired_image(&b.image, command)?;
         let from = (
             conv::map_image_access(b.srcAccessMask),
             conv::map_image_layout(b.oldLayout),
@@ -4074,7 +5240,7 @@ fn make_barriers<'a>(
             states: from..to,
             target,
             range: conv::map_subresource_range(b.subresourceRange),
-            families: None,
+            families: conv::map_queue_family_transfer(b.srcQueueFamilyIndex, b.dstQueueFamilyIndex),
         })
     });
 
@@ -4099,7 +5265,7 @@ pub unsafe extern "C" fn gfxCmdWaitEvents(
     let raw_buffers = make_slice(pBufferMemoryBarriers, bufferMemoryBarrierCount as _);
     let raw_images = make_slice(pImageMemoryBarriers, imageMemoryBarrierCount as _);
 
-    let barriers = make_barriers(raw_globals, raw_buffers, raw_images);
+    let barriers = make_barriers(raw_globals, raw_buffers, raw_images, "vkCmdWaitEvents");
 
     commandBuffer.wait_events(
         slice::from_raw_parts(pEvents, eventCount as usize)
@@ -4126,7 +5292,7 @@ pub unsafe extern "C" fn gfxCmdPipelineBarrier(
     let raw_buffers = make_slice(pBufferMemoryBarriers, bufferMemoryBarrierCount as _);
     let raw_images = make_slice(pImageMemoryBarriers, imageMemoryBarrierCount as _);
 
-    let barriers = make_barriers(raw_globals, raw_buffers, raw_images);
+    let barriers = make_barriers(raw_globals, raw_buffers, raw_images, "vkCmdPipelineBarrier");
 
     commandBuffer.pipeline_barrier(
         conv::map_pipeline_stage_flags(srcStageMask)..conv::map_pipeline_stage_flags(dstStageMask),
@@ -4141,6 +5307,9 @@ pub unsafe extern "C" fn gfxCmdBeginQuery(
     query: u32,
     flags: VkQueryControlFlags,
 ) {
+    if workarounds::is_ignored(queryPool.as_raw()) {
+        return;
+    }
     let query = hal::query::Query {
         pool: &*queryPool,
         id: query,
@@ -4153,6 +5322,9 @@ pub unsafe extern "C" fn gfxCmdEndQuery(
     queryPool: VkQueryPool,
     query: u32,
 ) {
+    if workarounds::is_ignored(queryPool.as_raw()) {
+        return;
+    }
     let query = hal::query::Query {
         pool: &*queryPool,
         id: query,
@@ -4166,6 +5338,9 @@ pub unsafe extern "C" fn gfxCmdResetQueryPool(
     firstQuery: u32,
     queryCount: u32,
 ) {
+    if workarounds::is_ignored(queryPool.as_raw()) {
+        return;
+    }
     commandBuffer.reset_query_pool(&*queryPool, firstQuery..firstQuery + queryCount);
 }
 #[inline]
@@ -4192,6 +5367,13 @@ pub unsafe extern "C" fn gfxCmdCopyQueryPoolResults(
     stride: VkDeviceSize,
     flags: VkQueryResultFlags,
 ) {
+    if workarounds::is_ignored(queryPool.as_raw()) {
+        let result = workarounds::result(flags);
+        for i in 0..queryCount as VkDeviceSize {
+            commandBuffer.update_buffer(&*dstBuffer, dstOffset + i * stride, &result);
+        }
+        return;
+    }
     commandBuffer.copy_query_pool_results(
         &*queryPool,
         firstQuery..firstQuery + queryCount,
@@ -4232,6 +5414,16 @@ pub unsafe extern "C" fn gfxCmdBeginRenderPass(
     contents: VkSubpassContents,
 ) {
     let info = &*pRenderPassBegin;
+    if validation::is_enabled()
+        && !(validation::check_recording(commandBuffer, "vkCmdBeginRenderPass")
+            & validation::check_render_pass_begin(
+                info.renderPass,
+                info.framebuffer,
+                "vkCmdBeginRenderPass",
+            ))
+    {
+        return;
+    }
 
     let render_area = pso::Rect {
         x: info.renderArea.offset.x as _,
@@ -4263,6 +5455,22 @@ pub unsafe extern "C" fn gfxCmdBeginRenderPass(
         }
     };
 
+    for view in image_views {
+        if let ImageView::SwapchainFrame { swapchain, frame } = **view {
+            if swapchain.active[frame as usize].is_none() {
+                validation::error(
+                    VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_VIEW_EXT,
+                    view.as_raw(),
+                    &format!(
+                        "vkCmdBeginRenderPass: swapchain image {} is not acquired",
+                        frame
+                    ),
+                );
+                return;
+            }
+        }
+    }
+
     // gfx-hal expects exactly one clear value for an attachment that needs
     // to be cleared, while Vulkan has gaps.
     let clear_values_slice = make_slice(info.pClearValues, info.clearValueCount as _);
@@ -4280,10 +5488,7 @@ pub unsafe extern "C" fn gfxCmdBeginRenderPass(
                 ImageView::SwapchainFrame {
                     ref swapchain,
                     frame,
-                } => swapchain.active[frame as usize]
-                    .as_ref()
-                    .expect("Swapchain frame is not acquired!")
-                    .borrow(),
+                } => swapchain.image_view(frame).unwrap(),
             },
             clear_value,
         });
@@ -4314,21 +5519,28 @@ pub unsafe extern "C" fn gfxCmdExecuteCommands(
     commandBufferCount: u32,
     pCommandBuffers: *const VkCommandBuffer,
 ) {
-    commandBuffer.execute_commands(
-        make_slice(pCommandBuffers, commandBufferCount as _)
-            .iter()
-            .map(|handle| &**handle),
-    );
+    let cmd_buffers = make_slice(pCommandBuffers, commandBufferCount as _);
+    if validation::is_enabled()
+        && !cmd_buffers.iter().fold(
+            validation::check_recording(commandBuffer, "vkCmdExecuteCommands"),
+            |valid, &cmd_buf| {
+                valid & validation::submit_command_buffer(cmd_buf, "vkCmdExecuteCommands")
+            },
+        )
+    {
+        return;
+    }
+    commandBuffer.execute_commands(cmd_buffers.iter().map(|handle| &**handle));
 }
 
 #[inline]
 pub unsafe extern "C" fn gfxDestroySurfaceKHR(
     instance: VkInstance,
     surface: VkSurfaceKHR,
-    _: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(surface) = surface.unbox() {
-        instance.backend.destroy_surface(surface.raw);
+    if let Some(Surface { raw: Some(raw), .. }) = surface.unbox_in(pAllocator) {
+        instance.backend.destroy_surface(raw);
     }
 }
 
@@ -4340,22 +5552,81 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceSurfaceSupportKHR(
     pSupported: *mut VkBool32,
 ) -> VkResult {
     let family = &adapter.queue_families[queueFamilyIndex as usize];
-    let supports = surface.raw.supports_queue_family(family);
+    let supports = match surface.raw {
+        Some(ref raw) => raw.supports_queue_family(family),
+        None => true,
+    };
     *pSupported = supports as _;
     VkResult::VK_SUCCESS
 }
 
-#[inline]
-pub unsafe extern "C" fn gfxGetPhysicalDeviceSurfaceCapabilitiesKHR(
-    adapter: VkPhysicalDevice,
-    surface: VkSurfaceKHR,
-    pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR,
-) -> VkResult {
-    let caps = surface.raw.capabilities(&adapter.physical_device);
-
-    let output = VkSurfaceCapabilitiesKHR {
-        minImageCount: *caps.image_count.start(),
-        maxImageCount: *caps.image_count.end(),
+/// Formats of the headless surfaces, which are the common ones of the other surfaces.
+const HEADLESS_FORMATS: &[hal::format::Format] = &[
+    hal::format::Format::Bgra8Unorm,
+    hal::format::Format::Bgra8Srgb,
+    hal::format::Format::Rgba8Unorm,
+    hal::format::Format::Rgba8Srgb,
+];
+/// Maximum number of images of a headless swapchain.
+const HEADLESS_MAX_IMAGES: hal::window::SwapImageIndex = 8;
+
+/// Returns the capabilities of a surface, which are the ones of any
+/// 2D color image for the headless surfaces.
+fn surface_capabilities(
+    adapter: &hal::adapter::Adapter<B>,
+    surface: &Surface<B>,
+) -> hal::window::SurfaceCapabilities {
+    match surface.raw {
+        Some(ref raw) => raw.capabilities(&adapter.physical_device),
+        None => {
+            let max_size = adapter
+                .physical_device
+                .properties()
+                .limits
+                .max_image_2d_size;
+            hal::window::SurfaceCapabilities {
+                present_modes: PresentMode::FIFO | PresentMode::MAILBOX | PresentMode::IMMEDIATE,
+                composite_alpha_modes: hal::window::CompositeAlphaMode::OPAQUE,
+                image_count: 1..=HEADLESS_MAX_IMAGES,
+                current_extent: None,
+                extents: hal::window::Extent2D {
+                    width: 1,
+                    height: 1,
+                }..=hal::window::Extent2D {
+                    width: max_size,
+                    height: max_size,
+                },
+                max_image_layers: 1,
+                usage: hal::image::Usage::COLOR_ATTACHMENT
+                    | hal::image::Usage::TRANSFER_SRC
+                    | hal::image::Usage::TRANSFER_DST
+                    | hal::image::Usage::SAMPLED,
+            }
+        }
+    }
+}
+
+fn surface_formats(adapter: &hal::adapter::Adapter<B>, surface: &Surface<B>) -> Vec<VkFormat> {
+    let formats = match surface.raw {
+        Some(ref raw) => raw.supported_formats(&adapter.physical_device),
+        None => Some(HEADLESS_FORMATS.to_vec()),
+    };
+    formats
+        .map(|formats| formats.into_iter().map(conv::format_from_hal).collect())
+        .unwrap_or(vec![VkFormat::VK_FORMAT_UNDEFINED])
+}
+
+#[inline]
+pub unsafe extern "C" fn gfxGetPhysicalDeviceSurfaceCapabilitiesKHR(
+    adapter: VkPhysicalDevice,
+    surface: VkSurfaceKHR,
+    pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR,
+) -> VkResult {
+    let caps = surface_capabilities(&adapter, &surface);
+
+    let output = VkSurfaceCapabilitiesKHR {
+        minImageCount: *caps.image_count.start(),
+        maxImageCount: *caps.image_count.end(),
         currentExtent: match caps.current_extent {
             Some(extent) => conv::extent2d_from_hal(extent),
             None => VkExtent2D {
@@ -4412,11 +5683,7 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceSurfaceFormatsKHR(
     pSurfaceFormatCount: *mut u32,
     pSurfaceFormats: *mut VkSurfaceFormatKHR,
 ) -> VkResult {
-    let formats = surface
-        .raw
-        .supported_formats(&adapter.physical_device)
-        .map(|formats| formats.into_iter().map(conv::format_from_hal).collect())
-        .unwrap_or(vec![VkFormat::VK_FORMAT_UNDEFINED]);
+    let formats = surface_formats(&adapter, &surface);
 
     if pSurfaceFormats.is_null() {
         // Return only the number of formats
@@ -4444,12 +5711,7 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceSurfaceFormats2KHR(
     pSurfaceFormatCount: *mut u32,
     pSurfaceFormats: *mut VkSurfaceFormat2KHR,
 ) -> VkResult {
-    let formats = (*pSurfaceInfo)
-        .surface
-        .raw
-        .supported_formats(&adapter.physical_device)
-        .map(|formats| formats.into_iter().map(conv::format_from_hal).collect())
-        .unwrap_or(vec![VkFormat::VK_FORMAT_UNDEFINED]);
+    let formats = surface_formats(&adapter, &(*pSurfaceInfo).surface);
 
     if pSurfaceFormats.is_null() {
         // Return only the number of formats
@@ -4477,10 +5739,7 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceSurfacePresentModesKHR(
     pPresentModeCount: *mut u32,
     pPresentModes: *mut VkPresentModeKHR,
 ) -> VkResult {
-    let present_modes = surface
-        .raw
-        .capabilities(&adapter.physical_device)
-        .present_modes;
+    let present_modes = surface_capabilities(&adapter, &surface).present_modes;
 
     let num_present_modes = present_modes.bits().count_ones();
 
@@ -4519,77 +5778,280 @@ pub unsafe extern "C" fn gfxGetPhysicalDeviceWin32PresentationSupportKHR(
     VK_TRUE
 }
 
+#[inline]
+pub unsafe extern "C" fn gfxGetPhysicalDeviceWaylandPresentationSupportKHR(
+    _adapter: VkPhysicalDevice,
+    _queueFamilyIndex: u32,
+    _display: *mut c_void,
+) -> VkBool32 {
+    // The backends that can create Wayland surfaces, gfx-hal
+    // not telling the queue families apart.
+    if_backend!([vulkan, gl] => {
+        VK_TRUE
+    } else {
+        VK_FALSE
+    })
+}
+
 #[inline]
 pub unsafe extern "C" fn gfxCreateSwapchainKHR(
     gpu: VkDevice,
     pCreateInfo: *const VkSwapchainCreateInfoKHR,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
     pSwapchain: *mut VkSwapchainKHR,
 ) -> VkResult {
+    let _object = debug::ObjectScope::enter(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT,
+        gpu.as_raw(),
+    );
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let mut info = *pCreateInfo;
+    let settings = &gpu.settings;
+    if let Some(present_mode) = settings.workarounds.present_mode {
+        let forced = conv::map_forced_present_mode(present_mode);
+        let supported = surface_capabilities(&gpu.adapter, &info.surface).present_modes;
+        // FIFO is the only mode every surface supports.
+        info.presentMode = if supported.contains(conv::map_present_mode(forced)) {
+            forced
+        } else {
+            warn!(
+                "Forced present mode {:?} is not supported by the surface, using FIFO",
+                forced
+            );
+            VkPresentModeKHR::VK_PRESENT_MODE_FIFO_KHR
+        };
+    }
     // TODO: more checks
     if info.clipped == 0 {
         warn!("Non-clipped swapchain requested");
     }
-    assert_eq!(
+    if let Err(code) = check_sharing_mode(
+        gpu,
         info.imageSharingMode,
-        VkSharingMode::VK_SHARING_MODE_EXCLUSIVE
-    ); // TODO
+        info.queueFamilyIndexCount,
+        info.pQueueFamilyIndices,
+        "vkCreateSwapchainKHR",
+    ) {
+        return code;
+    }
 
-    let config = hal::window::SwapchainConfig {
+    // The requested number of images, within the capabilities of the surface.
+    let capabilities = surface_capabilities(&gpu.adapter, &info.surface);
+    let image_count = capabilities.image_count;
+    let mut config = hal::window::SwapchainConfig {
         present_mode: conv::map_present_mode(info.presentMode),
         composite_alpha_mode: conv::map_composite_alpha(info.compositeAlpha),
         format: conv::map_format(info.imageFormat).unwrap(),
         extent: conv::map_extent2d(info.imageExtent),
-        image_count: info.minImageCount,
+        image_count: info
+            .minImageCount
+            .max(*image_count.start())
+            .min(*image_count.end()),
         image_layers: info.imageArrayLayers as _,
         image_usage: conv::map_image_usage(info.imageUsage),
     };
+    // Read back by the dumps, if the surface supports it.
+    let dumped = settings.frame_dump.directory.is_some();
+    let readable = capabilities.usage.contains(hal::image::Usage::TRANSFER_SRC);
+    if dumped && readable {
+        config.image_usage |= hal::image::Usage::TRANSFER_SRC;
+    } else if dumped {
+        warn!("Frames can't be dumped from a surface that doesn't support copying its images");
+    }
     let framebuffer_attachment = config.framebuffer_attachment();
+    let (format, extent, requested) = (config.format, config.extent, config.image_count);
 
-    match info
-        .surface
-        .as_mut()
-        .unwrap()
-        .raw
-        .configure_swapchain(&gpu.device, config)
-    {
-        Ok(()) => {
-            let frame_count = info.minImageCount;
+    // Allocated first, so that running out of host memory leaves the surface untouched.
+    let mut image_allocations = Vec::with_capacity(requested as usize);
+    for _ in 0..requested {
+        let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
+        image_allocations.push(allocation);
+    }
+
+    let result = match info.surface.as_mut().unwrap().raw {
+        Some(ref mut raw) => raw
+            .configure_swapchain(&gpu.device, config)
+            .map(|()| Vec::new())
+            .map_err(map_swapchain_error),
+        None => create_offscreen_images(gpu, &config),
+    };
+    match result {
+        Ok(offscreen) => {
+            // The headless swapchains expose the images actually created. gfx-hal
+            // doesn't report the number of images of the native ones, which expose
+            // the requested number: the indices given to the application cycle
+            // over it, whatever the backend created.
+            let frame_count = if info.surface.raw.is_some() {
+                requested
+            } else {
+                offscreen.len() as hal::window::SwapImageIndex
+            };
             let revision = info.surface.swapchain_revision;
             info.surface.swapchain_revision += 1;
             let swapchain = Swapchain {
+                gpu,
                 surface: info.surface,
+                format,
+                extent,
                 frame_count,
                 current_index: 0,
                 active: (0..frame_count).map(|_| None).collect(),
+                offscreen,
                 framebuffer_attachment,
+                images: Vec::new(),
                 revision,
+                frames: crate::frame_dump::Frames::new(readable),
             };
-            *pSwapchain = Handle::new(swapchain);
+            let mut swapchain = allocation.init(swapchain);
+            let images = image_allocations
+                .into_iter()
+                .zip(0..frame_count)
+                .map(|(allocation, frame)| {
+                    allocation.init(Image::SwapchainFrame { swapchain, frame })
+                })
+                .collect();
+            swapchain.images = images;
+            *pSwapchain = swapchain;
             VkResult::VK_SUCCESS
         }
-        Err(err) => {
-            use hal::window::SwapchainError as Se;
-            match err {
-                Se::OutOfMemory(oom) => map_oom(oom),
-                Se::DeviceLost(hal::device::DeviceLost) => VkResult::VK_ERROR_DEVICE_LOST,
-                Se::SurfaceLost(hal::window::SurfaceLost) => VkResult::VK_ERROR_SURFACE_LOST_KHR,
-                Se::WindowInUse => VkResult::VK_ERROR_NATIVE_WINDOW_IN_USE_KHR,
-                Se::Unknown => VkResult::VK_ERROR_UNKNOWN,
+        Err(code) => code,
+    }
+}
+
+fn map_swapchain_error(err: hal::window::SwapchainError) -> VkResult {
+    use hal::window::SwapchainError as Se;
+    match err {
+        Se::OutOfMemory(oom) => map_oom(oom),
+        Se::DeviceLost(hal::device::DeviceLost) => VkResult::VK_ERROR_DEVICE_LOST,
+        Se::SurfaceLost(hal::window::SurfaceLost) => VkResult::VK_ERROR_SURFACE_LOST_KHR,
+        Se::WindowInUse => VkResult::VK_ERROR_NATIVE_WINDOW_IN_USE_KHR,
+        Se::Unknown => VkResult::VK_ERROR_UNKNOWN,
+    }
+}
+
+/// Creates the images of a headless swapchain, in the first memory type
+/// they support.
+unsafe fn create_offscreen_images(
+    gpu: VkDevice,
+    config: &hal::window::SwapchainConfig,
+) -> Result<Vec<OffscreenImage<B>>, VkResult> {
+    let mut images = Vec::with_capacity(config.image_count as usize);
+    for _ in 0..config.image_count {
+        match create_offscreen_image(gpu, config) {
+            Ok(image) => images.push(image),
+            Err(code) => {
+                destroy_offscreen_images(&gpu, images);
+                return Err(code);
             }
         }
     }
+    Ok(images)
 }
+
+unsafe fn create_offscreen_image(
+    gpu: VkDevice,
+    config: &hal::window::SwapchainConfig,
+) -> Result<OffscreenImage<B>, VkResult> {
+    let kind = hal::image::Kind::D2(
+        config.extent.width,
+        config.extent.height,
+        config.image_layers,
+        1,
+    );
+    let mut raw = gpu
+        .device
+        .create_image(
+            kind,
+            1,
+            config.format,
+            hal::image::Tiling::Optimal,
+            config.image_usage,
+            hal::memory::SparseFlags::empty(),
+            hal::image::ViewCapabilities::empty(),
+        )
+        .map_err(|e| map_image_creation_error(gpu.as_raw(), e))?;
+
+    let requirements = gpu.device.get_image_requirements(&raw);
+    // A device-local type if there is one, like the images of a real swapchain.
+    let allowed = |i: usize| requirements.type_mask & (1 << i) != 0;
+    let memory_type = gpu
+        .memory_types
+        .iter()
+        .enumerate()
+        .position(|(i, ty)| allowed(i) && ty.properties.contains(memory::Properties::DEVICE_LOCAL))
+        .or_else(|| (0..gpu.memory_types.len()).find(|&i| allowed(i)));
+    let memory = match memory_type {
+        Some(id) => gpu
+            .device
+            .allocate_memory(hal::MemoryTypeId(id), requirements.size)
+            .map_err(map_alloc_error),
+        None => {
+            error!("No memory type can hold the offscreen swapchain images");
+            Err(VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY)
+        }
+    };
+    let memory = match memory {
+        Ok(memory) => memory,
+        Err(code) => {
+            gpu.device.destroy_image(raw);
+            return Err(code);
+        }
+    };
+    if let Err(bind_error) = gpu.device.bind_image_memory(&memory, 0, &mut raw) {
+        gpu.device.destroy_image(raw);
+        gpu.device.free_memory(memory);
+        return Err(map_bind_error(bind_error));
+    }
+
+    let range = hal::image::SubresourceRange {
+        aspects: hal::format::Aspects::COLOR,
+        level_start: 0,
+        level_count: None,
+        layer_start: 0,
+        layer_count: None,
+    };
+    match gpu.device.create_image_view(
+        &raw,
+        hal::image::ViewKind::D2,
+        config.format,
+        hal::format::Swizzle::NO,
+        config.image_usage,
+        range,
+    ) {
+        Ok(view) => Ok(OffscreenImage { raw, memory, view }),
+        Err(creation_error) => {
+            gpu.device.destroy_image(raw);
+            gpu.device.free_memory(memory);
+            Err(map_view_creation_error(gpu.as_raw(), creation_error))
+        }
+    }
+}
+
+unsafe fn destroy_offscreen_images(gpu: &Gpu<B>, images: Vec<OffscreenImage<B>>) {
+    for image in images {
+        gpu.device.destroy_image_view(image.view);
+        gpu.device.destroy_image(image.raw);
+        gpu.device.free_memory(image.memory);
+    }
+}
+
 #[inline]
 pub unsafe extern "C" fn gfxDestroySwapchainKHR(
     gpu: VkDevice,
     swapchain: VkSwapchainKHR,
-    _pAllocator: *const VkAllocationCallbacks,
+    pAllocator: *const VkAllocationCallbacks,
 ) {
-    if let Some(mut sc) = swapchain.unbox() {
-        if sc.surface.swapchain_revision == swapchain.revision {
-            sc.surface.raw.unconfigure_swapchain(&gpu.device);
+    if let Some(mut sc) = swapchain.unbox_in(pAllocator) {
+        for image in sc.images.drain(..) {
+            let _ = image.unbox_in(pAllocator);
+        }
+        let revision = sc.surface.swapchain_revision;
+        match sc.surface.raw {
+            Some(ref mut raw) if revision == sc.revision => {
+                raw.unconfigure_swapchain(&gpu.device);
+            }
+            Some(_) => {}
+            None => destroy_offscreen_images(&gpu, sc.offscreen),
         }
     }
 }
@@ -4603,7 +6065,7 @@ pub unsafe extern "C" fn gfxGetSwapchainImagesKHR(
     debug_assert!(!pSwapchainImageCount.is_null());
 
     let swapchain_image_count = &mut *pSwapchainImageCount;
-    let available_images = swapchain.frame_count;
+    let available_images = swapchain.images.len() as u32;
 
     if pSwapchainImages.is_null() {
         // If NULL the number of presentable images is returned.
@@ -4611,10 +6073,8 @@ pub unsafe extern "C" fn gfxGetSwapchainImagesKHR(
     } else {
         *swapchain_image_count = available_images.min(*swapchain_image_count);
 
-        for frame in 0..*swapchain_image_count {
-            *pSwapchainImages.offset(frame as isize) =
-                Handle::new(Image::SwapchainFrame { swapchain, frame });
-        }
+        let images = slice::from_raw_parts_mut(pSwapchainImages, *swapchain_image_count as _);
+        images.copy_from_slice(&swapchain.images[..images.len()]);
 
         if *swapchain_image_count < available_images {
             return VkResult::VK_INCOMPLETE;
@@ -4776,37 +6236,35 @@ pub unsafe extern "C" fn gfxCreateWin32SurfaceKHR(
     pAllocator: *const VkAllocationCallbacks,
     pSurface: *mut VkSurfaceKHR,
 ) -> VkResult {
-    assert!(pAllocator.is_null());
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
-    #[cfg(all(feature = "gfx-backend-vulkan", target_os = "windows"))]
-    {
-        assert_eq!(info.flags, 0);
-        *pSurface = Handle::new(
-            instance
-                .backend
-                .create_surface_from_hwnd(info.hinstance, info.hwnd),
-        );
-        VkResult::VK_SUCCESS
-    }
-    #[cfg(any(feature = "gfx-backend-dx12", feature = "gfx-backend-dx11"))]
+    #[cfg(target_os = "windows")]
     {
-        assert_eq!(info.flags, 0);
-        *pSurface = Handle::new(Surface {
-            raw: instance.backend.create_surface_from_hwnd(info.hwnd),
-            swapchain_revision: 0,
-        });
-        VkResult::VK_SUCCESS
+        if_backend!([vulkan] => {
+            assert_eq!(info.flags, 0);
+            *pSurface = allocation.init(
+                instance
+                    .backend
+                    .create_surface_from_hwnd(info.hinstance, info.hwnd),
+            );
+            VkResult::VK_SUCCESS
+        } else {
+            if_backend!([dx12, dx11] => {
+                assert_eq!(info.flags, 0);
+                *pSurface = allocation.init(Surface {
+                    raw: Some(instance.backend.create_surface_from_hwnd(info.hwnd)),
+                    swapchain_revision: 0,
+                });
+                VkResult::VK_SUCCESS
+            } else {
+                let _ = (instance, info, pSurface, allocation);
+                unreachable!()
+            })
+        })
     }
-    #[cfg(not(all(
-        target_os = "windows",
-        any(
-            feature = "gfx-backend-vulkan",
-            feature = "gfx-backend-dx12",
-            feature = "gfx-backend-dx11"
-        )
-    )))]
+    #[cfg(not(target_os = "windows"))]
     {
-        let _ = (instance, info, pSurface);
+        let _ = (instance, info, pSurface, allocation);
         unreachable!()
     }
 }
@@ -4816,7 +6274,7 @@ pub unsafe extern "C" fn gfxCreateXlibSurfaceKHR(
     pAllocator: *const VkAllocationCallbacks,
     pSurface: *mut VkSurfaceKHR,
 ) -> VkResult {
-    assert!(pAllocator.is_null());
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
 
     #[cfg(target_os = "linux")]
@@ -4842,15 +6300,15 @@ pub unsafe extern "C" fn gfxCreateXlibSurfaceKHR(
             .create_surface(&HandleWrapper(xlib_handle))
             .unwrap();
 
-        *pSurface = Handle::new(Surface {
-            raw: raw_surface,
+        *pSurface = allocation.init(Surface {
+            raw: Some(raw_surface),
             swapchain_revision: 0,
         });
         VkResult::VK_SUCCESS
     }
     #[cfg(not(target_os = "linux"))]
     {
-        let _ = (instance, info, pSurface);
+        let _ = (instance, info, pSurface, allocation);
         unreachable!()
     }
 }
@@ -4860,7 +6318,7 @@ pub unsafe extern "C" fn gfxCreateXcbSurfaceKHR(
     pAllocator: *const VkAllocationCallbacks,
     pSurface: *mut VkSurfaceKHR,
 ) -> VkResult {
-    assert!(pAllocator.is_null());
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
 
     #[cfg(target_os = "linux")]
@@ -4886,18 +6344,71 @@ pub unsafe extern "C" fn gfxCreateXcbSurfaceKHR(
             .create_surface(&HandleWrapper(xcb_handle))
             .unwrap();
 
-        *pSurface = Handle::new(Surface {
-            raw: raw_surface,
+        *pSurface = allocation.init(Surface {
+            raw: Some(raw_surface),
             swapchain_revision: 0,
         });
         VkResult::VK_SUCCESS
     }
     #[cfg(not(target_os = "linux"))]
     {
-        let _ = (instance, info, pSurface);
+        let _ = (instance, info, pSurface, allocation);
         unreachable!()
     }
 }
+
+#[inline]
+pub unsafe extern "C" fn gfxCreateWaylandSurfaceKHR(
+    instance: VkInstance,
+    pCreateInfo: *const VkWaylandSurfaceCreateInfoKHR,
+    pAllocator: *const VkAllocationCallbacks,
+    pSurface: *mut VkSurfaceKHR,
+) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
+    let info = &*pCreateInfo;
+
+    #[cfg(target_os = "linux")]
+    {
+        assert_eq!(info.flags, 0);
+        use raw_window_handle::{unix::WaylandHandle, HasRawWindowHandle, RawWindowHandle};
+
+        struct HandleWrapper(WaylandHandle);
+        unsafe impl HasRawWindowHandle for HandleWrapper {
+            fn raw_window_handle(&self) -> RawWindowHandle {
+                RawWindowHandle::Wayland(self.0)
+            }
+        }
+
+        let wayland_handle = WaylandHandle {
+            surface: info.surface,
+            display: info.display,
+            ..WaylandHandle::empty()
+        };
+
+        let raw_surface = match instance
+            .backend
+            .create_surface(&HandleWrapper(wayland_handle))
+        {
+            Ok(surface) => surface,
+            Err(_) => {
+                error!("Unable to create the Wayland surface");
+                return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+            }
+        };
+
+        *pSurface = allocation.init(Surface {
+            raw: Some(raw_surface),
+            swapchain_revision: 0,
+        });
+        VkResult::VK_SUCCESS
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (instance, info, pSurface, allocation);
+        unreachable!()
+    }
+}
+
 #[inline]
 pub unsafe extern "C" fn gfxAcquireNextImageKHR(
     _gpu: VkDevice,
@@ -4914,10 +6425,49 @@ pub unsafe extern "C" fn gfxAcquireNextImageKHR(
         sem.is_fake.set(true);
     }
 
-    match swapchain.surface.raw.acquire_image(timeout) {
+    if swapchain.surface.raw.is_none() {
+        // The offscreen images are ready as soon as they are presented.
+        let count = swapchain.frame_count;
+        let current = swapchain.current_index;
+        let next = (1..=count)
+            .map(|i| (current + i) % count)
+            .find(|&index| swapchain.active[index as usize].is_none());
+        return match next {
+            Some(index) => {
+                swapchain.active[index as usize] = Some(AcquiredImage::Offscreen);
+                *pImageIndex = index;
+                swapchain.current_index = index;
+                VkResult::VK_SUCCESS
+            }
+            None if timeout == 0 => VkResult::VK_NOT_READY,
+            None if timeout != !0 => VkResult::VK_TIMEOUT,
+            // Only the application releases the images, by presenting them,
+            // so an infinite wait would never end.
+            None => {
+                if !validation::is_enabled() {
+                    return VkResult::VK_ERROR_OUT_OF_DATE_KHR;
+                }
+                validation::error(
+                    VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_SWAPCHAIN_KHR_EXT,
+                    swapchain.as_raw(),
+                    "vkAcquireNextImageKHR: all the images are acquired, \
+                     the timeout can't be UINT64_MAX",
+                );
+                VkResult::VK_ERROR_VALIDATION_FAILED_EXT
+            }
+        };
+    }
+
+    match swapchain
+        .surface
+        .raw
+        .as_mut()
+        .unwrap()
+        .acquire_image(timeout)
+    {
         Ok((frame, suboptimal)) => {
             let index = (swapchain.current_index + 1) % swapchain.frame_count;
-            swapchain.active[index as usize] = Some(frame);
+            swapchain.active[index as usize] = Some(AcquiredImage::Native(frame));
             *pImageIndex = index;
             swapchain.current_index = index;
             match suboptimal {
@@ -4933,11 +6483,266 @@ pub unsafe extern "C" fn gfxAcquireNextImageKHR(
         Err(hal::window::AcquireError::OutOfMemory(oom)) => map_oom(oom),
     }
 }
+/// Copies of the offscreen images of the presented frames to dump,
+/// submitted along with the presentation.
+struct FrameReadback {
+    gpu: VkDevice,
+    pool: <B as hal::Backend>::CommandPool,
+    cmd_buf: <B as hal::Backend>::CommandBuffer,
+    fence: <B as hal::Backend>::Fence,
+    frames: Vec<FrameCopy>,
+}
+
+struct FrameCopy {
+    frame: crate::frame_dump::Frame,
+    swapchain: VkSwapchainKHR,
+    buffer: <B as hal::Backend>::Buffer,
+    memory: <B as hal::Backend>::Memory,
+}
+
+impl FrameReadback {
+    /// Records the copies of the given swapchain images, all acquired
+    /// and in the `PRESENT_SRC_KHR` layout.
+    unsafe fn record(
+        queue: VkQueue,
+        dumps: Vec<(VkSwapchainKHR, u32, crate::frame_dump::Frame)>,
+    ) -> Option<Self> {
+        let gpu = dumps.first()?.0.gpu;
+        let family = gpu
+            .queues
+            .iter()
+            .find(|(_, queues)| queues.iter().any(|q| q.as_raw() == queue.as_raw()))
+            .map(|(&family, _)| queue::QueueFamilyId(family as _))?;
+        let fence = match gpu.device.create_fence(false) {
+            Ok(fence) => fence,
+            Err(oom) => {
+                error!("Unable to dump the frames: {:?}", oom);
+                return None;
+            }
+        };
+        let mut pool = match gpu
+            .device
+            .create_command_pool(family, hal::pool::CommandPoolCreateFlags::TRANSIENT)
+        {
+            Ok(pool) => pool,
+            Err(oom) => {
+                error!("Unable to dump the frames: {:?}", oom);
+                gpu.device.destroy_fence(fence);
+                return None;
+            }
+        };
+        let mut cmd_buf = pool.allocate_one(com::Level::Primary);
+        cmd_buf.begin_primary(com::CommandBufferFlags::ONE_TIME_SUBMIT);
+
+        let mut frames = Vec::with_capacity(dumps.len());
+        for (swapchain, index, frame) in dumps {
+            let image = match swapchain.image(index) {
+                Some(image) => image,
+                None => continue,
+            };
+            let extent = swapchain.extent;
+            let size = extent.width as u64 * extent.height as u64 * 4;
+            let (buffer, memory) = match create_readback_buffer(&gpu, size) {
+                Ok(readback) => readback,
+                Err(message) => {
+                    error!("Unable to dump frame {}: {}", frame.index, message);
+                    continue;
+                }
+            };
+
+            let range = hal::image::SubresourceRange {
+                aspects: hal::format::Aspects::COLOR,
+                level_start: 0,
+                level_count: Some(1),
+                layer_start: 0,
+                layer_count: Some(1),
+            };
+            let present = (hal::image::Access::empty(), hal::image::Layout::Present);
+            let transfer = (
+                hal::image::Access::TRANSFER_READ,
+                hal::image::Layout::TransferSrcOptimal,
+            );
+            cmd_buf.pipeline_barrier(
+                pso::PipelineStage::BOTTOM_OF_PIPE..pso::PipelineStage::TRANSFER,
+                memory::Dependencies::empty(),
+                iter::once(memory::Barrier::Image {
+                    states: present..transfer,
+                    target: image,
+                    range: range.clone(),
+                    families: None,
+                }),
+            );
+            cmd_buf.copy_image_to_buffer(
+                image,
+                hal::image::Layout::TransferSrcOptimal,
+                &buffer,
+                iter::once(com::BufferImageCopy {
+                    buffer_offset: 0,
+                    buffer_width: 0,
+                    buffer_height: 0,
+                    image_layers: hal::image::SubresourceLayers {
+                        aspects: hal::format::Aspects::COLOR,
+                        level: 0,
+                        layers: 0..1,
+                    },
+                    image_offset: hal::image::Offset::ZERO,
+                    image_extent: extent.to_extent(),
+                }),
+            );
+            cmd_buf.pipeline_barrier(
+                pso::PipelineStage::TRANSFER..pso::PipelineStage::HOST,
+                memory::Dependencies::empty(),
+                vec![
+                    memory::Barrier::Image {
+                        states: transfer..present,
+                        target: image,
+                        range,
+                        families: None,
+                    },
+                    memory::Barrier::Buffer {
+                        states: hal::buffer::Access::TRANSFER_WRITE..hal::buffer::Access::HOST_READ,
+                        target: &buffer,
+                        range: hal::buffer::SubRange::WHOLE,
+                        families: None,
+                    },
+                ]
+                .into_iter(),
+            );
+            frames.push(FrameCopy {
+                frame,
+                swapchain,
+                buffer,
+                memory,
+            });
+        }
+        cmd_buf.finish();
+
+        Some(FrameReadback {
+            gpu,
+            pool,
+            cmd_buf,
+            fence,
+            frames,
+        })
+    }
+
+    /// Waits for the copies to complete and writes the frames.
+    unsafe fn finish(mut self) {
+        let gpu = self.gpu;
+        let settings = &gpu.settings;
+        match self.gpu.device.wait_for_fence(&self.fence, !0) {
+            Ok(true) => {
+                for copy in &mut self.frames {
+                    if let Err(message) = write_frame(self.gpu, &settings.frame_dump, copy) {
+                        error!("Unable to dump frame {}: {}", copy.frame.index, message);
+                    }
+                }
+            }
+            Ok(false) => error!("Unable to dump the frames: timeout"),
+            Err(wait_error) => error!("Unable to dump the frames: {:?}", wait_error),
+        }
+
+        for copy in self.frames {
+            self.gpu.device.destroy_buffer(copy.buffer);
+            self.gpu.device.free_memory(copy.memory);
+        }
+        self.pool.free(iter::once(self.cmd_buf));
+        self.gpu.device.destroy_command_pool(self.pool);
+        self.gpu.device.destroy_fence(self.fence);
+    }
+}
+
+/// Creates a buffer in host-visible memory, for the copy of a frame.
+unsafe fn create_readback_buffer(
+    gpu: &Gpu<B>,
+    size: u64,
+) -> Result<(<B as hal::Backend>::Buffer, <B as hal::Backend>::Memory), String> {
+    let mut buffer = gpu
+        .device
+        .create_buffer(
+            size,
+            hal::buffer::Usage::TRANSFER_DST,
+            hal::memory::SparseFlags::empty(),
+        )
+        .map_err(|e| format!("{:?}", e))?;
+    let requirements = gpu.device.get_buffer_requirements(&buffer);
+    let memory_type = gpu.memory_types.iter().enumerate().position(|(i, ty)| {
+        requirements.type_mask & (1 << i) != 0
+            && ty.properties.contains(memory::Properties::CPU_VISIBLE)
+    });
+    let memory = match memory_type {
+        Some(id) => gpu
+            .device
+            .allocate_memory(hal::MemoryTypeId(id), requirements.size)
+            .map_err(|e| format!("{:?}", e)),
+        None => Err("no host-visible memory type".to_string()),
+    };
+    let memory = match memory {
+        Ok(memory) => memory,
+        Err(message) => {
+            gpu.device.destroy_buffer(buffer);
+            return Err(message);
+        }
+    };
+    if let Err(bind_error) = gpu.device.bind_buffer_memory(&memory, 0, &mut buffer) {
+        gpu.device.destroy_buffer(buffer);
+        gpu.device.free_memory(memory);
+        return Err(format!("{:?}", bind_error));
+    }
+    Ok((buffer, memory))
+}
+
+unsafe fn write_frame(
+    gpu: VkDevice,
+    settings: &crate::config::FrameDump,
+    copy: &mut FrameCopy,
+) -> Result<(), String> {
+    use crate::frame_dump::Channels;
+    use hal::format::Format;
+
+    let format = copy.swapchain.format;
+    let channels = match format {
+        Format::Rgba8Unorm | Format::Rgba8Srgb => Channels::Rgba,
+        Format::Bgra8Unorm | Format::Bgra8Srgb => Channels::Bgra,
+        other => return Err(format!("unsupported format {:?}", other)),
+    };
+    let extent = copy.swapchain.extent;
+    let size = extent.width as usize * extent.height as usize * 4;
+
+    let ptr = gpu
+        .device
+        .map_memory(&mut copy.memory, memory::Segment::ALL)
+        .map_err(|e| format!("{:?}", e))?;
+    let result = gpu
+        .device
+        .invalidate_mapped_memory_ranges(iter::once((&copy.memory, memory::Segment::ALL)))
+        .map_err(|e| format!("{:?}", e))
+        .and_then(|()| {
+            crate::frame_dump::write(
+                settings,
+                &copy.frame,
+                extent.width,
+                extent.height,
+                channels,
+                &format!("{:?}", format),
+                slice::from_raw_parts(ptr, size),
+            )
+            .map_err(|e| e.to_string())
+        });
+    gpu.device.unmap_memory(&mut copy.memory);
+    result
+}
+
 #[inline]
 pub unsafe extern "C" fn gfxQueuePresentKHR(
     mut queue: VkQueue,
     pPresentInfo: *const VkPresentInfoKHR,
 ) -> VkResult {
+    let _object = debug::ObjectScope::enter(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_QUEUE_EXT,
+        queue.as_raw(),
+    );
+    let _scope = debug::QueueScope::enter(&queue.labels);
     let info = &*pPresentInfo;
 
     let swapchain_slice = slice::from_raw_parts(info.pSwapchains, info.swapchainCount as _);
@@ -4953,17 +6758,75 @@ pub unsafe extern "C" fn gfxQueuePresentKHR(
         );
     }
 
+    let mut offscreen = false;
+    let mut dumps = Vec::new();
+    for (swapchain, &index) in swapchain_slice.iter().zip(index_slice) {
+        let sc = swapchain.as_mut().unwrap();
+        match sc.active.get(index as usize) {
+            Some(Some(AcquiredImage::Offscreen)) => offscreen = true,
+            Some(Some(AcquiredImage::Native(_))) => {}
+            _ => {
+                validation::error(
+                    VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_SWAPCHAIN_KHR_EXT,
+                    swapchain.as_raw(),
+                    &format!(
+                        "vkQueuePresentKHR: swapchain image {} is not acquired",
+                        index
+                    ),
+                );
+                return VkResult::VK_ERROR_VALIDATION_FAILED_EXT;
+            }
+        }
+        let gpu = sc.gpu;
+        if let Some(dumped) = sc.frames.present(&gpu.settings.frame_dump) {
+            dumps.push((*swapchain, index, dumped));
+        }
+    }
+
+    // The frames are read back while their images are still acquired, after
+    // the semaphores, so the native images are then presented without them.
+    let mut waited = false;
+    if let Some(mut readback) = FrameReadback::record(queue, dumps) {
+        queue.raw.submit(
+            iter::once(&readback.cmd_buf),
+            wait_semaphores
+                .iter()
+                .filter(|sem| !sem.is_fake.get())
+                .map(|sem| (&sem.raw, pso::PipelineStage::TRANSFER)),
+            iter::empty(),
+            Some(&mut readback.fence),
+        );
+        readback.finish();
+        waited = true;
+    }
+
     for (swapchain, &index) in swapchain_slice.iter().zip(index_slice) {
         let sc = swapchain.as_mut().unwrap();
-        let frame = sc.active[index as usize]
-            .take()
-            .expect("Frame was not acquired properly!");
-        let sem = wait_semaphores.first_mut().map(|s| &mut s.raw);
-        if let Err(_) = queue.raw.present(&mut sc.surface.raw, frame, sem) {
-            return VkResult::VK_ERROR_SURFACE_LOST_KHR;
+        if let Some(AcquiredImage::Native(frame)) = sc.active[index as usize].take() {
+            let surface = sc.surface.raw.as_mut().unwrap();
+            let sem = match wait_semaphores.first_mut() {
+                Some(sem) if !waited => Some(&mut sem.raw),
+                _ => None,
+            };
+            if let Err(_) = queue.raw.present(surface, frame, sem) {
+                return VkResult::VK_ERROR_SURFACE_LOST_KHR;
+            }
         }
     }
 
+    if offscreen && !waited {
+        // Nothing reads the offscreen images, the semaphores are just waited on.
+        queue.raw.submit(
+            iter::empty(),
+            wait_semaphores
+                .iter()
+                .filter(|sem| !sem.is_fake.get())
+                .map(|sem| (&sem.raw, pso::PipelineStage::BOTTOM_OF_PIPE)),
+            iter::empty(),
+            None,
+        );
+    }
+
     VkResult::VK_SUCCESS
 }
 
@@ -4974,24 +6837,23 @@ pub unsafe extern "C" fn gfxCreateMetalSurfaceEXT(
     pAllocator: *const VkAllocationCallbacks,
     pSurface: *mut VkSurfaceKHR,
 ) -> VkResult {
-    assert!(pAllocator.is_null());
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
-    #[cfg(feature = "gfx-backend-metal")]
-    {
+    if_backend!([metal] => {
         assert_eq!(info.flags, 0);
-        *pSurface = Handle::new(Surface {
-            raw: instance
-                .backend
-                .create_surface_from_layer(mem::transmute(info.pLayer)),
+        *pSurface = allocation.init(Surface {
+            raw: Some(
+                instance
+                    .backend
+                    .create_surface_from_layer(mem::transmute(info.pLayer)),
+            ),
             swapchain_revision: 0,
         });
         VkResult::VK_SUCCESS
-    }
-    #[cfg(not(feature = "gfx-backend-metal"))]
-    {
-        let _ = (instance, info, pSurface);
+    } else {
+        let _ = (instance, info, pSurface, allocation);
         unreachable!()
-    }
+    })
 }
 
 #[inline]
@@ -5001,24 +6863,45 @@ pub unsafe extern "C" fn gfxCreateMacOSSurfaceMVK(
     pAllocator: *const VkAllocationCallbacks,
     pSurface: *mut VkSurfaceKHR,
 ) -> VkResult {
-    assert!(pAllocator.is_null());
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
     let info = &*pCreateInfo;
-    #[cfg(all(target_os = "macos", feature = "gfx-backend-metal"))]
+    #[cfg(target_os = "macos")]
     {
-        assert_eq!(info.flags, 0);
-        *pSurface = Handle::new(Surface {
-            raw: instance.backend.create_surface_from_nsview(info.pView),
-            swapchain_revision: 0,
-        });
-        VkResult::VK_SUCCESS
+        if_backend!([metal] => {
+            assert_eq!(info.flags, 0);
+            *pSurface = allocation.init(Surface {
+                raw: Some(instance.backend.create_surface_from_nsview(info.pView)),
+                swapchain_revision: 0,
+            });
+            VkResult::VK_SUCCESS
+        } else {
+            let _ = (instance, info, pSurface, allocation);
+            unreachable!()
+        })
     }
-    #[cfg(not(all(target_os = "macos", feature = "gfx-backend-metal")))]
+    #[cfg(not(target_os = "macos"))]
     {
-        let _ = (instance, info, pSurface);
+        let _ = (instance, info, pSurface, allocation);
         unreachable!()
     }
 }
 
+#[inline]
+pub unsafe extern "C" fn gfxCreateHeadlessSurfaceEXT(
+    _instance: VkInstance,
+    pCreateInfo: *const VkHeadlessSurfaceCreateInfoEXT,
+    pAllocator: *const VkAllocationCallbacks,
+    pSurface: *mut VkSurfaceKHR,
+) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
+    assert_eq!((*pCreateInfo).flags, 0);
+    *pSurface = allocation.init(Surface {
+        raw: None,
+        swapchain_revision: 0,
+    });
+    VkResult::VK_SUCCESS
+}
+
 #[inline]
 pub unsafe extern "C" fn gfxDebugMarkerSetObjectTagEXT(
     _gpu: VkDevice,
@@ -5031,37 +6914,48 @@ pub unsafe extern "C" fn gfxDebugMarkerSetObjectNameEXT(
     gpu: VkDevice,
     pNameInfo: *mut VkDebugMarkerObjectNameInfoEXT,
 ) -> VkResult {
-    use VkDebugReportObjectTypeEXT::*;
-
     let info = &*pNameInfo;
     let name = CStr::from_ptr(info.pObjectName).to_string_lossy();
-    match info.objectType {
+    set_object_name(&gpu, info.objectType, info.object, &name);
+    VkResult::VK_SUCCESS
+}
+
+unsafe fn set_object_name(
+    gpu: &Gpu<B>,
+    object_type: VkDebugReportObjectTypeEXT,
+    object: u64,
+    name: &str,
+) {
+    use VkDebugReportObjectTypeEXT::*;
+
+    let _object = debug::ObjectScope::enter(object_type, object);
+    handle::set_name(object, name);
+    match object_type {
         VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_EXT => {
-            let mut h = mem::transmute::<_, VkBuffer>(info.object);
-            gpu.device.set_buffer_name(&mut *h, &*name);
+            let mut h = mem::transmute::<_, VkBuffer>(object);
+            gpu.device.set_buffer_name(&mut *h, name);
         }
-        VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT => match *mem::transmute::<_, VkImage>(info.object) {
-            Image::Native { ref mut raw, .. } => gpu.device.set_image_name(raw, &*name),
+        VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT => match *mem::transmute::<_, VkImage>(object) {
+            Image::Native { ref mut raw, .. } => gpu.device.set_image_name(raw, name),
             Image::SwapchainFrame { .. } => (),
         },
         VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT => {
-            let mut h = mem::transmute::<_, VkCommandBuffer>(info.object);
-            gpu.device.set_command_buffer_name(&mut *h, &*name);
+            let mut h = mem::transmute::<_, VkCommandBuffer>(object);
+            gpu.device.set_command_buffer_name(&mut *h, name);
         }
         VK_DEBUG_REPORT_OBJECT_TYPE_FRAMEBUFFER_EXT => {
-            let mut h = mem::transmute::<_, VkFramebuffer>(info.object);
-            gpu.device.set_framebuffer_name(&mut h.raw, &*name);
+            let mut h = mem::transmute::<_, VkFramebuffer>(object);
+            gpu.device.set_framebuffer_name(&mut h.raw, name);
         }
         VK_DEBUG_REPORT_OBJECT_TYPE_RENDER_PASS_EXT => {
-            let mut h = mem::transmute::<_, VkRenderPass>(info.object);
-            gpu.device.set_render_pass_name(&mut *h, &*name);
+            let mut h = mem::transmute::<_, VkRenderPass>(object);
+            gpu.device.set_render_pass_name(&mut *h, name);
         }
         VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_EXT => {
             warn!("Unable to set the pipeline name {}", name);
         }
         _ => {}
     }
-    VkResult::VK_SUCCESS
 }
 #[inline]
 pub unsafe extern "C" fn gfxCmdDebugMarkerBeginEXT(
@@ -5085,3 +6979,168 @@ pub unsafe extern "C" fn gfxCmdDebugMarkerInsertEXT(
     let name = CStr::from_ptr(info.pMarkerName).to_string_lossy();
     commandBuffer.insert_debug_marker(&*name, conv::map_marker_color(info.color));
 }
+
+#[inline]
+pub unsafe extern "C" fn gfxCreateDebugUtilsMessengerEXT(
+    instance: VkInstance,
+    pCreateInfo: *const VkDebugUtilsMessengerCreateInfoEXT,
+    pAllocator: *const VkAllocationCallbacks,
+    pMessenger: *mut VkDebugUtilsMessengerEXT,
+) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
+    let info = &*pCreateInfo;
+    let messenger = allocation.init(DebugUtilsMessenger {
+        severity: info.messageSeverity,
+        types: info.messageType,
+        callback: info.pfnUserCallback,
+        user_data: info.pUserData,
+    });
+    debug::register(instance, messenger);
+    *pMessenger = messenger;
+    VkResult::VK_SUCCESS
+}
+#[inline]
+pub unsafe extern "C" fn gfxDestroyDebugUtilsMessengerEXT(
+    _instance: VkInstance,
+    messenger: VkDebugUtilsMessengerEXT,
+    pAllocator: *const VkAllocationCallbacks,
+) {
+    if messenger != Handle::null() {
+        debug::unregister(messenger);
+        let _ = messenger.unbox_in(pAllocator);
+    }
+}
+#[inline]
+pub unsafe extern "C" fn gfxSubmitDebugUtilsMessageEXT(
+    _instance: VkInstance,
+    messageSeverity: VkDebugUtilsMessageSeverityFlagBitsEXT,
+    messageTypes: VkDebugUtilsMessageTypeFlagsEXT,
+    pCallbackData: *const VkDebugUtilsMessengerCallbackDataEXT,
+) {
+    debug::submit(messageSeverity, messageTypes, &*pCallbackData);
+}
+#[inline]
+pub unsafe extern "C" fn gfxCreateDebugReportCallbackEXT(
+    instance: VkInstance,
+    pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT,
+    pAllocator: *const VkAllocationCallbacks,
+    pCallback: *mut VkDebugReportCallbackEXT,
+) -> VkResult {
+    let allocation = alloc_handle!(Handle, pAllocator, VK_SYSTEM_ALLOCATION_SCOPE_OBJECT);
+    let info = &*pCreateInfo;
+    let callback = allocation.init(DebugReportCallback {
+        flags: info.flags,
+        callback: info.pfnCallback,
+        user_data: info.pUserData,
+    });
+    debug::register_report(instance, callback);
+    *pCallback = callback;
+    VkResult::VK_SUCCESS
+}
+#[inline]
+pub unsafe extern "C" fn gfxDestroyDebugReportCallbackEXT(
+    _instance: VkInstance,
+    callback: VkDebugReportCallbackEXT,
+    pAllocator: *const VkAllocationCallbacks,
+) {
+    if callback != Handle::null() {
+        debug::unregister_report(callback);
+        let _ = callback.unbox_in(pAllocator);
+    }
+}
+#[inline]
+pub unsafe extern "C" fn gfxDebugReportMessageEXT(
+    _instance: VkInstance,
+    flags: VkDebugReportFlagsEXT,
+    objectType: VkDebugReportObjectTypeEXT,
+    object: u64,
+    location: usize,
+    messageCode: i32,
+    pLayerPrefix: *const ::std::os::raw::c_char,
+    pMessage: *const ::std::os::raw::c_char,
+) {
+    debug::report(
+        flags,
+        objectType,
+        object,
+        location,
+        messageCode,
+        CStr::from_ptr(pLayerPrefix),
+        CStr::from_ptr(pMessage),
+    );
+}
+#[inline]
+pub unsafe extern "C" fn gfxSetDebugUtilsObjectNameEXT(
+    gpu: VkDevice,
+    pNameInfo: *const VkDebugUtilsObjectNameInfoEXT,
+) -> VkResult {
+    let info = &*pNameInfo;
+    if !info.pObjectName.is_null() {
+        let name = CStr::from_ptr(info.pObjectName).to_string_lossy();
+        set_object_name(
+            &gpu,
+            debug::map_object_type(info.objectType),
+            info.objectHandle,
+            &name,
+        );
+    }
+    VkResult::VK_SUCCESS
+}
+#[inline]
+pub unsafe extern "C" fn gfxSetDebugUtilsObjectTagEXT(
+    _gpu: VkDevice,
+    _pTagInfo: *const VkDebugUtilsObjectTagInfoEXT,
+) -> VkResult {
+    VkResult::VK_SUCCESS //TODO
+}
+#[inline]
+pub unsafe extern "C" fn gfxQueueBeginDebugUtilsLabelEXT(
+    mut queue: VkQueue,
+    pLabelInfo: *const VkDebugUtilsLabelEXT,
+) {
+    let info = &*pLabelInfo;
+    queue
+        .labels
+        .push(CStr::from_ptr(info.pLabelName).to_owned());
+}
+#[inline]
+pub unsafe extern "C" fn gfxQueueEndDebugUtilsLabelEXT(mut queue: VkQueue) {
+    queue.labels.pop();
+}
+#[inline]
+pub unsafe extern "C" fn gfxQueueInsertDebugUtilsLabelEXT(
+    queue: VkQueue,
+    pLabelInfo: *const VkDebugUtilsLabelEXT,
+) {
+    let info = &*pLabelInfo;
+    let _scope = debug::QueueScope::enter(&queue.labels);
+    debug::message(
+        VkDebugUtilsMessageSeverityFlagBitsEXT::VK_DEBUG_UTILS_MESSAGE_SEVERITY_VERBOSE_BIT_EXT,
+        VkDebugUtilsMessageTypeFlagBitsEXT::VK_DEBUG_UTILS_MESSAGE_TYPE_GENERAL_BIT_EXT as _,
+        "vkQueueInsertDebugUtilsLabelEXT",
+        &CStr::from_ptr(info.pLabelName).to_string_lossy(),
+        None,
+    );
+}
+#[inline]
+pub unsafe extern "C" fn gfxCmdBeginDebugUtilsLabelEXT(
+    mut commandBuffer: VkCommandBuffer,
+    pLabelInfo: *const VkDebugUtilsLabelEXT,
+) {
+    let info = &*pLabelInfo;
+    let name = CStr::from_ptr(info.pLabelName).to_string_lossy();
+    commandBuffer.begin_debug_marker(&*name, conv::map_marker_color(info.color));
+}
+#[inline]
+pub unsafe extern "C" fn gfxCmdEndDebugUtilsLabelEXT(mut commandBuffer: VkCommandBuffer) {
+    commandBuffer.end_debug_marker();
+}
+#[inline]
+pub unsafe extern "C" fn gfxCmdInsertDebugUtilsLabelEXT(
+    mut commandBuffer: VkCommandBuffer,
+    pLabelInfo: *const VkDebugUtilsLabelEXT,
+) {
+    let info = &*pLabelInfo;
+    let name = CStr::from_ptr(info.pLabelName).to_string_lossy();
+    commandBuffer.insert_debug_marker(&*name, conv::map_marker_color(info.color));
+}
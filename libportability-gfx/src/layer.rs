@@ -0,0 +1,2878 @@
+//! The portability layer on top of a single backend.
+//!
+//! `lib.rs` compiles this module once for every enabled backend,
+//! each instance seeing its own `back` and `BACKEND`.
+
+use super::{back, BACKEND};
+
+use log::{error, info, warn};
+
+mod conv;
+pub mod impls;
+
+pub use crate::{
+    debug::{
+        DebugReportCallback, DebugUtilsMessenger, VkDebugReportCallbackEXT,
+        VkDebugUtilsMessengerEXT,
+    },
+    ffi::*,
+};
+
+use self::back::Backend as B;
+use crate::{
+    api_dump,
+    config::Settings,
+    debug,
+    handle::{self, DispatchHandle, Handle},
+    leaks, validation, workarounds,
+};
+
+use std::{
+    borrow::Borrow, cell::Cell, collections::HashMap, ffi::CString, ops, os::raw, slice, sync::Arc,
+};
+
+// Vulkan objects
+pub type VkInstance = Handle<RawInstance>;
+pub type VkPhysicalDevice = Handle<Adapter<B>>;
+pub type VkDevice = DispatchHandle<Gpu<B>>;
+pub type VkQueue = DispatchHandle<Queue<B>>;
+pub type VkCommandPool = Handle<CommandPool<B>>;
+pub type VkCommandBuffer = DispatchHandle<<B as hal::Backend>::CommandBuffer>;
+pub type VkDeviceMemory = Handle<<B as hal::Backend>::Memory>;
+pub type VkDescriptorSetLayout = Handle<<B as hal::Backend>::DescriptorSetLayout>;
+pub type VkPipelineLayout = Handle<<B as hal::Backend>::PipelineLayout>;
+pub type VkDescriptorPool = Handle<DescriptorPool<B>>;
+pub type VkDescriptorSet = Handle<<B as hal::Backend>::DescriptorSet>;
+pub type VkSampler = Handle<<B as hal::Backend>::Sampler>;
+pub type VkBufferView = Handle<<B as hal::Backend>::BufferView>;
+pub type VkShaderModule = Handle<ShaderModule<B>>;
+pub type VkImage = Handle<Image<B>>;
+pub type VkImageView = Handle<ImageView>;
+pub type VkBuffer = Handle<<B as hal::Backend>::Buffer>;
+pub type VkSemaphore = Handle<Semaphore<B>>;
+pub type VkEvent = Handle<<B as hal::Backend>::Event>;
+pub type VkFence = Handle<Fence<B>>;
+pub type VkRenderPass = Handle<<B as hal::Backend>::RenderPass>;
+pub type VkFramebuffer = Handle<Framebuffer>;
+pub type VkPipeline = Handle<Pipeline<B>>;
+pub type VkPipelineCache = Handle<<B as hal::Backend>::PipelineCache>;
+pub type VkQueryPool = Handle<<B as hal::Backend>::QueryPool>;
+
+pub type QueueFamilyIndex = u32;
+
+pub struct RawInstance {
+    pub backend: back::Instance,
+    pub adapters: Vec<VkPhysicalDevice>,
+    pub enabled_extensions: Vec<String>,
+    /// Settings of the application that created the instance.
+    pub settings: Arc<Settings>,
+}
+
+pub struct Adapter<B: hal::Backend> {
+    raw: hal::adapter::Adapter<B>,
+    /// Settings of the instance the adapter was enumerated from.
+    settings: Arc<Settings>,
+    /// Version of the API requested by the application.
+    api_version: u32,
+}
+
+impl<B: hal::Backend> ops::Deref for Adapter<B> {
+    type Target = hal::adapter::Adapter<B>;
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+pub struct Gpu<B: hal::Backend> {
+    adapter: VkPhysicalDevice,
+    settings: Arc<Settings>,
+    device: B::Device,
+    queues: HashMap<QueueFamilyIndex, Vec<VkQueue>>,
+    queue_family_count: usize,
+    enabled_extensions: Vec<String>,
+    /// Memory types of the adapter, for the allocations of the library.
+    memory_types: Vec<hal::adapter::MemoryType>,
+    #[cfg(feature = "renderdoc")]
+    renderdoc: renderdoc::RenderDoc<renderdoc::V110>,
+    #[cfg(feature = "renderdoc")]
+    capturing: *mut (),
+}
+
+impl<B: hal::Backend> Gpu<B> {
+    fn has_extension(&self, extension: &[u8]) -> bool {
+        self.enabled_extensions
+            .iter()
+            .any(|ext| ext.as_bytes() == &extension[..extension.len() - 1])
+    }
+}
+
+pub struct Queue<B: hal::Backend> {
+    raw: B::Queue,
+    temp_semaphores: Vec<(VkPipelineStageFlags, VkSemaphore)>,
+    /// Open `VK_EXT_debug_utils` label regions.
+    labels: Vec<CString>,
+}
+
+pub struct DescriptorPool<B: hal::Backend> {
+    raw: B::DescriptorPool,
+    temp_sets: Vec<B::DescriptorSet>,
+    set_handles: Option<Vec<VkDescriptorSet>>,
+}
+
+/// Shader module, along with the size of the code the backend compiled it to,
+/// which is reported to the application as an internal allocation.
+pub struct ShaderModule<B: hal::Backend> {
+    raw: B::ShaderModule,
+    /// Size of the SPIR-V, standing for the one of the compiled code.
+    code_size: usize,
+}
+
+pub enum Pipeline<B: hal::Backend> {
+    Graphics(B::GraphicsPipeline),
+    Compute(B::ComputePipeline),
+}
+
+pub enum Image<B: hal::Backend> {
+    Native {
+        raw: B::Image,
+        //mip_levels: u32,
+        //array_layers: u32,
+        fb_attachment: hal::image::FramebufferAttachment,
+        usage: hal::image::Usage,
+    },
+    SwapchainFrame {
+        swapchain: VkSwapchainKHR,
+        frame: hal::window::SwapImageIndex,
+    },
+}
+
+impl validation::ImageUsage for Image<B> {
+    fn usage(&self) -> hal::image::Usage {
+        match *self {
+            Image::Native { usage, .. } => usage,
+            Image::SwapchainFrame { swapchain, .. } => swapchain.framebuffer_attachment.usage,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct UnexpectedSwapchainImage;
+
+impl Image<B> {
+    /// Fails for the images of the native swapchains that aren't acquired,
+    /// gfx-hal only exposing the acquired ones.
+    fn as_native(&self) -> Result<&<B as hal::Backend>::Image, UnexpectedSwapchainImage> {
+        match *self {
+            Image::Native { ref raw, .. } => Ok(raw),
+            Image::SwapchainFrame {
+                ref swapchain,
+                frame,
+            } => swapchain.image(frame).ok_or(UnexpectedSwapchainImage),
+        }
+    }
+}
+
+pub enum ImageView {
+    Native {
+        raw: <B as hal::Backend>::ImageView,
+        fb_attachment: hal::image::FramebufferAttachment,
+    },
+    SwapchainFrame {
+        swapchain: VkSwapchainKHR,
+        frame: hal::window::SwapImageIndex,
+    },
+}
+
+impl ImageView {
+    fn as_native(&self) -> Result<&<B as hal::Backend>::ImageView, UnexpectedSwapchainImage> {
+        match *self {
+            ImageView::Native { ref raw, .. } => Ok(raw),
+            ImageView::SwapchainFrame {
+                ref swapchain,
+                frame,
+            } => swapchain.image_view(frame).ok_or(UnexpectedSwapchainImage),
+        }
+    }
+
+    fn framebuffer_attachment(&self) -> hal::image::FramebufferAttachment {
+        match *self {
+            Self::Native {
+                raw: _,
+                ref fb_attachment,
+            } => fb_attachment.clone(),
+            Self::SwapchainFrame {
+                swapchain,
+                frame: _,
+            } => swapchain.framebuffer_attachment.clone(),
+        }
+    }
+}
+
+pub struct DescriptorUpdateTemplate {
+    entries: Vec<VkDescriptorUpdateTemplateEntryKHR>,
+}
+
+pub struct Framebuffer {
+    raw: <B as hal::Backend>::Framebuffer,
+    // If none, the VkFramebuffer is image-less
+    image_views: Option<Box<[VkImageView]>>,
+}
+
+pub struct Semaphore<B: hal::Backend> {
+    raw: B::Semaphore,
+    is_fake: Cell<bool>,
+}
+
+pub struct Fence<B: hal::Backend> {
+    raw: B::Fence,
+    is_fake: bool,
+}
+
+pub struct CommandPool<B: hal::Backend> {
+    pool: B::CommandPool,
+    buffers: Vec<VkCommandBuffer>,
+}
+
+//NOTE: all *KHR types have to be pure `Handle` things for compatibility with
+//`VK_DEFINE_NON_DISPATCHABLE_HANDLE` used in `vulkan.h`
+pub type VkSurfaceKHR = Handle<Surface<B>>;
+pub type VkSwapchainKHR = Handle<Swapchain<B>>;
+
+pub struct Surface<B: hal::Backend> {
+    /// `None` for the headless surfaces, which are not presented anywhere.
+    raw: Option<B::Surface>,
+    swapchain_revision: u32,
+}
+
+pub struct Swapchain<B: hal::Backend> {
+    gpu: VkDevice,
+    surface: VkSurfaceKHR,
+    format: hal::format::Format,
+    extent: hal::window::Extent2D,
+    framebuffer_attachment: hal::image::FramebufferAttachment,
+    /// Handles of the images, returned by every `vkGetSwapchainImagesKHR`.
+    images: Vec<VkImage>,
+    frame_count: hal::window::SwapImageIndex,
+    current_index: hal::window::SwapImageIndex,
+    active: Vec<Option<AcquiredImage<B>>>,
+    /// Images of a headless swapchain, which stand for the presentable ones.
+    offscreen: Vec<OffscreenImage<B>>,
+    revision: u32,
+    frames: crate::frame_dump::Frames,
+}
+
+impl Swapchain<B> {
+    /// Returns the image of a frame, which has to be acquired
+    /// if the swapchain is not headless.
+    fn image(&self, frame: hal::window::SwapImageIndex) -> Option<&<B as hal::Backend>::Image> {
+        match self.active.get(frame as usize)? {
+            Some(AcquiredImage::Native(image)) => Some(image.borrow()),
+            _ => self.offscreen.get(frame as usize).map(|image| &image.raw),
+        }
+    }
+
+    /// Returns the view of a frame, which has to be acquired
+    /// if the swapchain is not headless.
+    fn image_view(
+        &self,
+        frame: hal::window::SwapImageIndex,
+    ) -> Option<&<B as hal::Backend>::ImageView> {
+        match self.active.get(frame as usize)? {
+            Some(AcquiredImage::Native(image)) => Some(image.borrow()),
+            _ => self.offscreen.get(frame as usize).map(|image| &image.view),
+        }
+    }
+}
+
+pub enum AcquiredImage<B: hal::Backend> {
+    Native(<B::Surface as hal::window::PresentationSurface<B>>::SwapchainImage),
+    /// The offscreen image of the same index.
+    Offscreen,
+}
+
+pub struct OffscreenImage<B: hal::Backend> {
+    raw: B::Image,
+    memory: B::Memory,
+    view: B::ImageView,
+}
+
+/* automatically generated by rust-bindgen */
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkSubmitInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub waitSemaphoreCount: u32,
+    pub pWaitSemaphores: *const VkSemaphore,
+    pub pWaitDstStageMask: *const VkPipelineStageFlags,
+    pub commandBufferCount: u32,
+    pub pCommandBuffers: *const VkCommandBuffer,
+    pub signalSemaphoreCount: u32,
+    pub pSignalSemaphores: *const VkSemaphore,
+}
+impl Clone for VkSubmitInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkMappedMemoryRange {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub memory: VkDeviceMemory,
+    pub offset: VkDeviceSize,
+    pub size: VkDeviceSize,
+}
+impl Clone for VkMappedMemoryRange {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkSparseMemoryBind {
+    pub resourceOffset: VkDeviceSize,
+    pub size: VkDeviceSize,
+    pub memory: VkDeviceMemory,
+    pub memoryOffset: VkDeviceSize,
+    pub flags: VkSparseMemoryBindFlags,
+}
+impl Clone for VkSparseMemoryBind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkSparseBufferMemoryBindInfo {
+    pub buffer: VkBuffer,
+    pub bindCount: u32,
+    pub pBinds: *const VkSparseMemoryBind,
+}
+impl Clone for VkSparseBufferMemoryBindInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkSparseImageOpaqueMemoryBindInfo {
+    pub image: VkImage,
+    pub bindCount: u32,
+    pub pBinds: *const VkSparseMemoryBind,
+}
+impl Clone for VkSparseImageOpaqueMemoryBindInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkSparseImageMemoryBind {
+    pub subresource: VkImageSubresource,
+    pub offset: VkOffset3D,
+    pub extent: VkExtent3D,
+    pub memory: VkDeviceMemory,
+    pub memoryOffset: VkDeviceSize,
+    pub flags: VkSparseMemoryBindFlags,
+}
+impl Clone for VkSparseImageMemoryBind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkSparseImageMemoryBindInfo {
+    pub image: VkImage,
+    pub bindCount: u32,
+    pub pBinds: *const VkSparseImageMemoryBind,
+}
+impl Clone for VkSparseImageMemoryBindInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkBindSparseInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub waitSemaphoreCount: u32,
+    pub pWaitSemaphores: *const VkSemaphore,
+    pub bufferBindCount: u32,
+    pub pBufferBinds: *const VkSparseBufferMemoryBindInfo,
+    pub imageOpaqueBindCount: u32,
+    pub pImageOpaqueBinds: *const VkSparseImageOpaqueMemoryBindInfo,
+    pub imageBindCount: u32,
+    pub pImageBinds: *const VkSparseImageMemoryBindInfo,
+    pub signalSemaphoreCount: u32,
+    pub pSignalSemaphores: *const VkSemaphore,
+}
+impl Clone for VkBindSparseInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkBufferViewCreateInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub flags: VkBufferViewCreateFlags,
+    pub buffer: VkBuffer,
+    pub format: VkFormat,
+    pub offset: VkDeviceSize,
+    pub range: VkDeviceSize,
+}
+impl Clone for VkBufferViewCreateInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkImageViewCreateInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub flags: VkImageViewCreateFlags,
+    pub image: VkImage,
+    pub viewType: VkImageViewType,
+    pub format: VkFormat,
+    pub components: VkComponentMapping,
+    pub subresourceRange: VkImageSubresourceRange,
+}
+impl Clone for VkImageViewCreateInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkPipelineShaderStageCreateInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub flags: VkPipelineShaderStageCreateFlags,
+    pub stage: VkShaderStageFlagBits,
+    pub module: VkShaderModule,
+    pub pName: *const raw::c_char,
+    pub pSpecializationInfo: *const VkSpecializationInfo,
+}
+impl Clone for VkPipelineShaderStageCreateInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkGraphicsPipelineCreateInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub flags: VkPipelineCreateFlags,
+    pub stageCount: u32,
+    pub pStages: *const VkPipelineShaderStageCreateInfo,
+    pub pVertexInputState: *const VkPipelineVertexInputStateCreateInfo,
+    pub pInputAssemblyState: *const VkPipelineInputAssemblyStateCreateInfo,
+    pub pTessellationState: *const VkPipelineTessellationStateCreateInfo,
+    pub pViewportState: *const VkPipelineViewportStateCreateInfo,
+    pub pRasterizationState: *const VkPipelineRasterizationStateCreateInfo,
+    pub pMultisampleState: *const VkPipelineMultisampleStateCreateInfo,
+    pub pDepthStencilState: *const VkPipelineDepthStencilStateCreateInfo,
+    pub pColorBlendState: *const VkPipelineColorBlendStateCreateInfo,
+    pub pDynamicState: *const VkPipelineDynamicStateCreateInfo,
+    pub layout: VkPipelineLayout,
+    pub renderPass: VkRenderPass,
+    pub subpass: u32,
+    pub basePipelineHandle: VkPipeline,
+    pub basePipelineIndex: i32,
+}
+impl Clone for VkGraphicsPipelineCreateInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkComputePipelineCreateInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub flags: VkPipelineCreateFlags,
+    pub stage: VkPipelineShaderStageCreateInfo,
+    pub layout: VkPipelineLayout,
+    pub basePipelineHandle: VkPipeline,
+    pub basePipelineIndex: i32,
+}
+impl Clone for VkComputePipelineCreateInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkPipelineLayoutCreateInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub flags: VkPipelineLayoutCreateFlags,
+    pub setLayoutCount: u32,
+    pub pSetLayouts: *const VkDescriptorSetLayout,
+    pub pushConstantRangeCount: u32,
+    pub pPushConstantRanges: *const VkPushConstantRange,
+}
+impl Clone for VkPipelineLayoutCreateInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkDescriptorSetLayoutBinding {
+    pub binding: u32,
+    pub descriptorType: VkDescriptorType,
+    pub descriptorCount: u32,
+    pub stageFlags: VkShaderStageFlags,
+    pub pImmutableSamplers: *const VkSampler,
+}
+impl Clone for VkDescriptorSetLayoutBinding {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkDescriptorSetLayoutCreateInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub flags: VkDescriptorSetLayoutCreateFlags,
+    pub bindingCount: u32,
+    pub pBindings: *const VkDescriptorSetLayoutBinding,
+}
+impl Clone for VkDescriptorSetLayoutCreateInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkDescriptorSetAllocateInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub descriptorPool: VkDescriptorPool,
+    pub descriptorSetCount: u32,
+    pub pSetLayouts: *const VkDescriptorSetLayout,
+}
+impl Clone for VkDescriptorSetAllocateInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkDescriptorImageInfo {
+    pub sampler: VkSampler,
+    pub imageView: VkImageView,
+    pub imageLayout: VkImageLayout,
+}
+impl Clone for VkDescriptorImageInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkDescriptorBufferInfo {
+    pub buffer: VkBuffer,
+    pub offset: VkDeviceSize,
+    pub range: VkDeviceSize,
+}
+impl Clone for VkDescriptorBufferInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkWriteDescriptorSet {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub dstSet: VkDescriptorSet,
+    pub dstBinding: u32,
+    pub dstArrayElement: u32,
+    pub descriptorCount: u32,
+    pub descriptorType: VkDescriptorType,
+    pub pImageInfo: *const VkDescriptorImageInfo,
+    pub pBufferInfo: *const VkDescriptorBufferInfo,
+    pub pTexelBufferView: *const VkBufferView,
+}
+impl Clone for VkWriteDescriptorSet {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkCopyDescriptorSet {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub srcSet: VkDescriptorSet,
+    pub srcBinding: u32,
+    pub srcArrayElement: u32,
+    pub dstSet: VkDescriptorSet,
+    pub dstBinding: u32,
+    pub dstArrayElement: u32,
+    pub descriptorCount: u32,
+}
+impl Clone for VkCopyDescriptorSet {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkFramebufferCreateInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub flags: VkFramebufferCreateFlags,
+    pub renderPass: VkRenderPass,
+    pub attachmentCount: u32,
+    pub pAttachments: *const VkImageView,
+    pub width: u32,
+    pub height: u32,
+    pub layers: u32,
+}
+impl Clone for VkFramebufferCreateInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkCommandBufferAllocateInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub commandPool: VkCommandPool,
+    pub level: VkCommandBufferLevel,
+    pub commandBufferCount: u32,
+}
+impl Clone for VkCommandBufferAllocateInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkCommandBufferInheritanceInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub renderPass: VkRenderPass,
+    pub subpass: u32,
+    pub framebuffer: VkFramebuffer,
+    pub occlusionQueryEnable: VkBool32,
+    pub queryFlags: VkQueryControlFlags,
+    pub pipelineStatistics: VkQueryPipelineStatisticFlags,
+}
+impl Clone for VkCommandBufferInheritanceInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkCommandBufferBeginInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub flags: VkCommandBufferUsageFlags,
+    pub pInheritanceInfo: *const VkCommandBufferInheritanceInfo,
+}
+impl Clone for VkCommandBufferBeginInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkBufferMemoryBarrier {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub srcAccessMask: VkAccessFlags,
+    pub dstAccessMask: VkAccessFlags,
+    pub srcQueueFamilyIndex: u32,
+    pub dstQueueFamilyIndex: u32,
+    pub buffer: VkBuffer,
+    pub offset: VkDeviceSize,
+    pub size: VkDeviceSize,
+}
+impl Clone for VkBufferMemoryBarrier {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkImageMemoryBarrier {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub srcAccessMask: VkAccessFlags,
+    pub dstAccessMask: VkAccessFlags,
+    pub oldLayout: VkImageLayout,
+    pub newLayout: VkImageLayout,
+    pub srcQueueFamilyIndex: u32,
+    pub dstQueueFamilyIndex: u32,
+    pub image: VkImage,
+    pub subresourceRange: VkImageSubresourceRange,
+}
+impl Clone for VkImageMemoryBarrier {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkRenderPassBeginInfo {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub renderPass: VkRenderPass,
+    pub framebuffer: VkFramebuffer,
+    pub renderArea: VkRect2D,
+    pub clearValueCount: u32,
+    pub pClearValues: *const VkClearValue,
+}
+impl Clone for VkRenderPassBeginInfo {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkGetPhysicalDeviceFeatures = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pFeatures: *mut VkPhysicalDeviceFeatures,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceFormatProperties = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        format: VkFormat,
+        pFormatProperties: *mut VkFormatProperties,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceImageFormatProperties = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        format: VkFormat,
+        type_: VkImageType,
+        tiling: VkImageTiling,
+        usage: VkImageUsageFlags,
+        flags: VkImageCreateFlags,
+        pImageFormatProperties: *mut VkImageFormatProperties,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceProperties = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pProperties: *mut VkPhysicalDeviceProperties,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceQueueFamilyProperties = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pQueueFamilyPropertyCount: *mut u32,
+        pQueueFamilyProperties: *mut VkQueueFamilyProperties,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceMemoryProperties = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties,
+    ),
+>;
+pub type PFN_vkGetInstanceProcAddr = Option<
+    unsafe extern "C" fn(instance: VkInstance, pName: *const raw::c_char) -> PFN_vkVoidFunction,
+>;
+pub type PFN_vkGetDeviceProcAddr =
+    Option<unsafe extern "C" fn(device: VkDevice, pName: *const raw::c_char) -> PFN_vkVoidFunction>;
+pub type PFN_vkCreateDevice = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pCreateInfo: *const VkDeviceCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pDevice: *mut VkDevice,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyDevice =
+    Option<unsafe extern "C" fn(device: VkDevice, pAllocator: *const VkAllocationCallbacks)>;
+pub type PFN_vkEnumerateDeviceExtensionProperties = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pLayerName: *const raw::c_char,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkExtensionProperties,
+    ) -> VkResult,
+>;
+pub type PFN_vkEnumerateDeviceLayerProperties = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkLayerProperties,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetDeviceQueue = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        queueFamilyIndex: u32,
+        queueIndex: u32,
+        pQueue: *mut VkQueue,
+    ),
+>;
+pub type PFN_vkQueueSubmit = Option<
+    unsafe extern "C" fn(
+        queue: VkQueue,
+        submitCount: u32,
+        pSubmits: *const VkSubmitInfo,
+        fence: VkFence,
+    ) -> VkResult,
+>;
+pub type PFN_vkQueueWaitIdle = Option<unsafe extern "C" fn(queue: VkQueue) -> VkResult>;
+pub type PFN_vkDeviceWaitIdle = Option<unsafe extern "C" fn(device: VkDevice) -> VkResult>;
+pub type PFN_vkAllocateMemory = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pAllocateInfo: *const VkMemoryAllocateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pMemory: *mut VkDeviceMemory,
+    ) -> VkResult,
+>;
+pub type PFN_vkFreeMemory = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        memory: VkDeviceMemory,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkMapMemory = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        memory: VkDeviceMemory,
+        offset: VkDeviceSize,
+        size: VkDeviceSize,
+        flags: VkMemoryMapFlags,
+        ppData: *mut *mut raw::c_void,
+    ) -> VkResult,
+>;
+pub type PFN_vkUnmapMemory = Option<unsafe extern "C" fn(device: VkDevice, memory: VkDeviceMemory)>;
+pub type PFN_vkFlushMappedMemoryRanges = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        memoryRangeCount: u32,
+        pMemoryRanges: *const VkMappedMemoryRange,
+    ) -> VkResult,
+>;
+pub type PFN_vkInvalidateMappedMemoryRanges = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        memoryRangeCount: u32,
+        pMemoryRanges: *const VkMappedMemoryRange,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetDeviceMemoryCommitment = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        memory: VkDeviceMemory,
+        pCommittedMemoryInBytes: *mut VkDeviceSize,
+    ),
+>;
+pub type PFN_vkBindBufferMemory = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        buffer: VkBuffer,
+        memory: VkDeviceMemory,
+        memoryOffset: VkDeviceSize,
+    ) -> VkResult,
+>;
+pub type PFN_vkBindImageMemory = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        image: VkImage,
+        memory: VkDeviceMemory,
+        memoryOffset: VkDeviceSize,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetBufferMemoryRequirements = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        buffer: VkBuffer,
+        pMemoryRequirements: *mut VkMemoryRequirements,
+    ),
+>;
+pub type PFN_vkGetImageMemoryRequirements = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        image: VkImage,
+        pMemoryRequirements: *mut VkMemoryRequirements,
+    ),
+>;
+pub type PFN_vkGetImageSparseMemoryRequirements = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        image: VkImage,
+        pSparseMemoryRequirementCount: *mut u32,
+        pSparseMemoryRequirements: *mut VkSparseImageMemoryRequirements,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceSparseImageFormatProperties = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        format: VkFormat,
+        type_: VkImageType,
+        samples: VkSampleCountFlagBits,
+        usage: VkImageUsageFlags,
+        tiling: VkImageTiling,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkSparseImageFormatProperties,
+    ),
+>;
+pub type PFN_vkQueueBindSparse = Option<
+    unsafe extern "C" fn(
+        queue: VkQueue,
+        bindInfoCount: u32,
+        pBindInfo: *const VkBindSparseInfo,
+        fence: VkFence,
+    ) -> VkResult,
+>;
+pub type PFN_vkCreateFence = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkFenceCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pFence: *mut VkFence,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyFence = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        fence: VkFence,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkResetFences = Option<
+    unsafe extern "C" fn(device: VkDevice, fenceCount: u32, pFences: *const VkFence) -> VkResult,
+>;
+pub type PFN_vkGetFenceStatus =
+    Option<unsafe extern "C" fn(device: VkDevice, fence: VkFence) -> VkResult>;
+pub type PFN_vkWaitForFences = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        fenceCount: u32,
+        pFences: *const VkFence,
+        waitAll: VkBool32,
+        timeout: u64,
+    ) -> VkResult,
+>;
+pub type PFN_vkCreateSemaphore = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkSemaphoreCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pSemaphore: *mut VkSemaphore,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroySemaphore = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        semaphore: VkSemaphore,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreateEvent = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkEventCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pEvent: *mut VkEvent,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyEvent = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        event: VkEvent,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkGetEventStatus =
+    Option<unsafe extern "C" fn(device: VkDevice, event: VkEvent) -> VkResult>;
+pub type PFN_vkSetEvent =
+    Option<unsafe extern "C" fn(device: VkDevice, event: VkEvent) -> VkResult>;
+pub type PFN_vkResetEvent =
+    Option<unsafe extern "C" fn(device: VkDevice, event: VkEvent) -> VkResult>;
+pub type PFN_vkCreateQueryPool = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkQueryPoolCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pQueryPool: *mut VkQueryPool,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyQueryPool = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        queryPool: VkQueryPool,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkGetQueryPoolResults = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        queryPool: VkQueryPool,
+        firstQuery: u32,
+        queryCount: u32,
+        dataSize: usize,
+        pData: *mut raw::c_void,
+        stride: VkDeviceSize,
+        flags: VkQueryResultFlags,
+    ) -> VkResult,
+>;
+pub type PFN_vkCreateBuffer = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkBufferCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pBuffer: *mut VkBuffer,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyBuffer = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        buffer: VkBuffer,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreateBufferView = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkBufferViewCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pView: *mut VkBufferView,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyBufferView = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        bufferView: VkBufferView,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreateImage = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkImageCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pImage: *mut VkImage,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyImage = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        image: VkImage,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkGetImageSubresourceLayout = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        image: VkImage,
+        pSubresource: *const VkImageSubresource,
+        pLayout: *mut VkSubresourceLayout,
+    ),
+>;
+pub type PFN_vkCreateImageView = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkImageViewCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pView: *mut VkImageView,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyImageView = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        imageView: VkImageView,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreateShaderModule = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkShaderModuleCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pShaderModule: *mut VkShaderModule,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyShaderModule = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        shaderModule: VkShaderModule,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreatePipelineCache = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkPipelineCacheCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pPipelineCache: *mut VkPipelineCache,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyPipelineCache = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pipelineCache: VkPipelineCache,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkGetPipelineCacheData = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pipelineCache: VkPipelineCache,
+        pDataSize: *mut usize,
+        pData: *mut raw::c_void,
+    ) -> VkResult,
+>;
+pub type PFN_vkMergePipelineCaches = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        dstCache: VkPipelineCache,
+        srcCacheCount: u32,
+        pSrcCaches: *const VkPipelineCache,
+    ) -> VkResult,
+>;
+pub type PFN_vkCreateGraphicsPipelines = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pipelineCache: VkPipelineCache,
+        createInfoCount: u32,
+        pCreateInfos: *const VkGraphicsPipelineCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pPipelines: *mut VkPipeline,
+    ) -> VkResult,
+>;
+pub type PFN_vkCreateComputePipelines = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pipelineCache: VkPipelineCache,
+        createInfoCount: u32,
+        pCreateInfos: *const VkComputePipelineCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pPipelines: *mut VkPipeline,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyPipeline = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pipeline: VkPipeline,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreatePipelineLayout = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkPipelineLayoutCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pPipelineLayout: *mut VkPipelineLayout,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyPipelineLayout = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pipelineLayout: VkPipelineLayout,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreateSampler = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkSamplerCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pSampler: *mut VkSampler,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroySampler = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        sampler: VkSampler,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreateDescriptorSetLayout = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkDescriptorSetLayoutCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pSetLayout: *mut VkDescriptorSetLayout,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyDescriptorSetLayout = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        descriptorSetLayout: VkDescriptorSetLayout,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreateDescriptorPool = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkDescriptorPoolCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pDescriptorPool: *mut VkDescriptorPool,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyDescriptorPool = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        descriptorPool: VkDescriptorPool,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkResetDescriptorPool = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        descriptorPool: VkDescriptorPool,
+        flags: VkDescriptorPoolResetFlags,
+    ) -> VkResult,
+>;
+pub type PFN_vkAllocateDescriptorSets = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pAllocateInfo: *const VkDescriptorSetAllocateInfo,
+        pDescriptorSets: *mut VkDescriptorSet,
+    ) -> VkResult,
+>;
+pub type PFN_vkFreeDescriptorSets = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        descriptorPool: VkDescriptorPool,
+        descriptorSetCount: u32,
+        pDescriptorSets: *const VkDescriptorSet,
+    ) -> VkResult,
+>;
+pub type PFN_vkUpdateDescriptorSets = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        descriptorWriteCount: u32,
+        pDescriptorWrites: *const VkWriteDescriptorSet,
+        descriptorCopyCount: u32,
+        pDescriptorCopies: *const VkCopyDescriptorSet,
+    ),
+>;
+pub type PFN_vkCreateFramebuffer = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkFramebufferCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pFramebuffer: *mut VkFramebuffer,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyFramebuffer = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        framebuffer: VkFramebuffer,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreateRenderPass = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkRenderPassCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pRenderPass: *mut VkRenderPass,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyRenderPass = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        renderPass: VkRenderPass,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkGetRenderAreaGranularity = Option<
+    unsafe extern "C" fn(device: VkDevice, renderPass: VkRenderPass, pGranularity: *mut VkExtent2D),
+>;
+pub type PFN_vkCreateCommandPool = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkCommandPoolCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pCommandPool: *mut VkCommandPool,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyCommandPool = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        commandPool: VkCommandPool,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkResetCommandPool = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        commandPool: VkCommandPool,
+        flags: VkCommandPoolResetFlags,
+    ) -> VkResult,
+>;
+pub type PFN_vkAllocateCommandBuffers = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pAllocateInfo: *const VkCommandBufferAllocateInfo,
+        pCommandBuffers: *mut VkCommandBuffer,
+    ) -> VkResult,
+>;
+pub type PFN_vkFreeCommandBuffers = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        commandPool: VkCommandPool,
+        commandBufferCount: u32,
+        pCommandBuffers: *const VkCommandBuffer,
+    ),
+>;
+pub type PFN_vkBeginCommandBuffer = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        pBeginInfo: *const VkCommandBufferBeginInfo,
+    ) -> VkResult,
+>;
+pub type PFN_vkEndCommandBuffer =
+    Option<unsafe extern "C" fn(commandBuffer: VkCommandBuffer) -> VkResult>;
+pub type PFN_vkResetCommandBuffer = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        flags: VkCommandBufferResetFlags,
+    ) -> VkResult,
+>;
+pub type PFN_vkCmdBindPipeline = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        pipelineBindPoint: VkPipelineBindPoint,
+        pipeline: VkPipeline,
+    ),
+>;
+pub type PFN_vkCmdSetViewport = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        firstViewport: u32,
+        viewportCount: u32,
+        pViewports: *const VkViewport,
+    ),
+>;
+pub type PFN_vkCmdSetScissor = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        firstScissor: u32,
+        scissorCount: u32,
+        pScissors: *const VkRect2D,
+    ),
+>;
+pub type PFN_vkCmdSetLineWidth =
+    Option<unsafe extern "C" fn(commandBuffer: VkCommandBuffer, lineWidth: f32)>;
+pub type PFN_vkCmdSetDepthBias = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        depthBiasConstantFactor: f32,
+        depthBiasClamp: f32,
+        depthBiasSlopeFactor: f32,
+    ),
+>;
+pub type PFN_vkCmdSetBlendConstants =
+    Option<unsafe extern "C" fn(commandBuffer: VkCommandBuffer, blendConstants: *const f32)>;
+pub type PFN_vkCmdSetDepthBounds = Option<
+    unsafe extern "C" fn(commandBuffer: VkCommandBuffer, minDepthBounds: f32, maxDepthBounds: f32),
+>;
+pub type PFN_vkCmdSetStencilCompareMask = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        faceMask: VkStencilFaceFlags,
+        compareMask: u32,
+    ),
+>;
+pub type PFN_vkCmdSetStencilWriteMask = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        faceMask: VkStencilFaceFlags,
+        writeMask: u32,
+    ),
+>;
+pub type PFN_vkCmdSetStencilReference = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        faceMask: VkStencilFaceFlags,
+        reference: u32,
+    ),
+>;
+pub type PFN_vkCmdBindDescriptorSets = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        pipelineBindPoint: VkPipelineBindPoint,
+        layout: VkPipelineLayout,
+        firstSet: u32,
+        descriptorSetCount: u32,
+        pDescriptorSets: *const VkDescriptorSet,
+        dynamicOffsetCount: u32,
+        pDynamicOffsets: *const u32,
+    ),
+>;
+pub type PFN_vkCmdBindIndexBuffer = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        buffer: VkBuffer,
+        offset: VkDeviceSize,
+        indexType: VkIndexType,
+    ),
+>;
+pub type PFN_vkCmdBindVertexBuffers = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        firstBinding: u32,
+        bindingCount: u32,
+        pBuffers: *const VkBuffer,
+        pOffsets: *const VkDeviceSize,
+    ),
+>;
+pub type PFN_vkCmdDraw = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        vertexCount: u32,
+        instanceCount: u32,
+        firstVertex: u32,
+        firstInstance: u32,
+    ),
+>;
+pub type PFN_vkCmdDrawIndexed = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        indexCount: u32,
+        instanceCount: u32,
+        firstIndex: u32,
+        vertexOffset: i32,
+        firstInstance: u32,
+    ),
+>;
+pub type PFN_vkCmdDrawIndirect = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        buffer: VkBuffer,
+        offset: VkDeviceSize,
+        drawCount: u32,
+        stride: u32,
+    ),
+>;
+pub type PFN_vkCmdDrawIndexedIndirect = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        buffer: VkBuffer,
+        offset: VkDeviceSize,
+        drawCount: u32,
+        stride: u32,
+    ),
+>;
+pub type PFN_vkCmdDispatch = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        groupCountX: u32,
+        groupCountY: u32,
+        groupCountZ: u32,
+    ),
+>;
+pub type PFN_vkCmdDispatchIndirect = Option<
+    unsafe extern "C" fn(commandBuffer: VkCommandBuffer, buffer: VkBuffer, offset: VkDeviceSize),
+>;
+pub type PFN_vkCmdCopyBuffer = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        srcBuffer: VkBuffer,
+        dstBuffer: VkBuffer,
+        regionCount: u32,
+        pRegions: *const VkBufferCopy,
+    ),
+>;
+pub type PFN_vkCmdCopyImage = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        srcImage: VkImage,
+        srcImageLayout: VkImageLayout,
+        dstImage: VkImage,
+        dstImageLayout: VkImageLayout,
+        regionCount: u32,
+        pRegions: *const VkImageCopy,
+    ),
+>;
+pub type PFN_vkCmdBlitImage = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        srcImage: VkImage,
+        srcImageLayout: VkImageLayout,
+        dstImage: VkImage,
+        dstImageLayout: VkImageLayout,
+        regionCount: u32,
+        pRegions: *const VkImageBlit,
+        filter: VkFilter,
+    ),
+>;
+pub type PFN_vkCmdCopyBufferToImage = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        srcBuffer: VkBuffer,
+        dstImage: VkImage,
+        dstImageLayout: VkImageLayout,
+        regionCount: u32,
+        pRegions: *const VkBufferImageCopy,
+    ),
+>;
+pub type PFN_vkCmdCopyImageToBuffer = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        srcImage: VkImage,
+        srcImageLayout: VkImageLayout,
+        dstBuffer: VkBuffer,
+        regionCount: u32,
+        pRegions: *const VkBufferImageCopy,
+    ),
+>;
+pub type PFN_vkCmdUpdateBuffer = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        dstBuffer: VkBuffer,
+        dstOffset: VkDeviceSize,
+        dataSize: VkDeviceSize,
+        pData: *const raw::c_void,
+    ),
+>;
+pub type PFN_vkCmdFillBuffer = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        dstBuffer: VkBuffer,
+        dstOffset: VkDeviceSize,
+        size: VkDeviceSize,
+        data: u32,
+    ),
+>;
+pub type PFN_vkCmdClearColorImage = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        image: VkImage,
+        imageLayout: VkImageLayout,
+        pColor: *const VkClearColorValue,
+        rangeCount: u32,
+        pRanges: *const VkImageSubresourceRange,
+    ),
+>;
+pub type PFN_vkCmdClearDepthStencilImage = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        image: VkImage,
+        imageLayout: VkImageLayout,
+        pDepthStencil: *const VkClearDepthStencilValue,
+        rangeCount: u32,
+        pRanges: *const VkImageSubresourceRange,
+    ),
+>;
+pub type PFN_vkCmdClearAttachments = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        attachmentCount: u32,
+        pAttachments: *const VkClearAttachment,
+        rectCount: u32,
+        pRects: *const VkClearRect,
+    ),
+>;
+pub type PFN_vkCmdResolveImage = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        srcImage: VkImage,
+        srcImageLayout: VkImageLayout,
+        dstImage: VkImage,
+        dstImageLayout: VkImageLayout,
+        regionCount: u32,
+        pRegions: *const VkImageResolve,
+    ),
+>;
+pub type PFN_vkCmdSetEvent = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        event: VkEvent,
+        stageMask: VkPipelineStageFlags,
+    ),
+>;
+pub type PFN_vkCmdResetEvent = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        event: VkEvent,
+        stageMask: VkPipelineStageFlags,
+    ),
+>;
+pub type PFN_vkCmdWaitEvents = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        eventCount: u32,
+        pEvents: *const VkEvent,
+        srcStageMask: VkPipelineStageFlags,
+        dstStageMask: VkPipelineStageFlags,
+        memoryBarrierCount: u32,
+        pMemoryBarriers: *const VkMemoryBarrier,
+        bufferMemoryBarrierCount: u32,
+        pBufferMemoryBarriers: *const VkBufferMemoryBarrier,
+        imageMemoryBarrierCount: u32,
+        pImageMemoryBarriers: *const VkImageMemoryBarrier,
+    ),
+>;
+pub type PFN_vkCmdPipelineBarrier = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        srcStageMask: VkPipelineStageFlags,
+        dstStageMask: VkPipelineStageFlags,
+        dependencyFlags: VkDependencyFlags,
+        memoryBarrierCount: u32,
+        pMemoryBarriers: *const VkMemoryBarrier,
+        bufferMemoryBarrierCount: u32,
+        pBufferMemoryBarriers: *const VkBufferMemoryBarrier,
+        imageMemoryBarrierCount: u32,
+        pImageMemoryBarriers: *const VkImageMemoryBarrier,
+    ),
+>;
+pub type PFN_vkCmdBeginQuery = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        queryPool: VkQueryPool,
+        query: u32,
+        flags: VkQueryControlFlags,
+    ),
+>;
+pub type PFN_vkCmdEndQuery = Option<
+    unsafe extern "C" fn(commandBuffer: VkCommandBuffer, queryPool: VkQueryPool, query: u32),
+>;
+pub type PFN_vkCmdResetQueryPool = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        queryPool: VkQueryPool,
+        firstQuery: u32,
+        queryCount: u32,
+    ),
+>;
+pub type PFN_vkCmdWriteTimestamp = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        pipelineStage: VkPipelineStageFlagBits,
+        queryPool: VkQueryPool,
+        query: u32,
+    ),
+>;
+pub type PFN_vkCmdCopyQueryPoolResults = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        queryPool: VkQueryPool,
+        firstQuery: u32,
+        queryCount: u32,
+        dstBuffer: VkBuffer,
+        dstOffset: VkDeviceSize,
+        stride: VkDeviceSize,
+        flags: VkQueryResultFlags,
+    ),
+>;
+pub type PFN_vkCmdPushConstants = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        layout: VkPipelineLayout,
+        stageFlags: VkShaderStageFlags,
+        offset: u32,
+        size: u32,
+        pValues: *const raw::c_void,
+    ),
+>;
+pub type PFN_vkCmdBeginRenderPass = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        pRenderPassBegin: *const VkRenderPassBeginInfo,
+        contents: VkSubpassContents,
+    ),
+>;
+pub type PFN_vkCmdNextSubpass =
+    Option<unsafe extern "C" fn(commandBuffer: VkCommandBuffer, contents: VkSubpassContents)>;
+pub type PFN_vkCmdEndRenderPass = Option<unsafe extern "C" fn(commandBuffer: VkCommandBuffer)>;
+pub type PFN_vkCmdExecuteCommands = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        commandBufferCount: u32,
+        pCommandBuffers: *const VkCommandBuffer,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkPhysicalDeviceSurfaceInfo2KHR {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub surface: VkSurfaceKHR,
+}
+impl Clone for VkPhysicalDeviceSurfaceInfo2KHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkDestroySurfaceKHR = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        surface: VkSurfaceKHR,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceSurfaceSupportKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        queueFamilyIndex: u32,
+        surface: VkSurfaceKHR,
+        pSupported: *mut VkBool32,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        surface: VkSurfaceKHR,
+        pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pSurfaceInfo: *const VkPhysicalDeviceSurfaceInfo2KHR,
+        pSurfaceCapabilities: *mut VkSurfaceCapabilities2KHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceSurfaceFormatsKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        surface: VkSurfaceKHR,
+        pSurfaceFormatCount: *mut u32,
+        pSurfaceFormats: *mut VkSurfaceFormatKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceSurfaceFormats2KHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pSurfaceInfo: *const VkPhysicalDeviceSurfaceInfo2KHR,
+        pSurfaceFormatCount: *mut u32,
+        pSurfaceFormats: *mut VkSurfaceFormat2KHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceSurfacePresentModesKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        surface: VkSurfaceKHR,
+        pPresentModeCount: *mut u32,
+        pPresentModes: *mut VkPresentModeKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceSurfaceCapabilities2EXT = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        surface: VkSurfaceKHR,
+        pSurfaceCapabilities: *mut VkSurfaceCapabilities2EXT,
+    ) -> VkResult,
+>;
+pub type PFN_vkDisplayPowerControlEXT = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        display: VkDisplayKHR,
+        pDisplayPowerInfo: *const VkDisplayPowerInfoEXT,
+    ) -> VkResult,
+>;
+pub type PFN_vkRegisterDeviceEventEXT = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pDeviceEventInfo: *const VkDeviceEventInfoEXT,
+        pAllocator: *const VkAllocationCallbacks,
+        pFence: *mut VkFence,
+    ) -> VkResult,
+>;
+pub type PFN_vkRegisterDisplayEventEXT = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        display: VkDisplayKHR,
+        pDisplayEventInfo: *const VkDisplayEventInfoEXT,
+        pAllocator: *const VkAllocationCallbacks,
+        pFence: *mut VkFence,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetSwapchainCounterEXT = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        swapchain: VkSwapchainKHR,
+        counter: VkSurfaceCounterFlagBitsEXT,
+        pCounterValue: *mut u64,
+    ) -> VkResult,
+>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkSwapchainCreateInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub flags: VkSwapchainCreateFlagsKHR,
+    pub surface: VkSurfaceKHR,
+    pub minImageCount: u32,
+    pub imageFormat: VkFormat,
+    pub imageColorSpace: VkColorSpaceKHR,
+    pub imageExtent: VkExtent2D,
+    pub imageArrayLayers: u32,
+    pub imageUsage: VkImageUsageFlags,
+    pub imageSharingMode: VkSharingMode,
+    pub queueFamilyIndexCount: u32,
+    pub pQueueFamilyIndices: *const u32,
+    pub preTransform: VkSurfaceTransformFlagBitsKHR,
+    pub compositeAlpha: VkCompositeAlphaFlagBitsKHR,
+    pub presentMode: VkPresentModeKHR,
+    pub clipped: VkBool32,
+    pub oldSwapchain: VkSwapchainKHR,
+}
+impl Clone for VkSwapchainCreateInfoKHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkPresentInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub waitSemaphoreCount: u32,
+    pub pWaitSemaphores: *const VkSemaphore,
+    pub swapchainCount: u32,
+    pub pSwapchains: *const VkSwapchainKHR,
+    pub pImageIndices: *const u32,
+    pub pResults: *mut VkResult,
+}
+impl Clone for VkPresentInfoKHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkCreateSwapchainKHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkSwapchainCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pSwapchain: *mut VkSwapchainKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroySwapchainKHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        swapchain: VkSwapchainKHR,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkGetSwapchainImagesKHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        swapchain: VkSwapchainKHR,
+        pSwapchainImageCount: *mut u32,
+        pSwapchainImages: *mut VkImage,
+    ) -> VkResult,
+>;
+pub type PFN_vkAcquireNextImageKHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        swapchain: VkSwapchainKHR,
+        timeout: u64,
+        semaphore: VkSemaphore,
+        fence: VkFence,
+        pImageIndex: *mut u32,
+    ) -> VkResult,
+>;
+pub type PFN_vkQueuePresentKHR =
+    Option<unsafe extern "C" fn(queue: VkQueue, pPresentInfo: *const VkPresentInfoKHR) -> VkResult>;
+pub type PFN_vkGetPhysicalDeviceDisplayPropertiesKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkDisplayPropertiesKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkDisplayPlanePropertiesKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetDisplayPlaneSupportedDisplaysKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        planeIndex: u32,
+        pDisplayCount: *mut u32,
+        pDisplays: *mut VkDisplayKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetDisplayModePropertiesKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        display: VkDisplayKHR,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkDisplayModePropertiesKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkCreateDisplayModeKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        display: VkDisplayKHR,
+        pCreateInfo: *const VkDisplayModeCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pMode: *mut VkDisplayModeKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetDisplayPlaneCapabilitiesKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        mode: VkDisplayModeKHR,
+        planeIndex: u32,
+        pCapabilities: *mut VkDisplayPlaneCapabilitiesKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkCreateDisplayPlaneSurfaceKHR = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkDisplaySurfaceCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pSurface: *mut VkSurfaceKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkCreateSharedSwapchainsKHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        swapchainCount: u32,
+        pCreateInfos: *const VkSwapchainCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pSwapchains: *mut VkSwapchainKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceFeatures2KHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pFeatures: *mut VkPhysicalDeviceFeatures2KHR,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceProperties2KHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pProperties: *mut VkPhysicalDeviceProperties2KHR,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceFormatProperties2KHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        format: VkFormat,
+        pFormatProperties: *mut VkFormatProperties2KHR,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceImageFormatProperties2KHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pImageFormatInfo: *const VkPhysicalDeviceImageFormatInfo2KHR,
+        pImageFormatProperties: *mut VkImageFormatProperties2KHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceQueueFamilyProperties2KHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pQueueFamilyPropertyCount: *mut u32,
+        pQueueFamilyProperties: *mut VkQueueFamilyProperties2KHR,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceMemoryProperties2KHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties2KHR,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceSparseImageFormatProperties2KHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pFormatInfo: *const VkPhysicalDeviceSparseImageFormatInfo2KHR,
+        pPropertyCount: *mut u32,
+        pProperties: *mut VkSparseImageFormatProperties2KHR,
+    ),
+>;
+pub type PFN_vkTrimCommandPoolKHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        commandPool: VkCommandPool,
+        flags: VkCommandPoolTrimFlagsKHR,
+    ),
+>;
+pub type PFN_vkCmdPushDescriptorSetKHR = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        pipelineBindPoint: VkPipelineBindPoint,
+        layout: VkPipelineLayout,
+        set: u32,
+        descriptorWriteCount: u32,
+        pDescriptorWrites: *const VkWriteDescriptorSet,
+    ),
+>;
+pub type VkDescriptorUpdateTemplateKHR = Handle<DescriptorUpdateTemplate>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkDescriptorUpdateTemplateCreateInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *mut raw::c_void,
+    pub flags: VkDescriptorUpdateTemplateCreateFlagsKHR,
+    pub descriptorUpdateEntryCount: u32,
+    pub pDescriptorUpdateEntries: *const VkDescriptorUpdateTemplateEntryKHR,
+    pub templateType: VkDescriptorUpdateTemplateTypeKHR,
+    pub descriptorSetLayout: VkDescriptorSetLayout,
+    pub pipelineBindPoint: VkPipelineBindPoint,
+    pub pipelineLayout: VkPipelineLayout,
+    pub set: u32,
+}
+impl Clone for VkDescriptorUpdateTemplateCreateInfoKHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkCreateDescriptorUpdateTemplateKHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkDescriptorUpdateTemplateCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pDescriptorUpdateTemplate: *mut VkDescriptorUpdateTemplateKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyDescriptorUpdateTemplateKHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        descriptorUpdateTemplate: VkDescriptorUpdateTemplateKHR,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkUpdateDescriptorSetWithTemplateKHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        descriptorSet: VkDescriptorSet,
+        descriptorUpdateTemplate: VkDescriptorUpdateTemplateKHR,
+        pData: *const raw::c_void,
+    ),
+>;
+pub type PFN_vkCmdPushDescriptorSetWithTemplateKHR = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        descriptorUpdateTemplate: VkDescriptorUpdateTemplateKHR,
+        layout: VkPipelineLayout,
+        set: u32,
+        pData: *const raw::c_void,
+    ),
+>;
+pub type PFN_vkCreateDebugReportCallbackEXT = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT,
+        pAllocator: *const VkAllocationCallbacks,
+        pCallback: *mut VkDebugReportCallbackEXT,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyDebugReportCallbackEXT = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        callback: VkDebugReportCallbackEXT,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkDebugReportMessageEXT = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        flags: VkDebugReportFlagsEXT,
+        objectType: VkDebugReportObjectTypeEXT,
+        object: u64,
+        location: usize,
+        messageCode: i32,
+        pLayerPrefix: *const raw::c_char,
+        pMessage: *const raw::c_char,
+    ),
+>;
+pub type PFN_vkDebugMarkerSetObjectTagEXT = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pTagInfo: *mut VkDebugMarkerObjectTagInfoEXT,
+    ) -> VkResult,
+>;
+pub type PFN_vkDebugMarkerSetObjectNameEXT = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pNameInfo: *mut VkDebugMarkerObjectNameInfoEXT,
+    ) -> VkResult,
+>;
+pub type PFN_vkCmdDebugMarkerBeginEXT = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        pMarkerInfo: *mut VkDebugMarkerMarkerInfoEXT,
+    ),
+>;
+pub type PFN_vkCmdDebugMarkerEndEXT = Option<unsafe extern "C" fn(commandBuffer: VkCommandBuffer)>;
+pub type PFN_vkCmdDebugMarkerInsertEXT = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        pMarkerInfo: *mut VkDebugMarkerMarkerInfoEXT,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkDedicatedAllocationMemoryAllocateInfoNV {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub image: VkImage,
+    pub buffer: VkBuffer,
+}
+impl Clone for VkDedicatedAllocationMemoryAllocateInfoNV {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkCmdDrawIndirectCountAMD = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        buffer: VkBuffer,
+        offset: VkDeviceSize,
+        countBuffer: VkBuffer,
+        countBufferOffset: VkDeviceSize,
+        maxDrawCount: u32,
+        stride: u32,
+    ),
+>;
+pub type PFN_vkCmdDrawIndexedIndirectCountAMD = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        buffer: VkBuffer,
+        offset: VkDeviceSize,
+        countBuffer: VkBuffer,
+        countBufferOffset: VkDeviceSize,
+        maxDrawCount: u32,
+        stride: u32,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceExternalImageFormatPropertiesNV = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        format: VkFormat,
+        type_: VkImageType,
+        tiling: VkImageTiling,
+        usage: VkImageUsageFlags,
+        flags: VkImageCreateFlags,
+        externalHandleType: VkExternalMemoryHandleTypeFlagsNV,
+        pExternalImageFormatProperties: *mut VkExternalImageFormatPropertiesNV,
+    ) -> VkResult,
+>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkBindBufferMemoryInfoKHX {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub buffer: VkBuffer,
+    pub memory: VkDeviceMemory,
+    pub memoryOffset: VkDeviceSize,
+    pub deviceIndexCount: u32,
+    pub pDeviceIndices: *const u32,
+}
+impl Clone for VkBindBufferMemoryInfoKHX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkBindImageMemoryInfoKHX {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub image: VkImage,
+    pub memory: VkDeviceMemory,
+    pub memoryOffset: VkDeviceSize,
+    pub deviceIndexCount: u32,
+    pub pDeviceIndices: *const u32,
+    pub SFRRectCount: u32,
+    pub pSFRRects: *const VkRect2D,
+}
+impl Clone for VkBindImageMemoryInfoKHX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkImageSwapchainCreateInfoKHX {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub swapchain: VkSwapchainKHR,
+}
+impl Clone for VkImageSwapchainCreateInfoKHX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkBindImageMemorySwapchainInfoKHX {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub swapchain: VkSwapchainKHR,
+    pub imageIndex: u32,
+}
+impl Clone for VkBindImageMemorySwapchainInfoKHX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkAcquireNextImageInfoKHX {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub swapchain: VkSwapchainKHR,
+    pub timeout: u64,
+    pub semaphore: VkSemaphore,
+    pub fence: VkFence,
+    pub deviceMask: u32,
+}
+impl Clone for VkAcquireNextImageInfoKHX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkGetDeviceGroupPeerMemoryFeaturesKHX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        heapIndex: u32,
+        localDeviceIndex: u32,
+        remoteDeviceIndex: u32,
+        pPeerMemoryFeatures: *mut VkPeerMemoryFeatureFlagsKHX,
+    ),
+>;
+pub type PFN_vkBindBufferMemory2KHX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        bindInfoCount: u32,
+        pBindInfos: *const VkBindBufferMemoryInfoKHX,
+    ) -> VkResult,
+>;
+pub type PFN_vkBindImageMemory2KHX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        bindInfoCount: u32,
+        pBindInfos: *const VkBindImageMemoryInfoKHX,
+    ) -> VkResult,
+>;
+pub type PFN_vkCmdSetDeviceMaskKHX =
+    Option<unsafe extern "C" fn(commandBuffer: VkCommandBuffer, deviceMask: u32)>;
+pub type PFN_vkGetDeviceGroupPresentCapabilitiesKHX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pDeviceGroupPresentCapabilities: *mut VkDeviceGroupPresentCapabilitiesKHX,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetDeviceGroupSurfacePresentModesKHX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        surface: VkSurfaceKHR,
+        pModes: *mut VkDeviceGroupPresentModeFlagsKHX,
+    ) -> VkResult,
+>;
+pub type PFN_vkAcquireNextImage2KHX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pAcquireInfo: *const VkAcquireNextImageInfoKHX,
+        pImageIndex: *mut u32,
+    ) -> VkResult,
+>;
+pub type PFN_vkCmdDispatchBaseKHX = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        baseGroupX: u32,
+        baseGroupY: u32,
+        baseGroupZ: u32,
+        groupCountX: u32,
+        groupCountY: u32,
+        groupCountZ: u32,
+    ),
+>;
+pub type PFN_vkGetPhysicalDevicePresentRectanglesKHX = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        surface: VkSurfaceKHR,
+        pRectCount: *mut u32,
+        pRects: *mut VkRect2D,
+    ) -> VkResult,
+>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkPhysicalDeviceGroupPropertiesKHX {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub physicalDeviceCount: u32,
+    pub physicalDevices: [VkPhysicalDevice; 32usize],
+    pub subsetAllocation: VkBool32,
+}
+impl Clone for VkPhysicalDeviceGroupPropertiesKHX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkDeviceGroupDeviceCreateInfoKHX {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub physicalDeviceCount: u32,
+    pub pPhysicalDevices: *const VkPhysicalDevice,
+}
+impl Clone for VkDeviceGroupDeviceCreateInfoKHX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkEnumeratePhysicalDeviceGroupsKHX = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pPhysicalDeviceGroupCount: *mut u32,
+        pPhysicalDeviceGroupProperties: *mut VkPhysicalDeviceGroupPropertiesKHX,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceExternalBufferPropertiesKHX = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pExternalBufferInfo: *const VkPhysicalDeviceExternalBufferInfoKHX,
+        pExternalBufferProperties: *mut VkExternalBufferPropertiesKHX,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceProperties2KHX = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pProperties: *mut VkPhysicalDeviceProperties2KHX,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceImageFormatProperties2KHX = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pImageFormatInfo: *const VkPhysicalDeviceImageFormatInfo2KHX,
+        pImageFormatProperties: *mut VkImageFormatProperties2KHX,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetMemoryFdKHX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        memory: VkDeviceMemory,
+        handleType: VkExternalMemoryHandleTypeFlagBitsKHX,
+        pFd: *mut raw::c_int,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetMemoryFdPropertiesKHX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        handleType: VkExternalMemoryHandleTypeFlagBitsKHX,
+        fd: raw::c_int,
+        pMemoryFdProperties: *mut VkMemoryFdPropertiesKHX,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceExternalSemaphorePropertiesKHX = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pExternalSemaphoreInfo: *const VkPhysicalDeviceExternalSemaphoreInfoKHX,
+        pExternalSemaphoreProperties: *mut VkExternalSemaphorePropertiesKHX,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkImportSemaphoreFdInfoKHX {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub semaphore: VkSemaphore,
+    pub handleType: VkExternalSemaphoreHandleTypeFlagBitsKHX,
+    pub fd: raw::c_int,
+}
+impl Clone for VkImportSemaphoreFdInfoKHX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkImportSemaphoreFdKHX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pImportSemaphoreFdInfo: *const VkImportSemaphoreFdInfoKHX,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetSemaphoreFdKHX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        semaphore: VkSemaphore,
+        handleType: VkExternalSemaphoreHandleTypeFlagBitsKHX,
+        pFd: *mut raw::c_int,
+    ) -> VkResult,
+>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkIndirectCommandsTokenNVX {
+    pub tokenType: VkIndirectCommandsTokenTypeNVX,
+    pub buffer: VkBuffer,
+    pub offset: VkDeviceSize,
+}
+impl Clone for VkIndirectCommandsTokenNVX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkCmdProcessCommandsInfoNVX {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub objectTable: VkObjectTableNVX,
+    pub indirectCommandsLayout: VkIndirectCommandsLayoutNVX,
+    pub indirectCommandsTokenCount: u32,
+    pub pIndirectCommandsTokens: *const VkIndirectCommandsTokenNVX,
+    pub maxSequencesCount: u32,
+    pub targetCommandBuffer: VkCommandBuffer,
+    pub sequencesCountBuffer: VkBuffer,
+    pub sequencesCountOffset: VkDeviceSize,
+    pub sequencesIndexBuffer: VkBuffer,
+    pub sequencesIndexOffset: VkDeviceSize,
+}
+impl Clone for VkCmdProcessCommandsInfoNVX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkObjectTablePipelineEntryNVX {
+    pub type_: VkObjectEntryTypeNVX,
+    pub flags: VkObjectEntryUsageFlagsNVX,
+    pub pipeline: VkPipeline,
+}
+impl Clone for VkObjectTablePipelineEntryNVX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkObjectTableDescriptorSetEntryNVX {
+    pub type_: VkObjectEntryTypeNVX,
+    pub flags: VkObjectEntryUsageFlagsNVX,
+    pub pipelineLayout: VkPipelineLayout,
+    pub descriptorSet: VkDescriptorSet,
+}
+impl Clone for VkObjectTableDescriptorSetEntryNVX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkObjectTableVertexBufferEntryNVX {
+    pub type_: VkObjectEntryTypeNVX,
+    pub flags: VkObjectEntryUsageFlagsNVX,
+    pub buffer: VkBuffer,
+}
+impl Clone for VkObjectTableVertexBufferEntryNVX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkObjectTableIndexBufferEntryNVX {
+    pub type_: VkObjectEntryTypeNVX,
+    pub flags: VkObjectEntryUsageFlagsNVX,
+    pub buffer: VkBuffer,
+    pub indexType: VkIndexType,
+}
+impl Clone for VkObjectTableIndexBufferEntryNVX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkObjectTablePushConstantEntryNVX {
+    pub type_: VkObjectEntryTypeNVX,
+    pub flags: VkObjectEntryUsageFlagsNVX,
+    pub pipelineLayout: VkPipelineLayout,
+    pub stageFlags: VkShaderStageFlags,
+}
+impl Clone for VkObjectTablePushConstantEntryNVX {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkCmdProcessCommandsNVX = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        pProcessCommandsInfo: *const VkCmdProcessCommandsInfoNVX,
+    ),
+>;
+pub type PFN_vkCmdReserveSpaceForCommandsNVX = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        pReserveSpaceInfo: *const VkCmdReserveSpaceForCommandsInfoNVX,
+    ),
+>;
+pub type PFN_vkCreateIndirectCommandsLayoutNVX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkIndirectCommandsLayoutCreateInfoNVX,
+        pAllocator: *const VkAllocationCallbacks,
+        pIndirectCommandsLayout: *mut VkIndirectCommandsLayoutNVX,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyIndirectCommandsLayoutNVX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        indirectCommandsLayout: VkIndirectCommandsLayoutNVX,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkCreateObjectTableNVX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkObjectTableCreateInfoNVX,
+        pAllocator: *const VkAllocationCallbacks,
+        pObjectTable: *mut VkObjectTableNVX,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyObjectTableNVX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        objectTable: VkObjectTableNVX,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkRegisterObjectsNVX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        objectTable: VkObjectTableNVX,
+        objectCount: u32,
+        ppObjectTableEntries: *const *const VkObjectTableEntryNVX,
+        pObjectIndices: *const u32,
+    ) -> VkResult,
+>;
+pub type PFN_vkUnregisterObjectsNVX = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        objectTable: VkObjectTableNVX,
+        objectCount: u32,
+        pObjectEntryTypes: *const VkObjectEntryTypeNVX,
+        pObjectIndices: *const u32,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetPhysicalDeviceGeneratedCommandsPropertiesNVX = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pFeatures: *mut VkDeviceGeneratedCommandsFeaturesNVX,
+        pLimits: *mut VkDeviceGeneratedCommandsLimitsNVX,
+    ),
+>;
+pub type PFN_vkCmdSetViewportWScalingNV = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        firstViewport: u32,
+        viewportCount: u32,
+        pViewportWScalings: *const VkViewportWScalingNV,
+    ),
+>;
+
+pub type PFN_vkReleaseDisplayEXT = Option<
+    unsafe extern "C" fn(physicalDevice: VkPhysicalDevice, display: VkDisplayKHR) -> VkResult,
+>;
+pub type PFN_vkCmdSetDiscardRectangleEXT = Option<
+    unsafe extern "C" fn(
+        commandBuffer: VkCommandBuffer,
+        firstDiscardRectangle: u32,
+        discardRectangleCount: u32,
+        pDiscardRectangles: *const VkRect2D,
+    ),
+>;
+
+pub type PFN_vkCreateInstance = Option<
+    unsafe extern "C" fn(
+        pCreateInfo: *const VkInstanceCreateInfo,
+        pAllocator: *const VkAllocationCallbacks,
+        pInstance: *mut VkInstance,
+    ) -> VkResult,
+>;
+
+pub type PFN_vkEnumeratePhysicalDevices = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pPhysicalDeviceCount: *mut u32,
+        pPhysicalDevices: *mut VkPhysicalDevice,
+    ) -> VkResult,
+>;
+
+pub type PFN_vkDestroyInstance =
+    Option<unsafe extern "C" fn(instance: VkInstance, pAllocator: *const VkAllocationCallbacks)>;
+
+pub type PFN_vkCreateXlibSurfaceKHR = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkXlibSurfaceCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pSurface: *mut VkSurfaceKHR,
+    ) -> VkResult,
+>;
+
+pub type PFN_vkCreateXcbSurfaceKHR = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkXcbSurfaceCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pSurface: *mut VkSurfaceKHR,
+    ) -> VkResult,
+>;
+
+pub type PFN_vkCreateWaylandSurfaceKHR = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkWaylandSurfaceCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pSurface: *mut VkSurfaceKHR,
+    ) -> VkResult,
+>;
+
+pub type PFN_vkCreateWin32SurfaceKHR = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkWin32SurfaceCreateInfoKHR,
+        pAllocator: *const VkAllocationCallbacks,
+        pSurface: *mut VkSurfaceKHR,
+    ) -> VkResult,
+>;
+
+pub type PFN_vkCreateMetalSurfaceEXT = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkMetalSurfaceCreateInfoEXT,
+        pAllocator: *const VkAllocationCallbacks,
+        pSurface: *mut VkSurfaceKHR,
+    ) -> VkResult,
+>;
+
+pub type PFN_vkCreateHeadlessSurfaceEXT = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkHeadlessSurfaceCreateInfoEXT,
+        pAllocator: *const VkAllocationCallbacks,
+        pSurface: *mut VkSurfaceKHR,
+    ) -> VkResult,
+>;
+
+pub type PFN_vkCreateMacOSSurfaceMVK = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkMacOSSurfaceCreateInfoMVK,
+        pAllocator: *const VkAllocationCallbacks,
+        pSurface: *mut VkSurfaceKHR,
+    ) -> VkResult,
+>;
+
+pub type PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR = Option<
+    unsafe extern "C" fn(physicalDevice: VkPhysicalDevice, queueFamilyIndex: u32) -> VkBool32,
+>;
+
+pub type PFN_vkGetPhysicalDeviceWaylandPresentationSupportKHR = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        queueFamilyIndex: u32,
+        display: *mut raw::c_void,
+    ) -> VkBool32,
+>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkRenderPassAttachmentBeginInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub attachmentCount: u32,
+    pub pAttachments: *const VkImageView,
+}
+impl Clone for VkRenderPassAttachmentBeginInfoKHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkSetDebugUtilsObjectNameEXT = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pNameInfo: *const VkDebugUtilsObjectNameInfoEXT,
+    ) -> VkResult,
+>;
+pub type PFN_vkSetDebugUtilsObjectTagEXT = Option<
+    unsafe extern "C" fn(device: VkDevice, pTagInfo: *const VkDebugUtilsObjectTagInfoEXT) -> VkResult,
+>;
+pub type PFN_vkQueueBeginDebugUtilsLabelEXT =
+    Option<unsafe extern "C" fn(queue: VkQueue, pLabelInfo: *const VkDebugUtilsLabelEXT)>;
+pub type PFN_vkQueueEndDebugUtilsLabelEXT = Option<unsafe extern "C" fn(queue: VkQueue)>;
+pub type PFN_vkQueueInsertDebugUtilsLabelEXT =
+    Option<unsafe extern "C" fn(queue: VkQueue, pLabelInfo: *const VkDebugUtilsLabelEXT)>;
+pub type PFN_vkCmdBeginDebugUtilsLabelEXT = Option<
+    unsafe extern "C" fn(commandBuffer: VkCommandBuffer, pLabelInfo: *const VkDebugUtilsLabelEXT),
+>;
+pub type PFN_vkCmdEndDebugUtilsLabelEXT =
+    Option<unsafe extern "C" fn(commandBuffer: VkCommandBuffer)>;
+pub type PFN_vkCmdInsertDebugUtilsLabelEXT = Option<
+    unsafe extern "C" fn(commandBuffer: VkCommandBuffer, pLabelInfo: *const VkDebugUtilsLabelEXT),
+>;
+pub type PFN_vkCreateDebugUtilsMessengerEXT = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pCreateInfo: *const VkDebugUtilsMessengerCreateInfoEXT,
+        pAllocator: *const VkAllocationCallbacks,
+        pMessenger: *mut VkDebugUtilsMessengerEXT,
+    ) -> VkResult,
+>;
+pub type PFN_vkDestroyDebugUtilsMessengerEXT = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        messenger: VkDebugUtilsMessengerEXT,
+        pAllocator: *const VkAllocationCallbacks,
+    ),
+>;
+pub type PFN_vkSubmitDebugUtilsMessageEXT = Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        messageSeverity: VkDebugUtilsMessageSeverityFlagBitsEXT,
+        messageTypes: VkDebugUtilsMessageTypeFlagsEXT,
+        pCallbackData: *const VkDebugUtilsMessengerCallbackDataEXT,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkBufferMemoryRequirementsInfo2KHR {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub buffer: VkBuffer,
+}
+impl Clone for VkBufferMemoryRequirementsInfo2KHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkImageMemoryRequirementsInfo2KHR {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub image: VkImage,
+}
+impl Clone for VkImageMemoryRequirementsInfo2KHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkImageSparseMemoryRequirementsInfo2KHR {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub image: VkImage,
+}
+impl Clone for VkImageSparseMemoryRequirementsInfo2KHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkGetImageMemoryRequirements2KHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pInfo: *const VkImageMemoryRequirementsInfo2KHR,
+        pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+    ),
+>;
+pub type PFN_vkGetBufferMemoryRequirements2KHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pInfo: *const VkBufferMemoryRequirementsInfo2KHR,
+        pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+    ),
+>;
+pub type PFN_vkGetImageSparseMemoryRequirements2KHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pInfo: *const VkImageSparseMemoryRequirementsInfo2KHR,
+        pSparseMemoryRequirementCount: *mut u32,
+        pSparseMemoryRequirements: *mut VkSparseImageMemoryRequirements2KHR,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkMemoryDedicatedAllocateInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub image: VkImage,
+    pub buffer: VkBuffer,
+}
+impl Clone for VkMemoryDedicatedAllocateInfoKHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkBindBufferMemoryInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub buffer: VkBuffer,
+    pub memory: VkDeviceMemory,
+    pub memoryOffset: VkDeviceSize,
+}
+impl Clone for VkBindBufferMemoryInfoKHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy)]
+pub struct VkBindImageMemoryInfoKHR {
+    pub sType: VkStructureType,
+    pub pNext: *const raw::c_void,
+    pub image: VkImage,
+    pub memory: VkDeviceMemory,
+    pub memoryOffset: VkDeviceSize,
+}
+impl Clone for VkBindImageMemoryInfoKHR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+pub type PFN_vkBindBufferMemory2KHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        bindInfoCount: u32,
+        pBindInfos: *const VkBindBufferMemoryInfoKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkBindImageMemory2KHR = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        bindInfoCount: u32,
+        pBindInfos: *const VkBindImageMemoryInfoKHR,
+    ) -> VkResult,
+>;
+pub type PFN_vkGetDeviceQueue2 = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pQueueInfo: *const VkDeviceQueueInfo2,
+        pQueue: *mut VkQueue,
+    ),
+>;
+pub type PFN_vkGetDescriptorSetLayoutSupport = Option<
+    unsafe extern "C" fn(
+        device: VkDevice,
+        pCreateInfo: *const VkDescriptorSetLayoutCreateInfo,
+        pSupport: *mut VkDescriptorSetLayoutSupport,
+    ),
+>;
+pub type PFN_vkGetPhysicalDeviceExternalFenceProperties = Option<
+    unsafe extern "C" fn(
+        physicalDevice: VkPhysicalDevice,
+        pExternalFenceInfo: *const VkPhysicalDeviceExternalFenceInfo,
+        pExternalFenceProperties: *mut VkExternalFenceProperties,
+    ),
+>;
@@ -0,0 +1,210 @@
+//! Reports of the handles that are still alive when the device
+//! or the instance owning them is destroyed.
+//!
+//! Tracking is enabled by the `leak-tracking` feature, or at run-time by
+//! setting `GFX_LEAK_REPORT` (or `leak_report` in the configuration) to the path
+//! of the report file. The file is written as JSON if the path has a `.json`
+//! extension, and as plain text otherwise. Setting `GFX_LEAK_BACKTRACE=1`
+//! (or `leak_backtrace`) also records the creation backtrace of every handle.
+//!
+//! The reports of all the backends go to the same file.
+
+use crate::{
+    debug::{DebugReportCallback, DebugUtilsMessenger},
+    handle,
+};
+
+use lazy_static::lazy_static;
+use log::{error, warn};
+
+use std::{any::type_name, collections::BTreeMap, fmt::Write as _, fs, path::PathBuf, sync::Mutex};
+
+lazy_static! {
+    static ref REPORT_PATH: Option<PathBuf> = crate::config::current().leak_report.clone();
+    static ref BACKTRACES: bool = crate::config::current().leak_backtrace;
+    /// Reports produced so far, the file is rewritten with all of them each time.
+    static ref REPORTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+}
+
+pub fn is_enabled() -> bool {
+    cfg!(feature = "leak-tracking") || REPORT_PATH.is_some()
+}
+
+pub fn capture_backtraces() -> bool {
+    is_enabled() && *BACKTRACES
+}
+
+/// Strips the module paths from a type name, so that the reports
+/// don't depend on the crate layout.
+fn short_type_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut path_start = 0;
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            short.truncate(path_start);
+        } else {
+            short.push(c);
+            if !c.is_alphanumeric() && c != '_' {
+                path_start = short.len();
+            }
+        }
+    }
+    short
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+struct Leak {
+    type_name: String,
+    name: Option<String>,
+    backtrace: Option<String>,
+}
+
+fn format_json(event: &str, counts: &BTreeMap<String, usize>, leaks: &[Leak]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "  {{");
+    let _ = writeln!(out, "    \"event\": \"{}\",", escape_json(event));
+    let _ = writeln!(out, "    \"total\": {},", leaks.len());
+    let _ = write!(out, "    \"types\": {{");
+    for (i, (type_name, count)) in counts.iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        let _ = write!(
+            out,
+            "{}\n      \"{}\": {}",
+            separator,
+            escape_json(type_name),
+            count
+        );
+    }
+    let _ = writeln!(out, "{}}},", if counts.is_empty() { "" } else { "\n    " });
+    let _ = write!(out, "    \"handles\": [");
+    for (i, leak) in leaks.iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        let _ = write!(
+            out,
+            "{}\n      {{ \"type\": \"{}\"",
+            separator,
+            escape_json(&leak.type_name)
+        );
+        if let Some(ref name) = leak.name {
+            let _ = write!(out, ", \"name\": \"{}\"", escape_json(name));
+        }
+        if let Some(ref backtrace) = leak.backtrace {
+            let _ = write!(out, ", \"backtrace\": \"{}\"", escape_json(backtrace));
+        }
+        let _ = write!(out, " }}");
+    }
+    let _ = write!(
+        out,
+        "{}]\n  }}",
+        if leaks.is_empty() { "" } else { "\n    " }
+    );
+    out
+}
+
+fn format_text(event: &str, counts: &BTreeMap<String, usize>, leaks: &[Leak]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{}: {} leaked handles", event, leaks.len());
+    for (type_name, count) in counts {
+        let _ = writeln!(out, "\t{}: {}", type_name, count);
+    }
+    for leak in leaks {
+        match leak.name {
+            Some(ref name) => {
+                let _ = writeln!(out, "\t{} \"{}\"", leak.type_name, name);
+            }
+            None if leak.backtrace.is_some() => {
+                let _ = writeln!(out, "\t{}", leak.type_name);
+            }
+            None => continue,
+        }
+        if let Some(ref backtrace) = leak.backtrace {
+            for line in backtrace.lines() {
+                let _ = writeln!(out, "\t\t{}", line);
+            }
+        }
+    }
+    out
+}
+
+/// Writes a report of the live handles, except for the ones of the `excluded` types.
+fn report(event: &str, excluded: &[&str]) {
+    if !is_enabled() {
+        return;
+    }
+    let mut leaks = handle::live_handles()
+        .into_iter()
+        .filter(|live| !excluded.contains(&live.type_name))
+        .map(|live| Leak {
+            type_name: short_type_name(live.type_name),
+            name: live.name,
+            backtrace: live.backtrace,
+        })
+        .collect::<Vec<_>>();
+    leaks.sort_by(|a, b| (&a.type_name, &a.name).cmp(&(&b.type_name, &b.name)));
+    let mut counts = BTreeMap::new();
+    for leak in &leaks {
+        *counts.entry(leak.type_name.clone()).or_insert(0) += 1;
+    }
+
+    let path = match *REPORT_PATH {
+        Some(ref path) => path,
+        None => {
+            if !leaks.is_empty() {
+                warn!("{}", format_text(event, &counts, &leaks));
+            }
+            return;
+        }
+    };
+    let is_json = path.extension().map_or(false, |ext| ext == "json");
+    let mut reports = REPORTS.lock().unwrap();
+    reports.push(if is_json {
+        format_json(event, &counts, &leaks)
+    } else {
+        format_text(event, &counts, &leaks)
+    });
+    let contents = if is_json {
+        format!("[\n{}\n]\n", reports.join(",\n"))
+    } else {
+        reports.join("\n")
+    };
+    if let Err(e) = fs::write(path, contents) {
+        error!("Unable to write the leak report to {:?}: {}", path, e);
+    }
+}
+
+/// Reports the objects left alive by the application at `vkDestroyDevice`,
+/// except for the ones of the `instance_types` given by the backend.
+///
+/// The handles aren't tracked per device, so the objects of the other
+/// devices that are still alive are reported as well.
+pub fn report_device(instance_types: &[&str]) {
+    let mut excluded = vec![
+        type_name::<DebugUtilsMessenger>(),
+        type_name::<DebugReportCallback>(),
+    ];
+    excluded.extend_from_slice(instance_types);
+    report("vkDestroyDevice", &excluded);
+}
+
+/// Reports the objects left alive by the application at `vkDestroyInstance`.
+pub fn report_instance() {
+    report("vkDestroyInstance", &[]);
+}
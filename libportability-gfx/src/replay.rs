@@ -0,0 +1,665 @@
+//! Replay of the traces written by the `capture` module.
+//!
+//! The commands are called again through the entry points, on the backend
+//! selected by the configuration, the handles they create being used
+//! in place of the captured ones. The surfaces don't exist here:
+//! their commands are skipped, and the swapchains are emulated with images
+//! that are never shown.
+
+use crate::{
+    capture,
+    empty::*,
+    entry,
+    trace::{self, Reader, Traced},
+};
+
+use fxhash::FxHashMap;
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt,
+    fs::File,
+    io::{self, BufReader, Read},
+    mem,
+    os::raw::c_void,
+    path::Path,
+    ptr, slice,
+};
+
+/// Commands that are not replayed, as the handles or the callbacks
+/// they use don't exist in the replay.
+const SKIPPED: &[&str] = &[
+    "gfxGetInstanceProcAddr",
+    "gfxGetDeviceProcAddr",
+    "gfxDestroySurfaceKHR",
+    "gfxGetPhysicalDeviceSurfaceSupportKHR",
+    "gfxGetPhysicalDeviceSurfaceCapabilitiesKHR",
+    "gfxGetPhysicalDeviceSurfaceCapabilities2KHR",
+    "gfxGetPhysicalDeviceSurfaceCapabilities2EXT",
+    "gfxGetPhysicalDeviceSurfaceFormatsKHR",
+    "gfxGetPhysicalDeviceSurfaceFormats2KHR",
+    "gfxGetPhysicalDeviceSurfacePresentModesKHR",
+    "gfxGetPhysicalDeviceWin32PresentationSupportKHR",
+    "gfxGetPhysicalDeviceWaylandPresentationSupportKHR",
+    "gfxGetSwapchainCounterEXT",
+    "gfxDisplayPowerControlEXT",
+    "gfxRegisterDisplayEventEXT",
+    "gfxReleaseDisplayEXT",
+    "gfxCreateDebugReportCallbackEXT",
+    "gfxDestroyDebugReportCallbackEXT",
+    "gfxDebugReportMessageEXT",
+    "gfxCreateDebugUtilsMessengerEXT",
+    "gfxDestroyDebugUtilsMessengerEXT",
+    "gfxSubmitDebugUtilsMessageEXT",
+    "gfxDebugMarkerSetObjectNameEXT",
+    "gfxDebugMarkerSetObjectTagEXT",
+    "gfxSetDebugUtilsObjectNameEXT",
+    "gfxSetDebugUtilsObjectTagEXT",
+];
+
+/// Commands creating surfaces, of which the captured handles are kept
+/// as placeholders.
+const SURFACE_CONSTRUCTORS: &[&str] = &[
+    "gfxCreateWin32SurfaceKHR",
+    "gfxCreateXlibSurfaceKHR",
+    "gfxCreateXcbSurfaceKHR",
+    "gfxCreateWaylandSurfaceKHR",
+    "gfxCreateMetalSurfaceEXT",
+    "gfxCreateMacOSSurfaceMVK",
+    "gfxCreateHeadlessSurfaceEXT",
+];
+
+/// Outcome of a replay.
+#[derive(Debug, Default)]
+pub struct Summary {
+    pub calls: usize,
+    /// Commands that were not replayed, with their number of calls.
+    pub skipped: BTreeMap<String, usize>,
+    /// Calls of which the result differs from the captured one.
+    pub mismatches: Vec<String>,
+    /// Number of captured handles that were not created by the replay.
+    pub unknown_handles: usize,
+}
+
+struct Mapping {
+    ptr: *mut u8,
+    offset: u64,
+}
+
+/// A swapchain emulated by the replay.
+struct Swapchain {
+    device: VkDevice,
+    info: VkSwapchainCreateInfoKHR,
+    images: Vec<(VkImage, VkDeviceMemory)>,
+    next: usize,
+}
+
+/// Player of a trace.
+pub struct Player {
+    input: BufReader<File>,
+    /// Records read while looking for the return of a call,
+    /// written by the other threads in the meantime.
+    pending: VecDeque<(u8, Vec<u8>)>,
+    reader: Reader,
+    /// Name and index of the call being replayed.
+    name: String,
+    index: usize,
+    /// Payload of the return record of the call being replayed.
+    ret: Option<Vec<u8>>,
+    summary: Summary,
+    /// Mapped memory, by the handles of the replay.
+    mappings: FxHashMap<u64, Mapping>,
+    templates: FxHashMap<u64, Vec<VkDescriptorUpdateTemplateEntryKHR>>,
+    /// Queues of the devices, used by the swapchains.
+    queues: FxHashMap<u64, VkQueue>,
+    swapchains: FxHashMap<u64, Swapchain>,
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl Player {
+    /// Opens a trace, disabling the capture in this process.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut input = BufReader::new(File::open(path)?);
+        let mut header = [0; 13];
+        input.read_exact(&mut header)?;
+        if &header[..8] != trace::MAGIC {
+            return Err(invalid("Not a trace"));
+        }
+        let mut version = [0; 4];
+        version.copy_from_slice(&header[8..12]);
+        if u32::from_le_bytes(version) != trace::VERSION {
+            return Err(invalid("Unsupported version of the trace"));
+        }
+        if header[12] as usize != mem::size_of::<usize>() {
+            return Err(invalid("Trace captured with another pointer size"));
+        }
+
+        capture::suppress();
+        Ok(Player {
+            input,
+            pending: VecDeque::new(),
+            reader: Reader::default(),
+            name: String::new(),
+            index: 0,
+            ret: None,
+            summary: Summary::default(),
+            mappings: FxHashMap::default(),
+            templates: FxHashMap::default(),
+            queues: FxHashMap::default(),
+            swapchains: FxHashMap::default(),
+        })
+    }
+
+    /// Replays the whole trace, calling the observer before every call.
+    pub fn run(mut self, mut observer: impl FnMut(usize, &str)) -> io::Result<Summary> {
+        while let Some((kind, payload)) = self.next_record()? {
+            match kind {
+                trace::RECORD_CALL => {
+                    self.reader.load(payload);
+                    let id = self.reader.u64();
+                    self.name = self.reader.string();
+                    let has_return = self.reader.u8() != 0;
+                    self.ret = if has_return {
+                        self.find_return(id)?
+                    } else {
+                        None
+                    };
+                    observer(self.index, &self.name);
+                    let name = self.name.clone();
+                    if !unsafe { self.replay(&name) } {
+                        *self.summary.skipped.entry(name).or_insert(0) += 1;
+                        self.reader.release();
+                    }
+                    if !self.is_complete() {
+                        return Err(invalid(&format!(
+                            "Truncated record of {} (call {})",
+                            self.name, self.index
+                        )));
+                    }
+                    self.index += 1;
+                }
+                trace::RECORD_MEMORY => {
+                    unsafe { self.write_memory(payload) };
+                    if !self.is_complete() {
+                        return Err(invalid("Truncated memory record"));
+                    }
+                }
+                _ => return Err(invalid("Unexpected record")),
+            }
+        }
+
+        self.summary.calls = self.index;
+        self.summary.unknown_handles = self.reader.unknown_handles;
+        Ok(self.summary)
+    }
+
+    fn next_record(&mut self) -> io::Result<Option<(u8, Vec<u8>)>> {
+        match self.pending.pop_front() {
+            Some(record) => Ok(Some(record)),
+            None => self.read_record(),
+        }
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<(u8, Vec<u8>)>> {
+        let mut kind = [0];
+        if self.input.read(&mut kind)? == 0 {
+            return Ok(None);
+        }
+        let mut len = [0; 4];
+        self.input.read_exact(&mut len)?;
+        let mut payload = vec![0; u32::from_le_bytes(len) as usize];
+        self.input.read_exact(&mut payload)?;
+        Ok(Some((kind[0], payload)))
+    }
+
+    /// Returns the payload of the return of a call, keeping the records
+    /// written before it by the other threads for later. Missing after
+    /// the call that crashed the application.
+    fn find_return(&mut self, id: u64) -> io::Result<Option<Vec<u8>>> {
+        let id = id.to_le_bytes();
+        let is_return = |&(kind, ref payload): &(u8, Vec<u8>)| {
+            kind == trace::RECORD_RETURN && payload.get(..id.len()) == Some(&id[..])
+        };
+        let mut record = match self.pending.iter().position(is_return) {
+            Some(index) => self.pending.remove(index),
+            None => loop {
+                match self.read_record()? {
+                    Some(record) if is_return(&record) => break Some(record),
+                    Some(record) => self.pending.push_back(record),
+                    None => break None,
+                }
+            },
+        };
+        Ok(record
+            .as_mut()
+            .map(|(_, payload)| payload.split_off(id.len())))
+    }
+
+    /// Returns false if the record being replayed is truncated, the call
+    /// being skipped when its parameters are.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.reader.missing == 0
+    }
+
+    pub(crate) fn reader(&mut self) -> &mut Reader {
+        &mut self.reader
+    }
+
+    fn read<T: Traced>(&mut self) -> T {
+        unsafe { T::read(&mut self.reader) }
+    }
+
+    /// Ends the call, binding the handles it created to the captured ones
+    /// if it succeeded, and comparing its result.
+    pub(crate) fn finish<T: Traced + fmt::Debug>(&mut self, result: T) {
+        if let Some(ret) = self.ret.take() {
+            self.reader.load(ret);
+            let captured = self.read::<T>();
+            if result.succeeded() {
+                self.reader.read_outputs();
+            }
+            if !result.matches(&captured) {
+                self.summary.mismatches.push(format!(
+                    "{} (call {}) returned {:?} instead of {:?}",
+                    self.name, self.index, result, captured
+                ));
+            }
+        }
+        self.reader.release();
+    }
+
+    /// Copies the data written to the mapped memory by the application.
+    unsafe fn write_memory(&mut self, payload: Vec<u8>) {
+        self.reader.load(payload);
+        let memory = self.reader.u64();
+        let memory = self.reader.lookup(memory);
+        let offset = self.reader.u64();
+        let len = self.reader.u32() as usize;
+        let data = match self.reader.try_bytes(len) {
+            Some(data) => data,
+            None => return,
+        };
+        if let Some(mapping) = self.mappings.get(&memory) {
+            let dst = mapping.ptr.add((offset - mapping.offset) as usize);
+            ptr::copy_nonoverlapping(data.as_ptr(), dst, len);
+        }
+    }
+
+    unsafe fn replay(&mut self, name: &str) -> bool {
+        match name {
+            "gfxMapMemory" => self.map_memory(),
+            "gfxUnmapMemory" => self.unmap_memory(),
+            "gfxFreeMemory" => self.free_memory(),
+            "gfxCreateDescriptorUpdateTemplate" => self.create_template(),
+            "gfxUpdateDescriptorSetWithTemplate" => self.update_with_template(),
+            "gfxGetDeviceQueue" => self.get_queue(),
+            "gfxGetDeviceQueue2" => self.get_queue2(),
+            "gfxCreateSwapchainKHR" => self.create_swapchain(),
+            "gfxDestroySwapchainKHR" => self.destroy_swapchain(),
+            "gfxGetSwapchainImagesKHR" => self.swapchain_images(),
+            "gfxAcquireNextImageKHR" => self.acquire_image(),
+            "gfxQueuePresentKHR" => self.present(),
+            _ if SURFACE_CONSTRUCTORS.contains(&name) => {
+                self.keep_placeholder();
+                return false;
+            }
+            _ if SKIPPED.contains(&name) => return false,
+            _ => return entry::replay_front(name, self) || entry::replay(name, self),
+        }
+        true
+    }
+
+    /// Binds the surface created by the call to itself, so that
+    /// the commands using it are not reported.
+    unsafe fn keep_placeholder(&mut self) {
+        if let Some(ret) = self.ret.take() {
+            self.reader.load(ret);
+            let _result = self.read::<VkResult>();
+            // The surface is the only parameter written by the call.
+            if self.reader.u32() == 1 {
+                let surface = self.reader.u64();
+                self.reader.bind(surface, surface);
+            }
+        }
+    }
+
+    unsafe fn map_memory(&mut self) {
+        let device = self.read::<VkDevice>();
+        let memory = self.read::<VkDeviceMemory>();
+        let offset = self.read::<VkDeviceSize>();
+        let size = self.read::<VkDeviceSize>();
+        let flags = self.read::<VkMemoryMapFlags>();
+        let ppData = self.read::<*mut *mut c_void>();
+        if !self.is_complete() {
+            return;
+        }
+        let result = entry::gfxMapMemory(device, memory, offset, size, flags, ppData);
+        if result == VkResult::VK_SUCCESS {
+            let ptr = *ppData as *mut u8;
+            self.mappings
+                .insert(memory.as_raw(), Mapping { ptr, offset });
+        }
+        self.finish(result);
+    }
+
+    unsafe fn unmap_memory(&mut self) {
+        let device = self.read::<VkDevice>();
+        let memory = self.read::<VkDeviceMemory>();
+        if !self.is_complete() {
+            return;
+        }
+        self.mappings.remove(&memory.as_raw());
+        entry::gfxUnmapMemory(device, memory);
+        self.finish(());
+    }
+
+    unsafe fn free_memory(&mut self) {
+        let device = self.read::<VkDevice>();
+        let memory = self.read::<VkDeviceMemory>();
+        let pAllocator = self.read::<*const VkAllocationCallbacks>();
+        if !self.is_complete() {
+            return;
+        }
+        self.mappings.remove(&memory.as_raw());
+        entry::gfxFreeMemory(device, memory, pAllocator);
+        self.finish(());
+    }
+
+    unsafe fn create_template(&mut self) {
+        let device = self.read::<VkDevice>();
+        let pCreateInfo = self.read::<*const VkDescriptorUpdateTemplateCreateInfoKHR>();
+        let pAllocator = self.read::<*const VkAllocationCallbacks>();
+        let pTemplate = self.read::<*mut VkDescriptorUpdateTemplateKHR>();
+        if !self.is_complete() {
+            return;
+        }
+        let result =
+            entry::gfxCreateDescriptorUpdateTemplate(device, pCreateInfo, pAllocator, pTemplate);
+        if result == VkResult::VK_SUCCESS {
+            let info = &*pCreateInfo;
+            let entries = slice::from_raw_parts(
+                info.pDescriptorUpdateEntries,
+                info.descriptorUpdateEntryCount as usize,
+            );
+            self.templates
+                .insert((*pTemplate).as_raw(), entries.to_vec());
+        }
+        self.finish(result);
+    }
+
+    /// Replaces the captured handles in the data of the update.
+    unsafe fn update_with_template(&mut self) {
+        let device = self.read::<VkDevice>();
+        let set = self.read::<VkDescriptorSet>();
+        let template = self.read::<VkDescriptorUpdateTemplateKHR>();
+        let pData = self.read::<*const c_void>();
+        if !self.is_complete() {
+            return;
+        }
+        if let (Some(entries), false) = (self.templates.get(&template.as_raw()), pData.is_null()) {
+            for entry in entries {
+                for i in 0..entry.descriptorCount as usize {
+                    let ptr = (pData as *mut u8).add(entry.offset + i * entry.stride);
+                    if trace::has_image_info(entry.descriptorType) {
+                        let info = &mut *(ptr as *mut VkDescriptorImageInfo);
+                        info.sampler =
+                            VkSampler::from_raw(self.reader.lookup(info.sampler.as_raw()));
+                        info.imageView =
+                            VkImageView::from_raw(self.reader.lookup(info.imageView.as_raw()));
+                    } else if trace::has_buffer_info(entry.descriptorType) {
+                        let info = &mut *(ptr as *mut VkDescriptorBufferInfo);
+                        info.buffer = VkBuffer::from_raw(self.reader.lookup(info.buffer.as_raw()));
+                    } else {
+                        let view = &mut *(ptr as *mut VkBufferView);
+                        *view = VkBufferView::from_raw(self.reader.lookup(view.as_raw()));
+                    }
+                }
+            }
+        }
+        entry::gfxUpdateDescriptorSetWithTemplate(device, set, template, pData);
+        self.finish(());
+    }
+
+    unsafe fn get_queue(&mut self) {
+        let device = self.read::<VkDevice>();
+        let family = self.read::<u32>();
+        let index = self.read::<u32>();
+        let pQueue = self.read::<*mut VkQueue>();
+        if !self.is_complete() {
+            return;
+        }
+        entry::gfxGetDeviceQueue(device, family, index, pQueue);
+        self.queues.entry(device.as_raw()).or_insert(*pQueue);
+        self.finish(());
+    }
+
+    unsafe fn get_queue2(&mut self) {
+        let device = self.read::<VkDevice>();
+        let pQueueInfo = self.read::<*const VkDeviceQueueInfo2>();
+        let pQueue = self.read::<*mut VkQueue>();
+        if !self.is_complete() {
+            return;
+        }
+        entry::gfxGetDeviceQueue2(device, pQueueInfo, pQueue);
+        self.queues.entry(device.as_raw()).or_insert(*pQueue);
+        self.finish(());
+    }
+
+    /// Creates a swapchain that only exists in the replay, of which the images
+    /// are created when they are queried.
+    unsafe fn create_swapchain(&mut self) {
+        let device = self.read::<VkDevice>();
+        let pCreateInfo = self.read::<*const VkSwapchainCreateInfoKHR>();
+        let _pAllocator = self.read::<*const VkAllocationCallbacks>();
+        let pSwapchain = self.read::<*mut VkSwapchainKHR>();
+        if !self.is_complete() {
+            return;
+        }
+        // Unique, as there is one per call.
+        let raw = self.index as u64 + 1;
+        self.swapchains.insert(
+            raw,
+            Swapchain {
+                device,
+                info: *pCreateInfo,
+                images: Vec::new(),
+                next: 0,
+            },
+        );
+        *pSwapchain = VkSwapchainKHR::from_raw(raw);
+        self.finish(VkResult::VK_SUCCESS);
+    }
+
+    unsafe fn destroy_swapchain(&mut self) {
+        let device = self.read::<VkDevice>();
+        let swapchain = self.read::<VkSwapchainKHR>();
+        if !self.is_complete() {
+            return;
+        }
+        if let Some(swapchain) = self.swapchains.remove(&swapchain.as_raw()) {
+            for (image, memory) in swapchain.images {
+                entry::gfxDestroyImage(device, image, ptr::null());
+                entry::gfxFreeMemory(device, memory, ptr::null());
+            }
+        }
+        self.finish(());
+    }
+
+    unsafe fn swapchain_images(&mut self) {
+        let _device = self.read::<VkDevice>();
+        let swapchain = self.read::<VkSwapchainKHR>();
+        let pCount = self.read::<*mut u32>();
+        let pImages = self.read::<*mut VkImage>();
+        if !self.is_complete() {
+            return;
+        }
+        let swapchain = match self.swapchains.get_mut(&swapchain.as_raw()) {
+            Some(swapchain) => swapchain,
+            None => return self.finish(VkResult::VK_ERROR_OUT_OF_DATE_KHR),
+        };
+
+        if pImages.is_null() {
+            *pCount = swapchain
+                .info
+                .minImageCount
+                .max(swapchain.images.len() as u32);
+            return self.finish(VkResult::VK_SUCCESS);
+        }
+        // The application asks for as many images as it got from the capture.
+        while swapchain.images.len() < *pCount as usize {
+            match create_image(swapchain.device, &swapchain.info) {
+                Some(image) => swapchain.images.push(image),
+                None => return self.finish(VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY),
+            }
+        }
+        for (i, &(image, _)) in swapchain.images.iter().take(*pCount as usize).enumerate() {
+            *pImages.add(i) = image;
+        }
+        self.finish(VkResult::VK_SUCCESS);
+    }
+
+    /// Acquires the images in turn, signaling the semaphore and the fence
+    /// with an empty submission.
+    unsafe fn acquire_image(&mut self) {
+        let device = self.read::<VkDevice>();
+        let swapchain = self.read::<VkSwapchainKHR>();
+        let _timeout = self.read::<u64>();
+        let semaphore = self.read::<VkSemaphore>();
+        let fence = self.read::<VkFence>();
+        let pImageIndex = self.read::<*mut u32>();
+        if !self.is_complete() {
+            return;
+        }
+        let swapchain = match self.swapchains.get_mut(&swapchain.as_raw()) {
+            Some(swapchain) if !swapchain.images.is_empty() => swapchain,
+            _ => return self.finish(VkResult::VK_ERROR_OUT_OF_DATE_KHR),
+        };
+        *pImageIndex = swapchain.next as u32;
+        swapchain.next = (swapchain.next + 1) % swapchain.images.len();
+
+        let signals: &[VkSemaphore] = if semaphore.as_raw() == VK_NULL_HANDLE as u64 {
+            &[]
+        } else {
+            slice::from_ref(&semaphore)
+        };
+        let result = self.signal(device, signals, fence);
+        self.finish(result);
+    }
+
+    /// Waits for the semaphores of the presentation, the images not being shown.
+    unsafe fn present(&mut self) {
+        let queue = self.read::<VkQueue>();
+        let pPresentInfo = self.read::<*const VkPresentInfoKHR>();
+        if !self.is_complete() {
+            return;
+        }
+        let info = &*pPresentInfo;
+        let waits: &[VkSemaphore] = if info.pWaitSemaphores.is_null() {
+            &[]
+        } else {
+            slice::from_raw_parts(info.pWaitSemaphores, info.waitSemaphoreCount as usize)
+        };
+        let mut result = VkResult::VK_SUCCESS;
+        if !waits.is_empty() {
+            let stages = vec![
+                VkPipelineStageFlagBits::VK_PIPELINE_STAGE_ALL_COMMANDS_BIT
+                    as VkPipelineStageFlags;
+                waits.len()
+            ];
+            let submit = VkSubmitInfo {
+                sType: VkStructureType::VK_STRUCTURE_TYPE_SUBMIT_INFO,
+                pNext: ptr::null(),
+                waitSemaphoreCount: waits.len() as u32,
+                pWaitSemaphores: waits.as_ptr(),
+                pWaitDstStageMask: stages.as_ptr(),
+                commandBufferCount: 0,
+                pCommandBuffers: ptr::null(),
+                signalSemaphoreCount: 0,
+                pSignalSemaphores: ptr::null(),
+            };
+            result = entry::gfxQueueSubmit(queue, 1, &submit, VkFence::null());
+        }
+        if !info.pResults.is_null() {
+            for i in 0..info.swapchainCount as usize {
+                *info.pResults.add(i) = result;
+            }
+        }
+        self.finish(result);
+    }
+
+    /// Signals the semaphores and the fence from the first queue of the device.
+    unsafe fn signal(&self, device: VkDevice, signals: &[VkSemaphore], fence: VkFence) -> VkResult {
+        let queue = match self.queues.get(&device.as_raw()) {
+            Some(&queue) => queue,
+            None => return VkResult::VK_SUCCESS,
+        };
+        if signals.is_empty() && fence.as_raw() == VK_NULL_HANDLE as u64 {
+            return VkResult::VK_SUCCESS;
+        }
+        let submit = VkSubmitInfo {
+            sType: VkStructureType::VK_STRUCTURE_TYPE_SUBMIT_INFO,
+            pNext: ptr::null(),
+            waitSemaphoreCount: 0,
+            pWaitSemaphores: ptr::null(),
+            pWaitDstStageMask: ptr::null(),
+            commandBufferCount: 0,
+            pCommandBuffers: ptr::null(),
+            signalSemaphoreCount: signals.len() as u32,
+            pSignalSemaphores: signals.as_ptr(),
+        };
+        entry::gfxQueueSubmit(queue, 1, &submit, fence)
+    }
+}
+
+/// Creates an image standing for one of a swapchain, with its memory.
+unsafe fn create_image(
+    device: VkDevice,
+    info: &VkSwapchainCreateInfoKHR,
+) -> Option<(VkImage, VkDeviceMemory)> {
+    let image_info = VkImageCreateInfo {
+        sType: VkStructureType::VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO,
+        pNext: ptr::null(),
+        flags: 0,
+        imageType: VkImageType::VK_IMAGE_TYPE_2D,
+        format: info.imageFormat,
+        extent: VkExtent3D {
+            width: info.imageExtent.width,
+            height: info.imageExtent.height,
+            depth: 1,
+        },
+        mipLevels: 1,
+        arrayLayers: info.imageArrayLayers,
+        samples: VkSampleCountFlagBits::VK_SAMPLE_COUNT_1_BIT,
+        tiling: VkImageTiling::VK_IMAGE_TILING_OPTIMAL,
+        usage: info.imageUsage,
+        sharingMode: VkSharingMode::VK_SHARING_MODE_EXCLUSIVE,
+        queueFamilyIndexCount: 0,
+        pQueueFamilyIndices: ptr::null(),
+        initialLayout: VkImageLayout::VK_IMAGE_LAYOUT_UNDEFINED,
+    };
+    let mut image = VkImage::null();
+    if entry::gfxCreateImage(device, &image_info, ptr::null(), &mut image) != VkResult::VK_SUCCESS {
+        return None;
+    }
+
+    let mut requirements = mem::zeroed::<VkMemoryRequirements>();
+    entry::gfxGetImageMemoryRequirements(device, image, &mut requirements);
+    let allocate_info = VkMemoryAllocateInfo {
+        sType: VkStructureType::VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO,
+        pNext: ptr::null(),
+        allocationSize: requirements.size,
+        memoryTypeIndex: requirements.memoryTypeBits.trailing_zeros(),
+    };
+    let mut memory = VkDeviceMemory::null();
+    if entry::gfxAllocateMemory(device, &allocate_info, ptr::null(), &mut memory)
+        != VkResult::VK_SUCCESS
+    {
+        entry::gfxDestroyImage(device, image, ptr::null());
+        return None;
+    }
+    entry::gfxBindImageMemory(device, image, memory, 0);
+    Some((image, memory))
+}
@@ -0,0 +1,1516 @@
+//! Binary format of the traces written by the `capture` module
+//! and read by the `replay` one.
+//!
+//! A trace starts with a header, followed by records of three kinds,
+//! each made of its kind, the length of its payload and the payload:
+//! - the calls, with the name of the command and its parameters,
+//!   written before the call so that a crashing one is in the trace;
+//! - the returns, with the result of the call and the parameters it wrote;
+//! - the memory updates, with the data written by the application
+//!   to the mapped memory.
+//!
+//! The values are written following their pointers, the arrays being
+//! prefixed with their length. The reader rebuilds them in memory it keeps
+//! until the end of the call, replacing the captured handles by the ones
+//! created by the replay.
+
+use crate::empty::*;
+
+use fxhash::FxHashMap;
+
+use std::{
+    any::Any,
+    ffi::CStr,
+    mem,
+    os::raw::{c_char, c_void},
+    ptr, slice,
+};
+
+pub const MAGIC: &[u8; 8] = b"GFXTRACE";
+/// Version of the format, to be bumped with any change to the records
+/// or to the way a type is written.
+pub const VERSION: u32 = 2;
+
+pub const RECORD_CALL: u8 = 0;
+pub const RECORD_RETURN: u8 = 1;
+pub const RECORD_MEMORY: u8 = 2;
+
+/// Length of a null pointer, or of one that is not followed.
+const NULL: u32 = !0;
+/// `sType` ending a `pNext` chain.
+const CHAIN_END: u32 = !0;
+
+/// Buffer the payload of a record is written to.
+#[derive(Default)]
+pub struct Writer {
+    data: Vec<u8>,
+}
+
+impl Writer {
+    pub fn u8(&mut self, value: u8) {
+        self.data.push(value);
+    }
+
+    pub fn u32(&mut self, value: u32) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn u64(&mut self, value: u64) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn bytes(&mut self, bytes: &[u8]) {
+        self.data.extend_from_slice(bytes);
+    }
+
+    pub fn string(&mut self, string: &str) {
+        self.u32(string.len() as u32);
+        self.bytes(string.as_bytes());
+    }
+
+    /// Writes the bytes of a value that has no pointer nor handle.
+    pub unsafe fn value<T>(&mut self, value: &T) {
+        self.bytes(slice::from_raw_parts(
+            value as *const T as *const u8,
+            mem::size_of::<T>(),
+        ));
+    }
+
+    /// Returns the payload written so far, emptying the buffer.
+    pub fn take(&mut self) -> Vec<u8> {
+        mem::replace(&mut self.data, Vec::new())
+    }
+}
+
+type Output = Box<dyn FnOnce(&mut Reader)>;
+
+/// Reader of the payload of a record, owning the values pointed
+/// by the parameters of the call being replayed.
+#[derive(Default)]
+pub struct Reader {
+    data: Vec<u8>,
+    offset: usize,
+    /// Handles created by the replay, by the captured ones.
+    handles: FxHashMap<u64, u64>,
+    /// Set while reading the parameters written by a call,
+    /// of which the handles are the captured ones.
+    raw: bool,
+    storage: Vec<Box<dyn Any>>,
+    /// Readers of the parameters written by the call, from the return record.
+    outputs: Vec<Output>,
+    /// Number of captured handles that were not created by the replay.
+    pub unknown_handles: usize,
+    /// Number of bytes missing from the record being read, read as zeros.
+    pub missing: usize,
+}
+
+impl Reader {
+    /// Starts reading the payload of a record.
+    pub fn load(&mut self, data: Vec<u8>) {
+        self.data = data;
+        self.offset = 0;
+        self.missing = 0;
+    }
+
+    /// Reads the next bytes, the ones past the end of a truncated record
+    /// being zeros, for the player to stop at it.
+    pub fn bytes(&mut self, len: usize) -> &[u8] {
+        let start = self.offset;
+        self.offset += len;
+        if self.offset > self.data.len() {
+            self.missing += self.offset - self.data.len();
+            self.data.resize(self.offset, 0);
+        }
+        &self.data[start..self.offset]
+    }
+
+    /// Reads the next bytes, returning `None` if the record is too short.
+    pub fn try_bytes(&mut self, len: usize) -> Option<&[u8]> {
+        if self.offset + len > self.data.len() {
+            self.bytes(len);
+            None
+        } else {
+            Some(self.bytes(len))
+        }
+    }
+
+    pub fn u8(&mut self) -> u8 {
+        self.bytes(1)[0]
+    }
+
+    pub fn u32(&mut self) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.bytes(4));
+        u32::from_le_bytes(bytes)
+    }
+
+    pub fn u64(&mut self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.bytes(8));
+        u64::from_le_bytes(bytes)
+    }
+
+    pub fn string(&mut self) -> String {
+        let len = self.u32() as usize;
+        String::from_utf8_lossy(self.bytes(len)).into_owned()
+    }
+
+    /// Reads a value written by `Writer::value`.
+    pub unsafe fn value<T>(&mut self) -> T {
+        ptr::read_unaligned(self.bytes(mem::size_of::<T>()).as_ptr() as *const T)
+    }
+
+    /// Reads a handle, returning the one created by the replay in its place.
+    pub fn handle(&mut self) -> u64 {
+        let raw = self.u64();
+        if self.raw {
+            raw
+        } else {
+            self.lookup(raw)
+        }
+    }
+
+    /// Returns the handle created by the replay in place of a captured one.
+    pub fn lookup(&mut self, raw: u64) -> u64 {
+        if raw == VK_NULL_HANDLE as u64 {
+            return raw;
+        }
+        match self.handles.get(&raw) {
+            Some(&handle) => handle,
+            None => {
+                self.unknown_handles += 1;
+                VK_NULL_HANDLE as u64
+            }
+        }
+    }
+
+    /// Records the handle created by the replay in place of a captured one.
+    pub fn bind(&mut self, captured: u64, replayed: u64) {
+        if captured != VK_NULL_HANDLE as u64 {
+            self.handles.insert(captured, replayed);
+        }
+    }
+
+    /// Keeps the values until the end of the call, returning their address.
+    fn keep<T: 'static>(&mut self, mut values: Vec<T>) -> *mut T {
+        let ptr = values.as_mut_ptr();
+        self.storage.push(Box::new(values));
+        ptr
+    }
+
+    /// Reads the parameters written by the call from the return record,
+    /// binding the handles it created.
+    pub fn read_outputs(&mut self) {
+        let outputs = mem::replace(&mut self.outputs, Vec::new());
+        for output in outputs {
+            output(self);
+        }
+    }
+
+    /// Releases the values of the call.
+    pub fn release(&mut self) {
+        self.storage.clear();
+        self.outputs.clear();
+    }
+}
+
+/// How the pointers to a type are followed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Pointee {
+    /// Arrays of values, of one element unless the length is known.
+    Value,
+    /// Null-terminated strings.
+    String,
+    /// Arrays of bytes of a known length, not followed otherwise.
+    Bytes,
+    /// Not followed, the replay getting a null pointer.
+    Skipped,
+}
+
+/// A type that can be written to a trace and read back.
+pub trait Traced: Sized + 'static {
+    const POINTEE: Pointee = Pointee::Value;
+
+    unsafe fn write(&self, writer: &mut Writer);
+
+    unsafe fn read(reader: &mut Reader) -> Self;
+
+    /// Writes a parameter, the pointers being arrays of `len` elements.
+    unsafe fn write_array(&self, writer: &mut Writer, _len: Option<usize>) {
+        self.write(writer)
+    }
+
+    /// Writes a parameter once the call returned, if the call writes to it.
+    unsafe fn write_output(&self, _writer: &mut Writer, _len: Option<usize>) {}
+
+    /// Binds the handles of a captured value to the ones of the replayed value.
+    fn bind(&self, _replayed: &Self, _reader: &mut Reader) {}
+
+    /// Raw value of the handles, sizes and pointers.
+    fn raw(&self) -> u64 {
+        0
+    }
+
+    /// Number of elements given by a parameter, directly or by pointer.
+    unsafe fn count(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns true for the results of the calls that succeeded.
+    fn succeeded(&self) -> bool {
+        true
+    }
+
+    /// Compares a result of the replay with the captured one.
+    fn matches(&self, _captured: &Self) -> bool {
+        true
+    }
+}
+
+unsafe fn write_pointer<T: Traced>(ptr: *const T, len: Option<usize>, writer: &mut Writer) {
+    if ptr.is_null() {
+        return writer.u32(NULL);
+    }
+    match T::POINTEE {
+        Pointee::Value => {
+            let len = len.unwrap_or(1);
+            writer.u32(len as u32);
+            for value in slice::from_raw_parts(ptr, len) {
+                value.write(writer);
+            }
+        }
+        Pointee::String => {
+            let bytes = CStr::from_ptr(ptr as *const c_char).to_bytes();
+            writer.u32(bytes.len() as u32);
+            writer.bytes(bytes);
+        }
+        Pointee::Bytes => match len {
+            Some(len) => {
+                writer.u32(len as u32);
+                writer.bytes(slice::from_raw_parts(ptr as *const u8, len));
+            }
+            None => writer.u32(NULL),
+        },
+        Pointee::Skipped => writer.u32(NULL),
+    }
+}
+
+/// Reads the values written by `write_pointer`, returning their address and length.
+unsafe fn read_pointer<T: Traced>(reader: &mut Reader) -> (*mut T, usize) {
+    let len = reader.u32();
+    if len == NULL {
+        return (ptr::null_mut(), 0);
+    }
+    let len = len as usize;
+    let ptr = match T::POINTEE {
+        Pointee::Value => {
+            let values = (0..len).map(|_| T::read(reader)).collect();
+            reader.keep::<T>(values)
+        }
+        Pointee::String => {
+            let mut bytes = reader.bytes(len).to_vec();
+            bytes.push(0);
+            reader.keep(bytes) as *mut T
+        }
+        Pointee::Bytes => {
+            // Aligned for the data read as words, like the specialization constants.
+            let mut words = vec![0u64; (len + 7) / 8];
+            ptr::copy_nonoverlapping(
+                reader.bytes(len).as_ptr(),
+                words.as_mut_ptr() as *mut u8,
+                len,
+            );
+            reader.keep(words) as *mut T
+        }
+        Pointee::Skipped => ptr::null_mut(),
+    };
+    (ptr, len)
+}
+
+/// Binds the handles written by a call to the ones of the replay.
+unsafe fn bind_output<T: Traced>(replayed: *mut T, len: usize, reader: &mut Reader) {
+    let raw = mem::replace(&mut reader.raw, true);
+    let (captured, captured_len) = read_pointer::<T>(reader);
+    reader.raw = raw;
+    if T::POINTEE != Pointee::Value || captured.is_null() || replayed.is_null() {
+        return;
+    }
+    for i in 0..len.min(captured_len) {
+        (*captured.add(i)).bind(&*replayed.add(i), reader);
+    }
+}
+
+impl<T: Traced> Traced for *const T {
+    unsafe fn write(&self, writer: &mut Writer) {
+        write_pointer(*self, None, writer)
+    }
+
+    unsafe fn read(reader: &mut Reader) -> Self {
+        read_pointer::<T>(reader).0
+    }
+
+    unsafe fn write_array(&self, writer: &mut Writer, len: Option<usize>) {
+        write_pointer(*self, len, writer)
+    }
+
+    fn raw(&self) -> u64 {
+        *self as u64
+    }
+
+    unsafe fn count(&self) -> Option<usize> {
+        self.as_ref().and_then(|value| value.count())
+    }
+}
+
+impl<T: Traced> Traced for *mut T {
+    unsafe fn write(&self, writer: &mut Writer) {
+        write_pointer(*self, None, writer)
+    }
+
+    /// Reads the values given to the call, and registers the reader
+    /// of the ones it wrote, for the handles it created.
+    unsafe fn read(reader: &mut Reader) -> Self {
+        let raw = mem::replace(&mut reader.raw, true);
+        let (ptr, len) = read_pointer::<T>(reader);
+        reader.raw = raw;
+        reader.outputs.push(Box::new(move |reader: &mut Reader| {
+            bind_output(ptr, len, reader)
+        }));
+        ptr
+    }
+
+    unsafe fn write_array(&self, writer: &mut Writer, len: Option<usize>) {
+        write_pointer(*self, len, writer)
+    }
+
+    unsafe fn write_output(&self, writer: &mut Writer, len: Option<usize>) {
+        write_pointer(*self, len, writer)
+    }
+
+    fn raw(&self) -> u64 {
+        *self as u64
+    }
+
+    unsafe fn count(&self) -> Option<usize> {
+        self.as_ref().and_then(|value| value.count())
+    }
+}
+
+/// Implements `Traced` for the types written as their bytes.
+macro_rules! traced_plain {
+    ($($ty:ty,)*) => {
+        $(
+            impl Traced for $ty {
+                unsafe fn write(&self, writer: &mut Writer) {
+                    writer.value(self)
+                }
+
+                unsafe fn read(reader: &mut Reader) -> Self {
+                    reader.value()
+                }
+            }
+        )*
+    };
+}
+
+/// Implements `Traced` for the integers, which can be counts.
+macro_rules! traced_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl Traced for $ty {
+                unsafe fn write(&self, writer: &mut Writer) {
+                    writer.value(self)
+                }
+
+                unsafe fn read(reader: &mut Reader) -> Self {
+                    reader.value()
+                }
+
+                fn raw(&self) -> u64 {
+                    *self as u64
+                }
+
+                unsafe fn count(&self) -> Option<usize> {
+                    Some(*self as usize)
+                }
+            }
+        )*
+    };
+}
+
+traced_integers!(u32, u64, usize);
+traced_plain!(bool, i32, f32,);
+
+/// Implements `Traced` for the types that are not followed, and not written.
+macro_rules! traced_skipped {
+    ($($ty:ty,)*) => {
+        $(
+            impl Traced for $ty {
+                const POINTEE: Pointee = Pointee::Skipped;
+
+                unsafe fn write(&self, _writer: &mut Writer) {}
+
+                unsafe fn read(_reader: &mut Reader) -> Self {
+                    mem::zeroed()
+                }
+            }
+        )*
+    };
+}
+
+// The allocation and debug callbacks can't be called by the replay.
+traced_skipped! {
+    VkAllocationCallbacks,
+    VkDebugReportCallbackCreateInfoEXT,
+    VkDebugUtilsMessengerCreateInfoEXT,
+    VkDisplayKHR_T,
+    VkDisplayModeKHR_T,
+    VkIndirectCommandsLayoutNVX_T,
+    VkObjectTableNVX_T,
+}
+
+/// The C strings, `c_char` being one or the other depending on the platform.
+macro_rules! traced_chars {
+    ($($ty:ty),*) => {
+        $(
+            impl Traced for $ty {
+                const POINTEE: Pointee = Pointee::String;
+
+                unsafe fn write(&self, writer: &mut Writer) {
+                    writer.value(self)
+                }
+
+                unsafe fn read(reader: &mut Reader) -> Self {
+                    reader.value()
+                }
+            }
+        )*
+    };
+}
+
+traced_chars!(i8, u8);
+
+impl Traced for c_void {
+    const POINTEE: Pointee = Pointee::Bytes;
+
+    unsafe fn write(&self, _writer: &mut Writer) {
+        unreachable!()
+    }
+
+    unsafe fn read(_reader: &mut Reader) -> Self {
+        unreachable!()
+    }
+}
+
+impl Traced for () {
+    unsafe fn write(&self, _writer: &mut Writer) {}
+
+    unsafe fn read(_reader: &mut Reader) -> Self {}
+}
+
+/// The functions returned by the `GetProcAddr` commands are not replayed.
+impl Traced for PFN_vkVoidFunction {
+    unsafe fn write(&self, _writer: &mut Writer) {}
+
+    unsafe fn read(_reader: &mut Reader) -> Self {
+        None
+    }
+}
+
+impl Traced for VkResult {
+    unsafe fn write(&self, writer: &mut Writer) {
+        writer.value(self)
+    }
+
+    unsafe fn read(reader: &mut Reader) -> Self {
+        reader.value()
+    }
+
+    fn succeeded(&self) -> bool {
+        *self as i32 >= 0
+    }
+
+    fn matches(&self, captured: &Self) -> bool {
+        self == captured
+    }
+}
+
+/// Implements `Traced` for the arrays of the given lengths.
+macro_rules! traced_arrays {
+    ($($len:expr),*) => {
+        $(
+            impl<T: Traced> Traced for [T; $len] {
+                unsafe fn write(&self, writer: &mut Writer) {
+                    for value in self {
+                        value.write(writer);
+                    }
+                }
+
+                unsafe fn read(reader: &mut Reader) -> Self {
+                    let mut array = mem::MaybeUninit::<Self>::uninit();
+                    let first = array.as_mut_ptr() as *mut T;
+                    for i in 0 .. $len {
+                        first.add(i).write(T::read(reader));
+                    }
+                    array.assume_init()
+                }
+
+                fn bind(&self, replayed: &Self, reader: &mut Reader) {
+                    for (captured, replayed) in self.iter().zip(replayed) {
+                        captured.bind(replayed, reader);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+traced_arrays!(4, 8, 16, 32, 256);
+
+// Enumerations, and structures without pointers nor handles.
+traced_plain! {
+    VkAttachmentLoadOp, VkAttachmentStoreOp, VkBlendFactor, VkBlendOp, VkBorderColor,
+    VkColorSpaceKHR, VkCommandBufferLevel, VkCompareOp, VkComponentSwizzle,
+    VkCompositeAlphaFlagBitsKHR, VkDebugReportObjectTypeEXT, VkDebugUtilsMessageSeverityFlagBitsEXT,
+    VkDescriptorType, VkDescriptorUpdateTemplateTypeKHR, VkDeviceEventTypeEXT,
+    VkDeviceGroupPresentModeFlagBitsKHX, VkDiscardRectangleModeEXT, VkDisplayEventTypeEXT,
+    VkDisplayPlaneAlphaFlagBitsKHR, VkDisplayPowerStateEXT, VkDriverIdKHR, VkDynamicState,
+    VkExternalFenceHandleTypeFlagBits, VkExternalMemoryHandleTypeFlagBitsKHX,
+    VkExternalSemaphoreHandleTypeFlagBitsKHX, VkFilter, VkFormat, VkFrontFace, VkImageLayout,
+    VkImageTiling, VkImageType, VkImageViewType, VkIndexType, VkIndirectCommandsTokenTypeNVX,
+    VkLogicOp, VkObjectEntryTypeNVX, VkObjectType, VkPhysicalDeviceType, VkPipelineBindPoint,
+    VkPipelineStageFlagBits, VkPointClippingBehavior, VkPolygonMode, VkPresentModeKHR,
+    VkPrimitiveTopology, VkQueryType, VkRasterizationOrderAMD, VkSampleCountFlagBits,
+    VkSamplerAddressMode, VkSamplerMipmapMode, VkShaderStageFlagBits, VkSharingMode, VkStencilOp,
+    VkStructureType, VkSubpassContents, VkSurfaceCounterFlagBitsEXT, VkSurfaceTransformFlagBitsKHR,
+    VkValidationCheckEXT, VkVertexInputRate, VkViewportCoordinateSwizzleNV,
+    VkAttachmentDescription, VkAttachmentReference, VkBufferCopy, VkBufferImageCopy,
+    VkClearAttachment, VkClearColorValue, VkClearDepthStencilValue, VkClearRect, VkClearValue,
+    VkComponentMapping, VkConformanceVersionKHR, VkDescriptorPoolSize,
+    VkDescriptorUpdateTemplateEntryKHR, VkDisplayModeParametersKHR, VkExtensionProperties,
+    VkExtent2D, VkExtent3D, VkExternalMemoryPropertiesKHX, VkFormatProperties, VkImageBlit,
+    VkImageCopy, VkImageFormatProperties, VkImageResolve, VkImageSubresource,
+    VkImageSubresourceLayers, VkImageSubresourceRange, VkIndirectCommandsLayoutTokenNVX,
+    VkLayerProperties, VkMemoryHeap, VkMemoryRequirements, VkMemoryType, VkObjectTableEntryNVX,
+    VkOffset2D, VkOffset3D, VkPhysicalDeviceFeatures, VkPhysicalDeviceLimits,
+    VkPhysicalDeviceMemoryProperties, VkPhysicalDeviceProperties, VkPhysicalDeviceSparseProperties,
+    VkPipelineColorBlendAttachmentState, VkPushConstantRange, VkQueueFamilyProperties, VkRect2D,
+    VkSparseImageFormatProperties, VkSparseImageMemoryRequirements, VkSpecializationMapEntry,
+    VkStencilOpState, VkSubpassDependency, VkSubresourceLayout, VkSurfaceCapabilitiesKHR,
+    VkSurfaceFormatKHR, VkVertexInputAttributeDescription, VkVertexInputBindingDescription,
+    VkViewport, VkViewportSwizzleNV, VkViewportWScalingNV,
+}
+
+/// Writes a field of a structure, given the expression of the length
+/// of the arrays, or `chain` for the `pNext` chains.
+macro_rules! write_field {
+    ($writer:ident, $s:ident.$field:ident) => {
+        Traced::write(&$s.$field, $writer)
+    };
+    ($writer:ident, $s:ident.$field:ident [chain]) => {
+        write_chain($s.$field as *const c_void, $writer)
+    };
+    ($writer:ident, $s:ident.$field:ident [$($len:tt)*]) => {
+        Traced::write_array(&$s.$field, $writer, Some(($($len)*) as usize))
+    };
+}
+
+macro_rules! read_field {
+    ($reader:ident) => {
+        Traced::read($reader)
+    };
+    ($reader:ident [chain]) => {
+        read_chain($reader) as _
+    };
+    ($reader:ident [$($len:tt)*]) => {
+        Traced::read($reader)
+    };
+}
+
+/// Implements `Traced` for the structures written field by field.
+macro_rules! traced_structs {
+    ($($ty:ident($s:ident) { $($field:ident $([$($len:tt)*])?,)* })*) => {
+        $(
+            impl Traced for $ty {
+                unsafe fn write(&self, writer: &mut Writer) {
+                    let $s = self;
+                    $(write_field!(writer, $s.$field $([$($len)*])?);)*
+                }
+
+                unsafe fn read(reader: &mut Reader) -> Self {
+                    $ty {
+                        $($field: read_field!(reader $([$($len)*])?),)*
+                    }
+                }
+
+                fn bind(&self, replayed: &Self, reader: &mut Reader) {
+                    $(Traced::bind(&self.$field, &replayed.$field, reader);)*
+                }
+            }
+        )*
+    };
+}
+
+fn is_concurrent(mode: VkSharingMode) -> bool {
+    mode == VkSharingMode::VK_SHARING_MODE_CONCURRENT
+}
+
+fn has_samplers(ty: VkDescriptorType) -> bool {
+    match ty {
+        VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER
+        | VkDescriptorType::VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER => true,
+        _ => false,
+    }
+}
+
+pub fn has_image_info(ty: VkDescriptorType) -> bool {
+    match ty {
+        VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER
+        | VkDescriptorType::VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER
+        | VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLED_IMAGE
+        | VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_IMAGE
+        | VkDescriptorType::VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT => true,
+        _ => false,
+    }
+}
+
+pub fn has_buffer_info(ty: VkDescriptorType) -> bool {
+    match ty {
+        VkDescriptorType::VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER
+        | VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER
+        | VkDescriptorType::VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC
+        | VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC => true,
+        _ => false,
+    }
+}
+
+pub fn has_texel_buffer_view(ty: VkDescriptorType) -> bool {
+    match ty {
+        VkDescriptorType::VK_DESCRIPTOR_TYPE_UNIFORM_TEXEL_BUFFER
+        | VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_TEXEL_BUFFER => true,
+        _ => false,
+    }
+}
+
+/// Size of the data given to `vkUpdateDescriptorSetWithTemplate` with a template.
+pub fn template_data_size(entries: &[VkDescriptorUpdateTemplateEntryKHR]) -> usize {
+    entries
+        .iter()
+        .filter(|entry| entry.descriptorCount != 0)
+        .map(|entry| {
+            let size = if has_image_info(entry.descriptorType) {
+                mem::size_of::<VkDescriptorImageInfo>()
+            } else if has_buffer_info(entry.descriptorType) {
+                mem::size_of::<VkDescriptorBufferInfo>()
+            } else {
+                mem::size_of::<VkBufferView>()
+            };
+            entry.offset + (entry.descriptorCount as usize - 1) * entry.stride + size
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Defines the readers and writers of the structures of the `pNext` chains.
+macro_rules! chain {
+    ($($ty:ident => $sType:ident,)*) => {
+        /// Writes the structures of a chain, skipping the unknown ones.
+        unsafe fn write_chain(mut next: *const c_void, writer: &mut Writer) {
+            while !next.is_null() {
+                let base = &*(next as *const VkBaseStruct);
+                match base.sType {
+                    $(
+                        VkStructureType::$sType if <$ty as Traced>::POINTEE == Pointee::Value => {
+                            writer.u32(base.sType as u32);
+                            // Including the rest of the chain.
+                            return Traced::write(&*(next as *const $ty), writer);
+                        }
+                    )*
+                    _ => next = base.pNext,
+                }
+            }
+            writer.u32(CHAIN_END);
+        }
+
+        unsafe fn read_chain(reader: &mut Reader) -> *const c_void {
+            let sType = reader.u32();
+            $(
+                if sType == VkStructureType::$sType as u32 {
+                    let value = <$ty as Traced>::read(reader);
+                    return reader.keep(vec![value]) as *const c_void;
+                }
+            )*
+            assert_eq!(sType, CHAIN_END, "Unknown structure in a chain");
+            ptr::null()
+        }
+    };
+}
+
+chained_structures!(chain);
+
+traced_structs! {
+    VkAcquireNextImageInfoKHX(s) {
+        sType, pNext[chain], swapchain, timeout, semaphore, fence, deviceMask,
+    }
+    VkApplicationInfo(s) {
+        sType, pNext[chain], pApplicationName, applicationVersion, pEngineName, engineVersion,
+        apiVersion,
+    }
+    VkBindBufferMemoryDeviceGroupInfoKHR(s) {
+        sType, pNext[chain], deviceIndexCount, pDeviceIndices[s.deviceIndexCount],
+    }
+    VkBindBufferMemoryInfoKHR(s) {
+        sType, pNext[chain], buffer, memory, memoryOffset,
+    }
+    VkBindBufferMemoryInfoKHX(s) {
+        sType, pNext[chain], buffer, memory, memoryOffset, deviceIndexCount,
+        pDeviceIndices[s.deviceIndexCount],
+    }
+    VkBindImageMemoryDeviceGroupInfoKHR(s) {
+        sType, pNext[chain], deviceIndexCount, pDeviceIndices[s.deviceIndexCount],
+        splitInstanceBindRegionCount, pSplitInstanceBindRegions[s.splitInstanceBindRegionCount],
+    }
+    VkBindImageMemoryInfoKHR(s) {
+        sType, pNext[chain], image, memory, memoryOffset,
+    }
+    VkBindImageMemoryInfoKHX(s) {
+        sType, pNext[chain], image, memory, memoryOffset, deviceIndexCount,
+        pDeviceIndices[s.deviceIndexCount], SFRRectCount, pSFRRects[s.SFRRectCount],
+    }
+    VkBindImageMemorySwapchainInfoKHX(s) {
+        sType, pNext[chain], swapchain, imageIndex,
+    }
+    VkBindSparseInfo(s) {
+        sType, pNext[chain], waitSemaphoreCount, pWaitSemaphores[s.waitSemaphoreCount],
+        bufferBindCount, pBufferBinds[s.bufferBindCount], imageOpaqueBindCount,
+        pImageOpaqueBinds[s.imageOpaqueBindCount], imageBindCount, pImageBinds[s.imageBindCount],
+        signalSemaphoreCount, pSignalSemaphores[s.signalSemaphoreCount],
+    }
+    VkBufferCreateInfo(s) {
+        sType, pNext[chain], flags, size, usage, sharingMode, queueFamilyIndexCount,
+        pQueueFamilyIndices[if is_concurrent(s.sharingMode) { s.queueFamilyIndexCount } else { 0 }],
+    }
+    VkBufferMemoryBarrier(s) {
+        sType, pNext[chain], srcAccessMask, dstAccessMask, srcQueueFamilyIndex, dstQueueFamilyIndex,
+        buffer, offset, size,
+    }
+    VkBufferMemoryRequirementsInfo2KHR(s) {
+        sType, pNext[chain], buffer,
+    }
+    VkBufferViewCreateInfo(s) {
+        sType, pNext[chain], flags, buffer, format, offset, range,
+    }
+    VkCmdProcessCommandsInfoNVX(s) {
+        sType, pNext[chain], objectTable, indirectCommandsLayout, indirectCommandsTokenCount,
+        pIndirectCommandsTokens[s.indirectCommandsTokenCount], maxSequencesCount,
+        targetCommandBuffer, sequencesCountBuffer, sequencesCountOffset, sequencesIndexBuffer,
+        sequencesIndexOffset,
+    }
+    VkCmdReserveSpaceForCommandsInfoNVX(s) {
+        sType, pNext[chain], objectTable, indirectCommandsLayout, maxSequencesCount,
+    }
+    VkCommandBufferAllocateInfo(s) {
+        sType, pNext[chain], commandPool, level, commandBufferCount,
+    }
+    VkCommandBufferBeginInfo(s) {
+        sType, pNext[chain], flags, pInheritanceInfo,
+    }
+    VkCommandBufferInheritanceInfo(s) {
+        sType, pNext[chain], renderPass, subpass, framebuffer, occlusionQueryEnable, queryFlags,
+        pipelineStatistics,
+    }
+    VkCommandPoolCreateInfo(s) {
+        sType, pNext[chain], flags, queueFamilyIndex,
+    }
+    VkComputePipelineCreateInfo(s) {
+        sType, pNext[chain], flags, stage, layout, basePipelineHandle, basePipelineIndex,
+    }
+    VkCopyDescriptorSet(s) {
+        sType, pNext[chain], srcSet, srcBinding, srcArrayElement, dstSet, dstBinding,
+        dstArrayElement, descriptorCount,
+    }
+    VkDebugMarkerMarkerInfoEXT(s) {
+        sType, pNext[chain], pMarkerName, color,
+    }
+    VkDebugMarkerObjectNameInfoEXT(s) {
+        sType, pNext[chain], objectType, object, pObjectName,
+    }
+    VkDebugMarkerObjectTagInfoEXT(s) {
+        sType, pNext[chain], objectType, object, tagName, tagSize, pTag[s.tagSize],
+    }
+    VkDebugUtilsLabelEXT(s) {
+        sType, pNext[chain], pLabelName, color,
+    }
+    VkDebugUtilsMessengerCallbackDataEXT(s) {
+        sType, pNext[chain], flags, pMessageIdName, messageIdNumber, pMessage, queueLabelCount,
+        pQueueLabels[s.queueLabelCount], cmdBufLabelCount, pCmdBufLabels[s.cmdBufLabelCount],
+        objectCount, pObjects[s.objectCount],
+    }
+    VkDebugUtilsObjectNameInfoEXT(s) {
+        sType, pNext[chain], objectType, objectHandle, pObjectName,
+    }
+    VkDebugUtilsObjectTagInfoEXT(s) {
+        sType, pNext[chain], objectType, objectHandle, tagName, tagSize, pTag[s.tagSize],
+    }
+    VkDedicatedAllocationBufferCreateInfoNV(s) {
+        sType, pNext[chain], dedicatedAllocation,
+    }
+    VkDedicatedAllocationImageCreateInfoNV(s) {
+        sType, pNext[chain], dedicatedAllocation,
+    }
+    VkDedicatedAllocationMemoryAllocateInfoNV(s) {
+        sType, pNext[chain], image, buffer,
+    }
+    VkDescriptorBufferInfo(s) {
+        buffer, offset, range,
+    }
+    VkDescriptorImageInfo(s) {
+        sampler, imageView, imageLayout,
+    }
+    VkDescriptorPoolCreateInfo(s) {
+        sType, pNext[chain], flags, maxSets, poolSizeCount, pPoolSizes[s.poolSizeCount],
+    }
+    VkDescriptorSetAllocateInfo(s) {
+        sType, pNext[chain], descriptorPool, descriptorSetCount, pSetLayouts[s.descriptorSetCount],
+    }
+    VkDescriptorSetLayoutBinding(s) {
+        binding, descriptorType, descriptorCount, stageFlags, pImmutableSamplers[if
+        has_samplers(s.descriptorType) { s.descriptorCount } else { 0 }],
+    }
+    VkDescriptorSetLayoutCreateInfo(s) {
+        sType, pNext[chain], flags, bindingCount, pBindings[s.bindingCount],
+    }
+    VkDescriptorSetLayoutSupport(s) {
+        sType, pNext[chain], supported,
+    }
+    VkDescriptorUpdateTemplateCreateInfoKHR(s) {
+        sType, pNext[chain], flags, descriptorUpdateEntryCount,
+        pDescriptorUpdateEntries[s.descriptorUpdateEntryCount], templateType, descriptorSetLayout,
+        pipelineBindPoint, pipelineLayout, set,
+    }
+    VkDeviceCreateInfo(s) {
+        sType, pNext[chain], flags, queueCreateInfoCount, pQueueCreateInfos[s.queueCreateInfoCount],
+        enabledLayerCount, ppEnabledLayerNames[s.enabledLayerCount], enabledExtensionCount,
+        ppEnabledExtensionNames[s.enabledExtensionCount], pEnabledFeatures,
+    }
+    VkDeviceEventInfoEXT(s) {
+        sType, pNext[chain], deviceEvent,
+    }
+    VkDeviceGeneratedCommandsFeaturesNVX(s) {
+        sType, pNext[chain], computeBindingPointSupport,
+    }
+    VkDeviceGeneratedCommandsLimitsNVX(s) {
+        sType, pNext[chain], maxIndirectCommandsLayoutTokenCount, maxObjectEntryCounts,
+        minSequenceCountBufferOffsetAlignment, minSequenceIndexBufferOffsetAlignment,
+        minCommandsTokenBufferOffsetAlignment,
+    }
+    VkDeviceGroupBindSparseInfoKHX(s) {
+        sType, pNext[chain], resourceDeviceIndex, memoryDeviceIndex,
+    }
+    VkDeviceGroupCommandBufferBeginInfoKHX(s) {
+        sType, pNext[chain], deviceMask,
+    }
+    VkDeviceGroupDeviceCreateInfoKHX(s) {
+        sType, pNext[chain], physicalDeviceCount, pPhysicalDevices[s.physicalDeviceCount],
+    }
+    VkDeviceGroupPresentCapabilitiesKHX(s) {
+        sType, pNext[chain], presentMask, modes,
+    }
+    VkDeviceGroupPresentInfoKHX(s) {
+        sType, pNext[chain], swapchainCount, pDeviceMasks[s.swapchainCount], mode,
+    }
+    VkDeviceGroupRenderPassBeginInfoKHX(s) {
+        sType, pNext[chain], deviceMask, deviceRenderAreaCount,
+        pDeviceRenderAreas[s.deviceRenderAreaCount],
+    }
+    VkDeviceGroupSubmitInfoKHX(s) {
+        sType, pNext[chain], waitSemaphoreCount, pWaitSemaphoreDeviceIndices[s.waitSemaphoreCount],
+        commandBufferCount, pCommandBufferDeviceMasks[s.commandBufferCount], signalSemaphoreCount,
+        pSignalSemaphoreDeviceIndices[s.signalSemaphoreCount],
+    }
+    VkDeviceGroupSwapchainCreateInfoKHX(s) {
+        sType, pNext[chain], modes,
+    }
+    VkDeviceQueueCreateInfo(s) {
+        sType, pNext[chain], flags, queueFamilyIndex, queueCount, pQueuePriorities[s.queueCount],
+    }
+    VkDeviceQueueInfo2(s) {
+        sType, pNext[chain], flags, queueFamilyIndex, queueIndex,
+    }
+    VkDisplayEventInfoEXT(s) {
+        sType, pNext[chain], displayEvent,
+    }
+    VkDisplayModeCreateInfoKHR(s) {
+        sType, pNext[chain], flags, parameters,
+    }
+    VkDisplayPowerInfoEXT(s) {
+        sType, pNext[chain], powerState,
+    }
+    VkDisplayPresentInfoKHR(s) {
+        sType, pNext[chain], srcRect, dstRect, persistent,
+    }
+    VkDisplaySurfaceCreateInfoKHR(s) {
+        sType, pNext[chain], flags, displayMode, planeIndex, planeStackIndex, transform,
+        globalAlpha, alphaMode, imageExtent,
+    }
+    VkEventCreateInfo(s) {
+        sType, pNext[chain], flags,
+    }
+    VkExportMemoryAllocateInfoKHX(s) {
+        sType, pNext[chain], handleTypes,
+    }
+    VkExportMemoryAllocateInfoNV(s) {
+        sType, pNext[chain], handleTypes,
+    }
+    VkExportSemaphoreCreateInfoKHX(s) {
+        sType, pNext[chain], handleTypes,
+    }
+    VkExternalBufferPropertiesKHX(s) {
+        sType, pNext[chain], externalMemoryProperties,
+    }
+    VkExternalFenceProperties(s) {
+        sType, pNext[chain], exportFromImportedHandleTypes, compatibleHandleTypes,
+        externalFenceFeatures,
+    }
+    VkExternalImageFormatPropertiesKHX(s) {
+        sType, pNext[chain], externalMemoryProperties,
+    }
+    VkExternalMemoryBufferCreateInfoKHX(s) {
+        sType, pNext[chain], handleTypes,
+    }
+    VkExternalMemoryImageCreateInfoKHX(s) {
+        sType, pNext[chain], handleTypes,
+    }
+    VkExternalMemoryImageCreateInfoNV(s) {
+        sType, pNext[chain], handleTypes,
+    }
+    VkExternalSemaphorePropertiesKHX(s) {
+        sType, pNext[chain], exportFromImportedHandleTypes, compatibleHandleTypes,
+        externalSemaphoreFeatures,
+    }
+    VkFenceCreateInfo(s) {
+        sType, pNext[chain], flags,
+    }
+    VkFormatProperties2KHR(s) {
+        sType, pNext[chain], formatProperties,
+    }
+    VkFramebufferAttachmentImageInfoKHR(s) {
+        sType, pNext[chain], flags, usage, width, height, layerCount, viewFormatCount,
+        pViewFormats[s.viewFormatCount],
+    }
+    VkFramebufferAttachmentsCreateInfoKHR(s) {
+        sType, pNext[chain], attachmentImageInfoCount,
+        pAttachmentImageInfos[s.attachmentImageInfoCount],
+    }
+    VkFramebufferCreateInfo(s) {
+        sType, pNext[chain], flags, renderPass, attachmentCount, pAttachments[s.attachmentCount],
+        width, height, layers,
+    }
+    VkGraphicsPipelineCreateInfo(s) {
+        sType, pNext[chain], flags, stageCount, pStages[s.stageCount], pVertexInputState,
+        pInputAssemblyState, pTessellationState, pViewportState, pRasterizationState,
+        pMultisampleState, pDepthStencilState, pColorBlendState, pDynamicState, layout, renderPass,
+        subpass, basePipelineHandle, basePipelineIndex,
+    }
+    VkHeadlessSurfaceCreateInfoEXT(s) {
+        sType, pNext[chain], flags,
+    }
+    VkImageCreateInfo(s) {
+        sType, pNext[chain], flags, imageType, format, extent, mipLevels, arrayLayers, samples,
+        tiling, usage, sharingMode, queueFamilyIndexCount, pQueueFamilyIndices[if
+        is_concurrent(s.sharingMode) { s.queueFamilyIndexCount } else { 0 }], initialLayout,
+    }
+    VkImageFormatProperties2KHR(s) {
+        sType, pNext[chain], imageFormatProperties,
+    }
+    VkImageFormatProperties2KHX(s) {
+        sType, pNext[chain], imageFormatProperties,
+    }
+    VkImageMemoryBarrier(s) {
+        sType, pNext[chain], srcAccessMask, dstAccessMask, oldLayout, newLayout,
+        srcQueueFamilyIndex, dstQueueFamilyIndex, image, subresourceRange,
+    }
+    VkImageMemoryRequirementsInfo2KHR(s) {
+        sType, pNext[chain], image,
+    }
+    VkImageSparseMemoryRequirementsInfo2KHR(s) {
+        sType, pNext[chain], image,
+    }
+    VkImageSwapchainCreateInfoKHX(s) {
+        sType, pNext[chain], swapchain,
+    }
+    VkImageViewCreateInfo(s) {
+        sType, pNext[chain], flags, image, viewType, format, components, subresourceRange,
+    }
+    VkImageViewUsageCreateInfoKHR(s) {
+        sType, pNext[chain], usage,
+    }
+    VkImportMemoryFdInfoKHX(s) {
+        sType, pNext[chain], handleType, fd,
+    }
+    VkImportSemaphoreFdInfoKHX(s) {
+        sType, pNext[chain], semaphore, handleType, fd,
+    }
+    VkIndirectCommandsLayoutCreateInfoNVX(s) {
+        sType, pNext[chain], pipelineBindPoint, flags, tokenCount, pTokens[s.tokenCount],
+    }
+    VkIndirectCommandsTokenNVX(s) {
+        tokenType, buffer, offset,
+    }
+    VkInstanceCreateInfo(s) {
+        sType, pNext[chain], flags, pApplicationInfo, enabledLayerCount,
+        ppEnabledLayerNames[s.enabledLayerCount], enabledExtensionCount,
+        ppEnabledExtensionNames[s.enabledExtensionCount],
+    }
+    VkMacOSSurfaceCreateInfoMVK(s) {
+        sType, pNext[chain], flags, pView,
+    }
+    VkMappedMemoryRange(s) {
+        sType, pNext[chain], memory, offset, size,
+    }
+    VkMemoryAllocateFlagsInfoKHX(s) {
+        sType, pNext[chain], flags, deviceMask,
+    }
+    VkMemoryAllocateInfo(s) {
+        sType, pNext[chain], allocationSize, memoryTypeIndex,
+    }
+    VkMemoryBarrier(s) {
+        sType, pNext[chain], srcAccessMask, dstAccessMask,
+    }
+    VkMemoryDedicatedAllocateInfoKHR(s) {
+        sType, pNext[chain], image, buffer,
+    }
+    VkMemoryDedicatedRequirementsKHR(s) {
+        sType, pNext[chain], prefersDedicatedAllocation, requiresDedicatedAllocation,
+    }
+    VkMemoryFdPropertiesKHX(s) {
+        sType, pNext[chain], memoryTypeBits,
+    }
+    VkMemoryRequirements2KHR(s) {
+        sType, pNext[chain], memoryRequirements,
+    }
+    VkMetalSurfaceCreateInfoEXT(s) {
+        sType, pNext[chain], flags, pLayer,
+    }
+    VkObjectTableCreateInfoNVX(s) {
+        sType, pNext[chain], objectCount, pObjectEntryTypes[s.objectCount],
+        pObjectEntryCounts[s.objectCount], pObjectEntryUsageFlags[s.objectCount],
+        maxUniformBuffersPerDescriptor, maxStorageBuffersPerDescriptor,
+        maxStorageImagesPerDescriptor, maxSampledImagesPerDescriptor, maxPipelineLayouts,
+    }
+    VkPhysicalDeviceDiscardRectanglePropertiesEXT(s) {
+        sType, pNext[chain], maxDiscardRectangles,
+    }
+    VkPhysicalDeviceDriverPropertiesKHR(s) {
+        sType, pNext[chain], driverID, driverName, driverInfo, conformanceVersion,
+    }
+    VkPhysicalDeviceExternalBufferInfoKHX(s) {
+        sType, pNext[chain], flags, usage, handleType,
+    }
+    VkPhysicalDeviceExternalFenceInfo(s) {
+        sType, pNext[chain], handleType,
+    }
+    VkPhysicalDeviceExternalImageFormatInfoKHX(s) {
+        sType, pNext[chain], handleType,
+    }
+    VkPhysicalDeviceExternalSemaphoreInfoKHX(s) {
+        sType, pNext[chain], handleType,
+    }
+    VkPhysicalDeviceFeatures2KHR(s) {
+        sType, pNext[chain], features,
+    }
+    VkPhysicalDeviceGroupPropertiesKHX(s) {
+        sType, pNext[chain], physicalDeviceCount, physicalDevices, subsetAllocation,
+    }
+    VkPhysicalDeviceIDPropertiesKHX(s) {
+        sType, pNext[chain], deviceUUID, driverUUID, deviceLUID, deviceLUIDValid,
+    }
+    VkPhysicalDeviceImageFormatInfo2KHR(s) {
+        sType, pNext[chain], format, type_, tiling, usage, flags,
+    }
+    VkPhysicalDeviceImageFormatInfo2KHX(s) {
+        sType, pNext[chain], format, type_, tiling, usage, flags,
+    }
+    VkPhysicalDeviceImagelessFramebufferFeaturesKHR(s) {
+        sType, pNext[chain], imagelessFramebuffer,
+    }
+    VkPhysicalDeviceMemoryProperties2KHR(s) {
+        sType, pNext[chain], memoryProperties,
+    }
+    VkPhysicalDeviceMultiviewFeaturesKHX(s) {
+        sType, pNext[chain], multiview, multiviewGeometryShader, multiviewTessellationShader,
+    }
+    VkPhysicalDeviceMultiviewPerViewAttributesPropertiesNVX(s) {
+        sType, pNext[chain], perViewPositionAllComponents,
+    }
+    VkPhysicalDeviceMultiviewPropertiesKHX(s) {
+        sType, pNext[chain], maxMultiviewViewCount, maxMultiviewInstanceIndex,
+    }
+    VkPhysicalDevicePortabilitySubsetFeaturesKHR(s) {
+        sType, pNext[chain], constantAlphaColorBlendFactors, events,
+        imageViewFormatReinterpretation, imageViewFormatSwizzle, imageView2DOn3DImage,
+        multisampleArrayImage, mutableComparisonSamplers, pointPolygons, samplerMipLodBias,
+        separateStencilMaskRef, shaderSampleRateInterpolationFunctions, tessellationIsolines,
+        tessellationPointMode, triangleFans, vertexAttributeAccessBeyondStride,
+    }
+    VkPhysicalDevicePortabilitySubsetPropertiesKHR(s) {
+        sType, pNext[chain], minVertexInputBindingStrideAlignment,
+    }
+    VkPhysicalDeviceProperties2KHR(s) {
+        sType, pNext[chain], properties,
+    }
+    VkPhysicalDeviceProperties2KHX(s) {
+        sType, pNext[chain], properties,
+    }
+    VkPhysicalDevicePushDescriptorPropertiesKHR(s) {
+        sType, pNext[chain], maxPushDescriptors,
+    }
+    VkPhysicalDeviceSparseImageFormatInfo2KHR(s) {
+        sType, pNext[chain], format, type_, samples, usage, tiling,
+    }
+    VkPhysicalDeviceSurfaceInfo2KHR(s) {
+        sType, pNext[chain], surface,
+    }
+    VkPhysicalDeviceVulkan11Features(s) {
+        sType, pNext[chain], storageBuffer16BitAccess, uniformAndStorageBuffer16BitAccess,
+        storagePushConstant16, storageInputOutput16, multiview, multiviewGeometryShader,
+        multiviewTessellationShader, variablePointersStorageBuffer, variablePointers,
+        protectedMemory, samplerYcbcrConversion, shaderDrawParameters,
+    }
+    VkPhysicalDeviceVulkan11Properties(s) {
+        sType, pNext[chain], deviceUUID, driverUUID, deviceLUID, deviceNodeMask, deviceLUIDValid,
+        subgroupSize, subgroupSupportedStages, subgroupSupportedOperations,
+        subgroupQuadOperationsInAllStages, pointClippingBehavior, maxMultiviewViewCount,
+        maxMultiviewInstanceIndex, protectedNoFault, maxPerSetDescriptors, maxMemoryAllocationSize,
+    }
+    VkPipelineCacheCreateInfo(s) {
+        sType, pNext[chain], flags, initialDataSize, pInitialData[s.initialDataSize],
+    }
+    VkPipelineColorBlendStateCreateInfo(s) {
+        sType, pNext[chain], flags, logicOpEnable, logicOp, attachmentCount,
+        pAttachments[s.attachmentCount], blendConstants,
+    }
+    VkPipelineDepthStencilStateCreateInfo(s) {
+        sType, pNext[chain], flags, depthTestEnable, depthWriteEnable, depthCompareOp,
+        depthBoundsTestEnable, stencilTestEnable, front, back, minDepthBounds, maxDepthBounds,
+    }
+    VkPipelineDiscardRectangleStateCreateInfoEXT(s) {
+        sType, pNext[chain], flags, discardRectangleMode, discardRectangleCount,
+        pDiscardRectangles[s.discardRectangleCount],
+    }
+    VkPipelineDynamicStateCreateInfo(s) {
+        sType, pNext[chain], flags, dynamicStateCount, pDynamicStates[s.dynamicStateCount],
+    }
+    VkPipelineInputAssemblyStateCreateInfo(s) {
+        sType, pNext[chain], flags, topology, primitiveRestartEnable,
+    }
+    VkPipelineLayoutCreateInfo(s) {
+        sType, pNext[chain], flags, setLayoutCount, pSetLayouts[s.setLayoutCount],
+        pushConstantRangeCount, pPushConstantRanges[s.pushConstantRangeCount],
+    }
+    VkPipelineMultisampleStateCreateInfo(s) {
+        sType, pNext[chain], flags, rasterizationSamples, sampleShadingEnable, minSampleShading,
+        pSampleMask[(s.rasterizationSamples as u32 + 31) / 32], alphaToCoverageEnable,
+        alphaToOneEnable,
+    }
+    VkPipelineRasterizationStateCreateInfo(s) {
+        sType, pNext[chain], flags, depthClampEnable, rasterizerDiscardEnable, polygonMode,
+        cullMode, frontFace, depthBiasEnable, depthBiasConstantFactor, depthBiasClamp,
+        depthBiasSlopeFactor, lineWidth,
+    }
+    VkPipelineRasterizationStateRasterizationOrderAMD(s) {
+        sType, pNext[chain], rasterizationOrder,
+    }
+    VkPipelineShaderStageCreateInfo(s) {
+        sType, pNext[chain], flags, stage, module, pName, pSpecializationInfo,
+    }
+    VkPipelineTessellationStateCreateInfo(s) {
+        sType, pNext[chain], flags, patchControlPoints,
+    }
+    VkPipelineVertexInputStateCreateInfo(s) {
+        sType, pNext[chain], flags, vertexBindingDescriptionCount,
+        pVertexBindingDescriptions[s.vertexBindingDescriptionCount],
+        vertexAttributeDescriptionCount,
+        pVertexAttributeDescriptions[s.vertexAttributeDescriptionCount],
+    }
+    VkPipelineViewportStateCreateInfo(s) {
+        sType, pNext[chain], flags, viewportCount, pViewports[s.viewportCount], scissorCount,
+        pScissors[s.scissorCount],
+    }
+    VkPipelineViewportSwizzleStateCreateInfoNV(s) {
+        sType, pNext[chain], flags, viewportCount, pViewportSwizzles[s.viewportCount],
+    }
+    VkPipelineViewportWScalingStateCreateInfoNV(s) {
+        sType, pNext[chain], viewportWScalingEnable, viewportCount,
+        pViewportWScalings[s.viewportCount],
+    }
+    VkPresentInfoKHR(s) {
+        sType, pNext[chain], waitSemaphoreCount, pWaitSemaphores[s.waitSemaphoreCount],
+        swapchainCount, pSwapchains[s.swapchainCount], pImageIndices[s.swapchainCount],
+        pResults[s.swapchainCount],
+    }
+    VkQueryPoolCreateInfo(s) {
+        sType, pNext[chain], flags, queryType, queryCount, pipelineStatistics,
+    }
+    VkQueueFamilyProperties2KHR(s) {
+        sType, pNext[chain], queueFamilyProperties,
+    }
+    VkRenderPassAttachmentBeginInfoKHR(s) {
+        sType, pNext[chain], attachmentCount, pAttachments[s.attachmentCount],
+    }
+    VkRenderPassBeginInfo(s) {
+        sType, pNext[chain], renderPass, framebuffer, renderArea, clearValueCount,
+        pClearValues[s.clearValueCount],
+    }
+    VkRenderPassCreateInfo(s) {
+        sType, pNext[chain], flags, attachmentCount, pAttachments[s.attachmentCount], subpassCount,
+        pSubpasses[s.subpassCount], dependencyCount, pDependencies[s.dependencyCount],
+    }
+    VkRenderPassMultiviewCreateInfoKHX(s) {
+        sType, pNext[chain], subpassCount, pViewMasks[s.subpassCount], dependencyCount,
+        pViewOffsets[s.dependencyCount], correlationMaskCount,
+        pCorrelationMasks[s.correlationMaskCount],
+    }
+    VkSamplerCreateInfo(s) {
+        sType, pNext[chain], flags, magFilter, minFilter, mipmapMode, addressModeU, addressModeV,
+        addressModeW, mipLodBias, anisotropyEnable, maxAnisotropy, compareEnable, compareOp, minLod,
+        maxLod, borderColor, unnormalizedCoordinates,
+    }
+    VkSemaphoreCreateInfo(s) {
+        sType, pNext[chain], flags,
+    }
+    VkShaderModuleCreateInfo(s) {
+        sType, pNext[chain], flags, codeSize, pCode[s.codeSize / 4],
+    }
+    VkSparseBufferMemoryBindInfo(s) {
+        buffer, bindCount, pBinds[s.bindCount],
+    }
+    VkSparseImageFormatProperties2KHR(s) {
+        sType, pNext[chain], properties,
+    }
+    VkSparseImageMemoryBind(s) {
+        subresource, offset, extent, memory, memoryOffset, flags,
+    }
+    VkSparseImageMemoryBindInfo(s) {
+        image, bindCount, pBinds[s.bindCount],
+    }
+    VkSparseImageMemoryRequirements2KHR(s) {
+        sType, pNext[chain], memoryRequirements,
+    }
+    VkSparseImageOpaqueMemoryBindInfo(s) {
+        image, bindCount, pBinds[s.bindCount],
+    }
+    VkSparseMemoryBind(s) {
+        resourceOffset, size, memory, memoryOffset, flags,
+    }
+    VkSpecializationInfo(s) {
+        mapEntryCount, pMapEntries[s.mapEntryCount], dataSize, pData[s.dataSize],
+    }
+    VkSubmitInfo(s) {
+        sType, pNext[chain], waitSemaphoreCount, pWaitSemaphores[s.waitSemaphoreCount],
+        pWaitDstStageMask[s.waitSemaphoreCount], commandBufferCount,
+        pCommandBuffers[s.commandBufferCount], signalSemaphoreCount,
+        pSignalSemaphores[s.signalSemaphoreCount],
+    }
+    VkSubpassDescription(s) {
+        flags, pipelineBindPoint, inputAttachmentCount, pInputAttachments[s.inputAttachmentCount],
+        colorAttachmentCount, pColorAttachments[s.colorAttachmentCount],
+        pResolveAttachments[s.colorAttachmentCount], pDepthStencilAttachment,
+        preserveAttachmentCount, pPreserveAttachments[s.preserveAttachmentCount],
+    }
+    VkSurfaceCapabilities2EXT(s) {
+        sType, pNext[chain], minImageCount, maxImageCount, currentExtent, minImageExtent,
+        maxImageExtent, maxImageArrayLayers, supportedTransforms, currentTransform,
+        supportedCompositeAlpha, supportedUsageFlags, supportedSurfaceCounters,
+    }
+    VkSurfaceCapabilities2KHR(s) {
+        sType, pNext[chain], surfaceCapabilities,
+    }
+    VkSurfaceFormat2KHR(s) {
+        sType, pNext[chain], surfaceFormat,
+    }
+    VkSwapchainCounterCreateInfoEXT(s) {
+        sType, pNext[chain], surfaceCounters,
+    }
+    VkSwapchainCreateInfoKHR(s) {
+        sType, pNext[chain], flags, surface, minImageCount, imageFormat, imageColorSpace,
+        imageExtent, imageArrayLayers, imageUsage, imageSharingMode, queueFamilyIndexCount,
+        pQueueFamilyIndices[if is_concurrent(s.imageSharingMode) { s.queueFamilyIndexCount } else {
+        0 }], preTransform, compositeAlpha, presentMode, clipped, oldSwapchain,
+    }
+    VkValidationFlagsEXT(s) {
+        sType, pNext[chain], disabledValidationCheckCount,
+        pDisabledValidationChecks[s.disabledValidationCheckCount],
+    }
+    VkWaylandSurfaceCreateInfoKHR(s) {
+        sType, pNext[chain], flags, display, surface,
+    }
+    VkWin32SurfaceCreateInfoKHR(s) {
+        sType, pNext[chain], flags, hinstance, hwnd,
+    }
+    VkWriteDescriptorSet(s) {
+        sType, pNext[chain], dstSet, dstBinding, dstArrayElement, descriptorCount, descriptorType,
+        pImageInfo[if has_image_info(s.descriptorType) { s.descriptorCount } else { 0 }],
+        pBufferInfo[if has_buffer_info(s.descriptorType) { s.descriptorCount } else { 0 }],
+        pTexelBufferView[if has_texel_buffer_view(s.descriptorType) { s.descriptorCount } else { 0
+        }],
+    }
+    VkXcbSurfaceCreateInfoKHR(s) {
+        sType, pNext[chain], flags, connection, window,
+    }
+    VkXlibSurfaceCreateInfoKHR(s) {
+        sType, pNext[chain], flags, dpy, window,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handle::Handle;
+
+    /// Reads the values written by `write`, as the replay does with a record.
+    fn round_trip(write: impl FnOnce(&mut Writer)) -> Reader {
+        let mut writer = Writer::default();
+        write(&mut writer);
+        let mut reader = Reader::default();
+        reader.load(writer.take());
+        reader
+    }
+
+    #[test]
+    fn values() {
+        let mut reader = round_trip(|writer| unsafe {
+            7u32.write(writer);
+            u64::max_value().write(writer);
+            (-1.5f32).write(writer);
+            VkFormat::VK_FORMAT_R8G8B8A8_UNORM.write(writer);
+            writer.string("vkCreateInstance");
+        });
+        unsafe {
+            assert_eq!(u32::read(&mut reader), 7);
+            assert_eq!(u64::read(&mut reader), u64::max_value());
+            assert_eq!(f32::read(&mut reader), -1.5);
+            assert_eq!(
+                VkFormat::read(&mut reader),
+                VkFormat::VK_FORMAT_R8G8B8A8_UNORM
+            );
+        }
+        assert_eq!(reader.string(), "vkCreateInstance");
+        assert_eq!(reader.missing, 0);
+    }
+
+    #[test]
+    fn truncated() {
+        let mut reader = round_trip(|writer| writer.u32(1));
+        assert_eq!(reader.try_bytes(8), None);
+        assert_eq!(reader.missing, 4);
+        assert_eq!(reader.u64(), 0);
+        assert_eq!(reader.missing, 12);
+    }
+
+    #[test]
+    fn pointers() {
+        let values = [1u32, 2, 3];
+        let name = b"gfx\0";
+        let mut reader = round_trip(|writer| unsafe {
+            values.as_ptr().write_array(writer, Some(values.len()));
+            Traced::write(&(name.as_ptr() as *const c_char), writer);
+            Traced::write(&ptr::null::<u32>(), writer);
+        });
+        unsafe {
+            let read: *const u32 = Traced::read(&mut reader);
+            assert_eq!(slice::from_raw_parts(read, 3), values);
+            let read: *const c_char = Traced::read(&mut reader);
+            assert_eq!(CStr::from_ptr(read).to_bytes(), b"gfx");
+            let read: *const u32 = Traced::read(&mut reader);
+            assert!(read.is_null());
+        }
+    }
+
+    #[test]
+    fn structures() {
+        let unknown = VkBaseStruct {
+            sType: VkStructureType::VK_STRUCTURE_TYPE_LOADER_INSTANCE_CREATE_INFO,
+            pNext: ptr::null(),
+        };
+        let engine = VkApplicationInfo {
+            sType: VkStructureType::VK_STRUCTURE_TYPE_APPLICATION_INFO,
+            pNext: &unknown as *const _ as *const c_void,
+            pApplicationName: ptr::null(),
+            applicationVersion: 0,
+            pEngineName: b"engine\0".as_ptr() as *const c_char,
+            engineVersion: 2,
+            apiVersion: 0,
+        };
+        // The unknown structures of a chain are skipped.
+        let unknown = VkBaseStruct {
+            sType: VkStructureType::VK_STRUCTURE_TYPE_LOADER_INSTANCE_CREATE_INFO,
+            pNext: &engine as *const _ as *const c_void,
+        };
+        let info = VkApplicationInfo {
+            sType: VkStructureType::VK_STRUCTURE_TYPE_APPLICATION_INFO,
+            pNext: &unknown as *const _ as *const c_void,
+            pApplicationName: b"application\0".as_ptr() as *const c_char,
+            applicationVersion: 1,
+            pEngineName: ptr::null(),
+            engineVersion: 0,
+            apiVersion: VK_API_VERSION_1_0,
+        };
+        let mut reader = round_trip(|writer| unsafe { info.write(writer) });
+        unsafe {
+            let read = VkApplicationInfo::read(&mut reader);
+            assert_eq!(
+                CStr::from_ptr(read.pApplicationName).to_bytes(),
+                b"application"
+            );
+            assert_eq!(read.applicationVersion, 1);
+            assert!(read.pEngineName.is_null());
+            assert_eq!(read.apiVersion, VK_API_VERSION_1_0);
+
+            let next = &*(read.pNext as *const VkApplicationInfo);
+            assert_eq!(
+                next.sType,
+                VkStructureType::VK_STRUCTURE_TYPE_APPLICATION_INFO
+            );
+            assert_eq!(CStr::from_ptr(next.pEngineName).to_bytes(), b"engine");
+            assert_eq!(next.engineVersion, 2);
+            assert!(next.pNext.is_null());
+        }
+        assert_eq!(reader.missing, 0);
+    }
+
+    #[test]
+    fn handles() {
+        let mut captured = Handle::<u32>::from_raw(0x10);
+        let output: *mut Handle<u32> = &mut captured;
+        let mut writer = Writer::default();
+        let (call, result) = unsafe {
+            Traced::write(&output, &mut writer);
+            let call = writer.take();
+            output.write_output(&mut writer, None);
+            (call, writer.take())
+        };
+
+        // The replay creates its own object, bound to the captured one
+        // once the return record is read.
+        let mut reader = Reader::default();
+        reader.load(call);
+        let replayed: *mut Handle<u32> = unsafe { Traced::read(&mut reader) };
+        unsafe { *replayed = Handle::from_raw(0x20) };
+        reader.load(result);
+        reader.read_outputs();
+        reader.release();
+
+        let mut writer = Writer::default();
+        unsafe {
+            Handle::<u32>::from_raw(0x10).write(&mut writer);
+            Handle::<u32>::from_raw(0x30).write(&mut writer);
+        }
+        reader.load(writer.take());
+        unsafe {
+            assert_eq!(Handle::<u32>::read(&mut reader).as_raw(), 0x20);
+            assert_eq!(Handle::<u32>::read(&mut reader).as_raw(), 0);
+        }
+        assert_eq!(reader.unknown_handles, 1);
+    }
+}
@@ -0,0 +1,480 @@
+//! Opt-in validation of the API usage.
+//!
+//! It's enabled by the `validation` feature, or at run-time by the `validation`
+//! setting or `GFX_VALIDATION=1`. The errors are reported to the debug messengers and
+//! report callbacks, and the offending call is skipped instead of crashing
+//! somewhere deep in the backend.
+//!
+//! The state is shared by all the backends, the objects being told apart
+//! by their raw handles.
+//!
+//! Only the layout parameters of the commands are validated: the current
+//! layouts of the image subresources aren't tracked through the barriers,
+//! render passes and submissions.
+
+use crate::{
+    debug,
+    ffi::{
+        VkCommandBufferUsageFlagBits, VkCommandBufferUsageFlags, VkDebugReportObjectTypeEXT,
+        VkDebugUtilsMessageSeverityFlagBitsEXT, VkDebugUtilsMessageTypeFlagBitsEXT, VkImageLayout,
+    },
+    handle::{self, DispatchHandle, Handle},
+};
+
+use hal::{
+    format::{Aspects, Format},
+    image::{FramebufferAttachment, Usage},
+};
+use lazy_static::lazy_static;
+use log::error;
+
+use std::{collections::HashMap, sync::Mutex};
+
+/// Message ID of the validation errors, also used as the `log` target.
+pub const MESSAGE_ID: &str = "gfx-portability-validation";
+
+lazy_static! {
+    static ref ENABLED: bool = cfg!(feature = "validation") || crate::config::current().validation;
+    /// States of the command buffers, keyed by their raw handles.
+    static ref COMMAND_BUFFERS: Mutex<HashMap<u64, CommandBufferState>> =
+        Mutex::new(HashMap::new());
+    /// Attachment formats of the render passes.
+    static ref RENDER_PASSES: Mutex<HashMap<u64, Vec<Option<Format>>>> =
+        Mutex::new(HashMap::new());
+    /// Attachments of the framebuffers.
+    static ref FRAMEBUFFERS: Mutex<HashMap<u64, Vec<FramebufferAttachment>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Lifecycle of a command buffer, as described by the spec.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CommandBufferState {
+    Initial,
+    Recording { one_time: bool },
+    Executable { one_time: bool },
+    Invalid,
+}
+
+impl CommandBufferState {
+    fn begin(self, one_time: bool) -> Result<Self, String> {
+        match self {
+            CommandBufferState::Recording { .. } => {
+                Err("vkBeginCommandBuffer: command buffer is already recording".to_string())
+            }
+            _ => Ok(CommandBufferState::Recording { one_time }),
+        }
+    }
+
+    fn end(self) -> Result<Self, String> {
+        match self {
+            CommandBufferState::Recording { one_time } => {
+                Ok(CommandBufferState::Executable { one_time })
+            }
+            other => Err(format!(
+                "vkEndCommandBuffer: command buffer is not recording, its state is {:?}",
+                other
+            )),
+        }
+    }
+
+    fn record(self, command: &str) -> Result<Self, String> {
+        match self {
+            CommandBufferState::Recording { .. } => Ok(self),
+            other => Err(format!(
+                "{}: command buffer is not recording, its state is {:?}",
+                command, other
+            )),
+        }
+    }
+
+    /// One-time command buffers become invalid once submitted.
+    fn submit(self, command: &str) -> Result<Self, String> {
+        match self {
+            CommandBufferState::Executable { one_time: true } => Ok(CommandBufferState::Invalid),
+            CommandBufferState::Executable { one_time: false } => Ok(self),
+            other => Err(format!(
+                "{}: command buffer is not executable, its state is {:?}",
+                command, other
+            )),
+        }
+    }
+}
+
+pub fn is_enabled() -> bool {
+    *ENABLED
+}
+
+/// Reports a validation error related to the given object.
+pub fn error(object_type: VkDebugReportObjectTypeEXT, object: u64, text: &str) {
+    error!(target: MESSAGE_ID, "{}", text);
+    debug::message(
+        VkDebugUtilsMessageSeverityFlagBitsEXT::VK_DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT,
+        VkDebugUtilsMessageTypeFlagBitsEXT::VK_DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT as _,
+        MESSAGE_ID,
+        text,
+        Some((object_type, object)),
+    );
+}
+
+/// Checks that the object with the given raw handle hasn't been destroyed.
+pub fn check_alive(object_type: VkDebugReportObjectTypeEXT, object: u64, command: &str) -> bool {
+    if !is_enabled() || handle::is_alive(object) {
+        return true;
+    }
+    error(
+        object_type,
+        object,
+        &format!("{}: {:?} {:#x} is not alive", command, object_type, object),
+    );
+    false
+}
+
+fn command_buffer_error<T>(command_buffer: DispatchHandle<T>, text: &str) {
+    error(
+        VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT,
+        command_buffer.as_raw(),
+        text,
+    );
+}
+
+pub fn register_command_buffer<T>(command_buffer: DispatchHandle<T>) {
+    if is_enabled() {
+        COMMAND_BUFFERS
+            .lock()
+            .unwrap()
+            .insert(command_buffer.as_raw(), CommandBufferState::Initial);
+    }
+}
+
+pub fn unregister_command_buffer<T>(command_buffer: DispatchHandle<T>) {
+    if is_enabled() {
+        COMMAND_BUFFERS
+            .lock()
+            .unwrap()
+            .remove(&command_buffer.as_raw());
+    }
+}
+
+/// Applies a state transition to the command buffer, if it's allowed by `transition`.
+/// Otherwise, reports the error returned by `transition` and leaves the state as is.
+fn transition_command_buffer<T>(
+    command_buffer: DispatchHandle<T>,
+    transition: impl FnOnce(CommandBufferState) -> Result<CommandBufferState, String>,
+) -> bool {
+    if !is_enabled() {
+        return true;
+    }
+    let result = {
+        let mut states = COMMAND_BUFFERS.lock().unwrap();
+        match states.get_mut(&command_buffer.as_raw()) {
+            Some(state) => transition(*state).map(|new_state| *state = new_state),
+            None => Err(format!(
+                "Command buffer {:#x} is not allocated",
+                command_buffer.as_raw()
+            )),
+        }
+    };
+    // The messengers may call back into us, so the lock isn't held while reporting.
+    match result {
+        Ok(()) => true,
+        Err(text) => {
+            command_buffer_error(command_buffer, &text);
+            false
+        }
+    }
+}
+
+pub fn begin_command_buffer<T>(
+    command_buffer: DispatchHandle<T>,
+    flags: VkCommandBufferUsageFlags,
+) -> bool {
+    let one_time = flags
+        & VkCommandBufferUsageFlagBits::VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT as u32
+        != 0;
+    transition_command_buffer(command_buffer, |state| state.begin(one_time))
+}
+
+pub fn end_command_buffer<T>(command_buffer: DispatchHandle<T>) -> bool {
+    transition_command_buffer(command_buffer, CommandBufferState::end)
+}
+
+pub fn reset_command_buffer<T>(command_buffer: DispatchHandle<T>) {
+    transition_command_buffer(command_buffer, |_| Ok(CommandBufferState::Initial));
+}
+
+/// Checks that commands can be recorded into the command buffer.
+pub fn check_recording<T>(command_buffer: DispatchHandle<T>, command: &str) -> bool {
+    transition_command_buffer(command_buffer, |state| state.record(command))
+}
+
+/// Checks that the command buffer can be submitted. One-time command buffers
+/// become invalid after that.
+pub fn submit_command_buffer<T>(command_buffer: DispatchHandle<T>, command: &str) -> bool {
+    transition_command_buffer(command_buffer, |state| state.submit(command))
+}
+
+/// Images of a backend, exposing the usage they were created with.
+pub trait ImageUsage {
+    fn usage(&self) -> Usage;
+}
+
+/// Checks that the image was created with the `usage` required by the command,
+/// and that the `layout` the command is given is one of the `layouts` allowed there.
+pub fn check_image<T: ImageUsage>(
+    image: Handle<T>,
+    layout: VkImageLayout,
+    usage: Usage,
+    layouts: &[VkImageLayout],
+    command: &str,
+) -> bool {
+    if !is_enabled() {
+        return true;
+    }
+    let object_type = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT;
+    if !check_alive(object_type, image.as_raw(), command) {
+        return false;
+    }
+    let mut valid = true;
+    let image_usage = image.usage();
+    if !image_usage.contains(usage) {
+        error(
+            object_type,
+            image.as_raw(),
+            &format!(
+                "{}: image usage {:?} doesn't include {:?}",
+                command, image_usage, usage
+            ),
+        );
+        valid = false;
+    }
+    if !layouts.contains(&layout) {
+        error(
+            object_type,
+            image.as_raw(),
+            &format!(
+                "{}: image layout {:?} is not one of {:?}",
+                command, layout, layouts
+            ),
+        );
+        valid = false;
+    }
+    valid
+}
+
+/// Checks an image used as the source of a transfer command.
+pub fn check_transfer_src<T: ImageUsage>(
+    image: Handle<T>,
+    layout: VkImageLayout,
+    command: &str,
+) -> bool {
+    check_image(
+        image,
+        layout,
+        Usage::TRANSFER_SRC,
+        &[
+            VkImageLayout::VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL,
+            VkImageLayout::VK_IMAGE_LAYOUT_GENERAL,
+        ],
+        command,
+    )
+}
+
+/// Checks an image used as the destination of a transfer command.
+pub fn check_transfer_dst<T: ImageUsage>(
+    image: Handle<T>,
+    layout: VkImageLayout,
+    command: &str,
+) -> bool {
+    check_image(
+        image,
+        layout,
+        Usage::TRANSFER_DST,
+        &[
+            VkImageLayout::VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL,
+            VkImageLayout::VK_IMAGE_LAYOUT_GENERAL,
+        ],
+        command,
+    )
+}
+
+pub fn register_render_pass<T>(
+    render_pass: Handle<T>,
+    formats: impl Iterator<Item = Option<Format>>,
+) {
+    if is_enabled() {
+        RENDER_PASSES
+            .lock()
+            .unwrap()
+            .insert(render_pass.as_raw(), formats.collect());
+    }
+}
+
+pub fn unregister_render_pass<T>(render_pass: Handle<T>) {
+    if is_enabled() {
+        RENDER_PASSES.lock().unwrap().remove(&render_pass.as_raw());
+    }
+}
+
+pub fn register_framebuffer<T>(framebuffer: Handle<T>, attachments: Vec<FramebufferAttachment>) {
+    if is_enabled() {
+        FRAMEBUFFERS
+            .lock()
+            .unwrap()
+            .insert(framebuffer.as_raw(), attachments);
+    }
+}
+
+pub fn unregister_framebuffer<T>(framebuffer: Handle<T>) {
+    if is_enabled() {
+        FRAMEBUFFERS.lock().unwrap().remove(&framebuffer.as_raw());
+    }
+}
+
+/// Checks that the attachments are compatible with the render pass:
+/// their count and formats have to match, and their usage has to allow
+/// them to be bound as color or depth-stencil attachments.
+pub fn check_framebuffer<T>(
+    render_pass: Handle<T>,
+    attachments: &[FramebufferAttachment],
+    command: &str,
+) -> bool {
+    if !is_enabled() {
+        return true;
+    }
+    let object_type = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_RENDER_PASS_EXT;
+    if !check_alive(object_type, render_pass.as_raw(), command) {
+        return false;
+    }
+    let formats = match RENDER_PASSES.lock().unwrap().get(&render_pass.as_raw()) {
+        Some(formats) => formats.clone(),
+        None => return true,
+    };
+
+    let mut errors = Vec::new();
+    if formats.len() != attachments.len() {
+        errors.push(format!(
+            "{}: render pass has {} attachments, but {} are provided",
+            command,
+            formats.len(),
+            attachments.len()
+        ));
+    }
+    for (index, (format, attachment)) in formats.iter().zip(attachments).enumerate() {
+        let format = match *format {
+            Some(format) => format,
+            None => continue,
+        };
+        if format != attachment.format {
+            errors.push(format!(
+                "{}: attachment {} has format {:?}, but the render pass expects {:?}",
+                command, index, attachment.format, format
+            ));
+        }
+        let usage = if format.surface_desc().aspects.contains(Aspects::COLOR) {
+            Usage::COLOR_ATTACHMENT
+        } else {
+            Usage::DEPTH_STENCIL_ATTACHMENT
+        };
+        if !attachment.usage.contains(usage) {
+            errors.push(format!(
+                "{}: attachment {} usage {:?} doesn't include {:?}",
+                command, index, attachment.usage, usage
+            ));
+        }
+    }
+
+    for text in &errors {
+        error(object_type, render_pass.as_raw(), text);
+    }
+    errors.is_empty()
+}
+
+/// Checks that the framebuffer is compatible with the render pass it's used with.
+pub fn check_render_pass_begin<R, F>(
+    render_pass: Handle<R>,
+    framebuffer: Handle<F>,
+    command: &str,
+) -> bool {
+    if !is_enabled() {
+        return true;
+    }
+    let object_type = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_FRAMEBUFFER_EXT;
+    if !check_alive(object_type, framebuffer.as_raw(), command) {
+        return false;
+    }
+    let attachments = FRAMEBUFFERS
+        .lock()
+        .unwrap()
+        .get(&framebuffer.as_raw())
+        .cloned();
+    match attachments {
+        Some(attachments) => check_framebuffer(render_pass, &attachments, command),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandBufferState::{self, *};
+
+    type Step = fn(CommandBufferState) -> Result<CommandBufferState, String>;
+
+    fn run(state: CommandBufferState, steps: &[Step]) -> Result<CommandBufferState, String> {
+        steps.iter().try_fold(state, |state, step| step(state))
+    }
+
+    fn begin(state: CommandBufferState) -> Result<CommandBufferState, String> {
+        state.begin(false)
+    }
+    fn begin_one_time(state: CommandBufferState) -> Result<CommandBufferState, String> {
+        state.begin(true)
+    }
+    fn draw(state: CommandBufferState) -> Result<CommandBufferState, String> {
+        state.record("vkCmdDraw")
+    }
+    fn end(state: CommandBufferState) -> Result<CommandBufferState, String> {
+        state.end()
+    }
+    fn submit(state: CommandBufferState) -> Result<CommandBufferState, String> {
+        state.submit("vkQueueSubmit")
+    }
+
+    #[test]
+    fn lifecycle() {
+        let steps = [begin, draw, end, submit, submit];
+        assert_eq!(run(Initial, &steps), Ok(Executable { one_time: false }));
+        let steps = [begin_one_time, draw, end, submit];
+        assert_eq!(run(Initial, &steps), Ok(Invalid));
+        // Beginning again resets the command buffer implicitly.
+        assert_eq!(run(Invalid, &[begin]), Ok(Recording { one_time: false }));
+        assert_eq!(
+            run(Executable { one_time: true }, &[begin]),
+            Ok(Recording { one_time: false })
+        );
+    }
+
+    #[test]
+    fn invalid_transitions() {
+        assert_eq!(
+            run(Initial, &[begin, begin]),
+            Err("vkBeginCommandBuffer: command buffer is already recording".to_string())
+        );
+        assert_eq!(
+            run(Initial, &[draw]),
+            Err("vkCmdDraw: command buffer is not recording, its state is Initial".to_string())
+        );
+        assert_eq!(
+            run(Initial, &[end]),
+            Err("vkEndCommandBuffer: command buffer is not recording, its state is Initial".into())
+        );
+        assert_eq!(
+            run(Initial, &[begin, submit]),
+            Err(format!(
+                "vkQueueSubmit: command buffer is not executable, its state is {:?}",
+                Recording { one_time: false }
+            ))
+        );
+        assert!(run(Initial, &[begin_one_time, end, submit, submit]).is_err());
+        assert!(run(Invalid, &[draw]).is_err());
+    }
+}
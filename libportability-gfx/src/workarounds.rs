@@ -0,0 +1,76 @@
+//! Query pools ignored by the `disable_occlusion_queries` workaround,
+//! see `config::Workarounds`.
+//!
+//! Their queries are never begun on the backend, and read back as if a
+//! sample passed, with the availability set.
+
+use crate::ffi::{VkQueryResultFlagBits, VkQueryResultFlags};
+
+use fxhash::FxHashSet;
+use lazy_static::lazy_static;
+
+use std::{
+    ptr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        RwLock,
+    },
+};
+
+/// Number of samples reported for every ignored query, enough for the
+/// applications only checking if anything is visible.
+const PASSED: u64 = 1;
+
+lazy_static! {
+    static ref IGNORED_POOLS: RwLock<FxHashSet<u64>> = RwLock::new(Default::default());
+}
+/// Avoids the lock when no pool was ever ignored.
+static ANY_IGNORED: AtomicBool = AtomicBool::new(false);
+
+pub fn ignore_query_pool(pool: u64) {
+    ANY_IGNORED.store(true, Ordering::Relaxed);
+    IGNORED_POOLS.write().unwrap().insert(pool);
+}
+
+pub fn forget_query_pool(pool: u64) {
+    if ANY_IGNORED.load(Ordering::Relaxed) {
+        IGNORED_POOLS.write().unwrap().remove(&pool);
+    }
+}
+
+pub fn is_ignored(pool: u64) -> bool {
+    ANY_IGNORED.load(Ordering::Relaxed) && IGNORED_POOLS.read().unwrap().contains(&pool)
+}
+
+/// Result of an ignored query in the layout given by the flags,
+/// availability included.
+pub fn result(flags: VkQueryResultFlags) -> Vec<u8> {
+    let is_64 = flags & VkQueryResultFlagBits::VK_QUERY_RESULT_64_BIT as u32 != 0;
+    let mut values = vec![PASSED];
+    if flags & VkQueryResultFlagBits::VK_QUERY_RESULT_WITH_AVAILABILITY_BIT as u32 != 0 {
+        values.push(1);
+    }
+    values
+        .into_iter()
+        .flat_map(|value| {
+            if is_64 {
+                value.to_ne_bytes().to_vec()
+            } else {
+                (value as u32).to_ne_bytes().to_vec()
+            }
+        })
+        .collect()
+}
+
+/// Writes the results of ignored queries to the memory of the application.
+pub unsafe fn write_results(
+    data: *mut u8,
+    query_count: u32,
+    stride: usize,
+    flags: VkQueryResultFlags,
+) {
+    let result = result(flags);
+    for i in 0..query_count as usize {
+        ptr::copy_nonoverlapping(result.as_ptr(), data.add(i * stride), result.len());
+    }
+}
@@ -284,6 +284,15 @@ pub unsafe extern "C" fn vkGetPhysicalDeviceWin32PresentationSupportKHR(
     gfxGetPhysicalDeviceWin32PresentationSupportKHR(adapter, queueFamilyIndex)
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceWaylandPresentationSupportKHR(
+    adapter: VkPhysicalDevice,
+    queueFamilyIndex: u32,
+    display: *mut ::std::os::raw::c_void,
+) -> VkBool32 {
+    gfxGetPhysicalDeviceWaylandPresentationSupportKHR(adapter, queueFamilyIndex, display)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn vkCreateSwapchainKHR(
     device: VkDevice,
@@ -341,6 +350,16 @@ pub unsafe extern "C" fn vkCreateMetalSurfaceEXT(
     gfxCreateMetalSurfaceEXT(instance, pCreateInfos, pAllocator, pSurface)
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn vkCreateHeadlessSurfaceEXT(
+    instance: VkInstance,
+    pCreateInfo: *const VkHeadlessSurfaceCreateInfoEXT,
+    pAllocator: *const VkAllocationCallbacks,
+    pSurface: *mut VkSurfaceKHR,
+) -> VkResult {
+    gfxCreateHeadlessSurfaceEXT(instance, pCreateInfo, pAllocator, pSurface)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn vkCreateXcbSurfaceKHR(
     instance: VkInstance,
@@ -351,6 +370,16 @@ pub unsafe extern "C" fn vkCreateXcbSurfaceKHR(
     gfxCreateXcbSurfaceKHR(instance, pCreateInfos, pAllocator, pSurface)
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn vkCreateWaylandSurfaceKHR(
+    instance: VkInstance,
+    pCreateInfos: *const VkWaylandSurfaceCreateInfoKHR,
+    pAllocator: *const VkAllocationCallbacks,
+    pSurface: *mut VkSurfaceKHR,
+) -> VkResult {
+    gfxCreateWaylandSurfaceKHR(instance, pCreateInfos, pAllocator, pSurface)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn vkMapMemory(
     device: VkDevice,
@@ -1633,3 +1662,419 @@ pub unsafe extern "C" fn vkCmdDebugMarkerInsertEXT(
 ) {
     gfxCmdDebugMarkerInsertEXT(commandBuffer, pMarkerInfo)
 }
+#[no_mangle]
+pub unsafe extern "C" fn vkCreateDebugUtilsMessengerEXT(
+    instance: VkInstance,
+    pCreateInfo: *const VkDebugUtilsMessengerCreateInfoEXT,
+    pAllocator: *const VkAllocationCallbacks,
+    pMessenger: *mut VkDebugUtilsMessengerEXT,
+) -> VkResult {
+    gfxCreateDebugUtilsMessengerEXT(instance, pCreateInfo, pAllocator, pMessenger)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkDestroyDebugUtilsMessengerEXT(
+    instance: VkInstance,
+    messenger: VkDebugUtilsMessengerEXT,
+    pAllocator: *const VkAllocationCallbacks,
+) {
+    gfxDestroyDebugUtilsMessengerEXT(instance, messenger, pAllocator)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkSubmitDebugUtilsMessageEXT(
+    instance: VkInstance,
+    messageSeverity: VkDebugUtilsMessageSeverityFlagBitsEXT,
+    messageTypes: VkDebugUtilsMessageTypeFlagsEXT,
+    pCallbackData: *const VkDebugUtilsMessengerCallbackDataEXT,
+) {
+    gfxSubmitDebugUtilsMessageEXT(instance, messageSeverity, messageTypes, pCallbackData)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkSetDebugUtilsObjectNameEXT(
+    gpu: VkDevice,
+    pNameInfo: *const VkDebugUtilsObjectNameInfoEXT,
+) -> VkResult {
+    gfxSetDebugUtilsObjectNameEXT(gpu, pNameInfo)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkSetDebugUtilsObjectTagEXT(
+    gpu: VkDevice,
+    pTagInfo: *const VkDebugUtilsObjectTagInfoEXT,
+) -> VkResult {
+    gfxSetDebugUtilsObjectTagEXT(gpu, pTagInfo)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkQueueBeginDebugUtilsLabelEXT(
+    queue: VkQueue,
+    pLabelInfo: *const VkDebugUtilsLabelEXT,
+) {
+    gfxQueueBeginDebugUtilsLabelEXT(queue, pLabelInfo)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkQueueEndDebugUtilsLabelEXT(queue: VkQueue) {
+    gfxQueueEndDebugUtilsLabelEXT(queue)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkQueueInsertDebugUtilsLabelEXT(
+    queue: VkQueue,
+    pLabelInfo: *const VkDebugUtilsLabelEXT,
+) {
+    gfxQueueInsertDebugUtilsLabelEXT(queue, pLabelInfo)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkCmdBeginDebugUtilsLabelEXT(
+    commandBuffer: VkCommandBuffer,
+    pLabelInfo: *const VkDebugUtilsLabelEXT,
+) {
+    gfxCmdBeginDebugUtilsLabelEXT(commandBuffer, pLabelInfo)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkCmdEndDebugUtilsLabelEXT(commandBuffer: VkCommandBuffer) {
+    gfxCmdEndDebugUtilsLabelEXT(commandBuffer)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkCmdInsertDebugUtilsLabelEXT(
+    commandBuffer: VkCommandBuffer,
+    pLabelInfo: *const VkDebugUtilsLabelEXT,
+) {
+    gfxCmdInsertDebugUtilsLabelEXT(commandBuffer, pLabelInfo)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkCreateDebugReportCallbackEXT(
+    instance: VkInstance,
+    pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT,
+    pAllocator: *const VkAllocationCallbacks,
+    pCallback: *mut VkDebugReportCallbackEXT,
+) -> VkResult {
+    gfxCreateDebugReportCallbackEXT(instance, pCreateInfo, pAllocator, pCallback)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkDestroyDebugReportCallbackEXT(
+    instance: VkInstance,
+    callback: VkDebugReportCallbackEXT,
+    pAllocator: *const VkAllocationCallbacks,
+) {
+    gfxDestroyDebugReportCallbackEXT(instance, callback, pAllocator)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkDebugReportMessageEXT(
+    instance: VkInstance,
+    flags: VkDebugReportFlagsEXT,
+    objectType: VkDebugReportObjectTypeEXT,
+    object: u64,
+    location: usize,
+    messageCode: i32,
+    pLayerPrefix: *const ::std::os::raw::c_char,
+    pMessage: *const ::std::os::raw::c_char,
+) {
+    gfxDebugReportMessageEXT(
+        instance,
+        flags,
+        objectType,
+        object,
+        location,
+        messageCode,
+        pLayerPrefix,
+        pMessage,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetBufferMemoryRequirements2KHR(
+    device: VkDevice,
+    pInfo: *const VkBufferMemoryRequirementsInfo2KHR,
+    pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+) {
+    gfxGetBufferMemoryRequirements2KHR(device, pInfo, pMemoryRequirements)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetImageMemoryRequirements2KHR(
+    device: VkDevice,
+    pInfo: *const VkImageMemoryRequirementsInfo2KHR,
+    pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+) {
+    gfxGetImageMemoryRequirements2KHR(device, pInfo, pMemoryRequirements)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetImageSparseMemoryRequirements2KHR(
+    device: VkDevice,
+    pInfo: *const VkImageSparseMemoryRequirementsInfo2KHR,
+    pSparseMemoryRequirementCount: *mut u32,
+    pSparseMemoryRequirements: *mut VkSparseImageMemoryRequirements2KHR,
+) {
+    gfxGetImageSparseMemoryRequirements2KHR(
+        device,
+        pInfo,
+        pSparseMemoryRequirementCount,
+        pSparseMemoryRequirements,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkBindBufferMemory2KHR(
+    device: VkDevice,
+    bindInfoCount: u32,
+    pBindInfos: *const VkBindBufferMemoryInfoKHR,
+) -> VkResult {
+    gfxBindBufferMemory2KHR(device, bindInfoCount, pBindInfos)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkBindImageMemory2KHR(
+    device: VkDevice,
+    bindInfoCount: u32,
+    pBindInfos: *const VkBindImageMemoryInfoKHR,
+) -> VkResult {
+    gfxBindImageMemory2KHR(device, bindInfoCount, pBindInfos)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkEnumerateInstanceVersion(pApiVersion: *mut u32) -> VkResult {
+    gfxEnumerateInstanceVersion(pApiVersion)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkEnumeratePhysicalDeviceGroups(
+    instance: VkInstance,
+    pPhysicalDeviceGroupCount: *mut u32,
+    pPhysicalDeviceGroupProperties: *mut VkPhysicalDeviceGroupPropertiesKHX,
+) -> VkResult {
+    gfxEnumeratePhysicalDeviceGroups(
+        instance,
+        pPhysicalDeviceGroupCount,
+        pPhysicalDeviceGroupProperties,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceFeatures2(
+    physicalDevice: VkPhysicalDevice,
+    pFeatures: *mut VkPhysicalDeviceFeatures2KHR,
+) {
+    gfxGetPhysicalDeviceFeatures2KHR(physicalDevice, pFeatures)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceProperties2(
+    physicalDevice: VkPhysicalDevice,
+    pProperties: *mut VkPhysicalDeviceProperties2KHR,
+) {
+    gfxGetPhysicalDeviceProperties2KHR(physicalDevice, pProperties)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceFormatProperties2(
+    physicalDevice: VkPhysicalDevice,
+    format: VkFormat,
+    pFormatProperties: *mut VkFormatProperties2KHR,
+) {
+    gfxGetPhysicalDeviceFormatProperties2KHR(physicalDevice, format, pFormatProperties)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceImageFormatProperties2(
+    physicalDevice: VkPhysicalDevice,
+    pImageFormatInfo: *const VkPhysicalDeviceImageFormatInfo2KHR,
+    pImageFormatProperties: *mut VkImageFormatProperties2KHR,
+) -> VkResult {
+    gfxGetPhysicalDeviceImageFormatProperties2KHR(
+        physicalDevice,
+        pImageFormatInfo,
+        pImageFormatProperties,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceQueueFamilyProperties2(
+    physicalDevice: VkPhysicalDevice,
+    pQueueFamilyPropertyCount: *mut u32,
+    pQueueFamilyProperties: *mut VkQueueFamilyProperties2KHR,
+) {
+    gfxGetPhysicalDeviceQueueFamilyProperties2KHR(
+        physicalDevice,
+        pQueueFamilyPropertyCount,
+        pQueueFamilyProperties,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceMemoryProperties2(
+    physicalDevice: VkPhysicalDevice,
+    pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties2KHR,
+) {
+    gfxGetPhysicalDeviceMemoryProperties2KHR(physicalDevice, pMemoryProperties)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceSparseImageFormatProperties2(
+    physicalDevice: VkPhysicalDevice,
+    pFormatInfo: *const VkPhysicalDeviceSparseImageFormatInfo2KHR,
+    pPropertyCount: *mut u32,
+    pProperties: *mut VkSparseImageFormatProperties2KHR,
+) {
+    gfxGetPhysicalDeviceSparseImageFormatProperties2KHR(
+        physicalDevice,
+        pFormatInfo,
+        pPropertyCount,
+        pProperties,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceExternalBufferProperties(
+    physicalDevice: VkPhysicalDevice,
+    pExternalBufferInfo: *const VkPhysicalDeviceExternalBufferInfoKHX,
+    pExternalBufferProperties: *mut VkExternalBufferPropertiesKHX,
+) {
+    gfxGetPhysicalDeviceExternalBufferProperties(
+        physicalDevice,
+        pExternalBufferInfo,
+        pExternalBufferProperties,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceExternalFenceProperties(
+    physicalDevice: VkPhysicalDevice,
+    pExternalFenceInfo: *const VkPhysicalDeviceExternalFenceInfo,
+    pExternalFenceProperties: *mut VkExternalFenceProperties,
+) {
+    gfxGetPhysicalDeviceExternalFenceProperties(
+        physicalDevice,
+        pExternalFenceInfo,
+        pExternalFenceProperties,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceExternalSemaphoreProperties(
+    physicalDevice: VkPhysicalDevice,
+    pExternalSemaphoreInfo: *const VkPhysicalDeviceExternalSemaphoreInfoKHX,
+    pExternalSemaphoreProperties: *mut VkExternalSemaphorePropertiesKHX,
+) {
+    gfxGetPhysicalDeviceExternalSemaphoreProperties(
+        physicalDevice,
+        pExternalSemaphoreInfo,
+        pExternalSemaphoreProperties,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetDeviceQueue2(
+    device: VkDevice,
+    pQueueInfo: *const VkDeviceQueueInfo2,
+    pQueue: *mut VkQueue,
+) {
+    gfxGetDeviceQueue2(device, pQueueInfo, pQueue)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetDeviceGroupPeerMemoryFeatures(
+    device: VkDevice,
+    heapIndex: u32,
+    localDeviceIndex: u32,
+    remoteDeviceIndex: u32,
+    pPeerMemoryFeatures: *mut VkPeerMemoryFeatureFlagsKHX,
+) {
+    gfxGetDeviceGroupPeerMemoryFeatures(
+        device,
+        heapIndex,
+        localDeviceIndex,
+        remoteDeviceIndex,
+        pPeerMemoryFeatures,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetBufferMemoryRequirements2(
+    device: VkDevice,
+    pInfo: *const VkBufferMemoryRequirementsInfo2KHR,
+    pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+) {
+    gfxGetBufferMemoryRequirements2KHR(device, pInfo, pMemoryRequirements)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetImageMemoryRequirements2(
+    device: VkDevice,
+    pInfo: *const VkImageMemoryRequirementsInfo2KHR,
+    pMemoryRequirements: *mut VkMemoryRequirements2KHR,
+) {
+    gfxGetImageMemoryRequirements2KHR(device, pInfo, pMemoryRequirements)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetImageSparseMemoryRequirements2(
+    device: VkDevice,
+    pInfo: *const VkImageSparseMemoryRequirementsInfo2KHR,
+    pSparseMemoryRequirementCount: *mut u32,
+    pSparseMemoryRequirements: *mut VkSparseImageMemoryRequirements2KHR,
+) {
+    gfxGetImageSparseMemoryRequirements2KHR(
+        device,
+        pInfo,
+        pSparseMemoryRequirementCount,
+        pSparseMemoryRequirements,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkBindBufferMemory2(
+    device: VkDevice,
+    bindInfoCount: u32,
+    pBindInfos: *const VkBindBufferMemoryInfoKHR,
+) -> VkResult {
+    gfxBindBufferMemory2KHR(device, bindInfoCount, pBindInfos)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkBindImageMemory2(
+    device: VkDevice,
+    bindInfoCount: u32,
+    pBindInfos: *const VkBindImageMemoryInfoKHR,
+) -> VkResult {
+    gfxBindImageMemory2KHR(device, bindInfoCount, pBindInfos)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkTrimCommandPool(
+    device: VkDevice,
+    commandPool: VkCommandPool,
+    flags: VkCommandPoolTrimFlagsKHR,
+) {
+    gfxTrimCommandPoolKHR(device, commandPool, flags)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkGetDescriptorSetLayoutSupport(
+    device: VkDevice,
+    pCreateInfo: *const VkDescriptorSetLayoutCreateInfo,
+    pSupport: *mut VkDescriptorSetLayoutSupport,
+) {
+    gfxGetDescriptorSetLayoutSupport(device, pCreateInfo, pSupport)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkCreateDescriptorUpdateTemplate(
+    device: VkDevice,
+    pCreateInfo: *const VkDescriptorUpdateTemplateCreateInfoKHR,
+    pAllocator: *const VkAllocationCallbacks,
+    pDescriptorUpdateTemplate: *mut VkDescriptorUpdateTemplateKHR,
+) -> VkResult {
+    gfxCreateDescriptorUpdateTemplate(device, pCreateInfo, pAllocator, pDescriptorUpdateTemplate)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkDestroyDescriptorUpdateTemplate(
+    device: VkDevice,
+    descriptorUpdateTemplate: VkDescriptorUpdateTemplateKHR,
+    pAllocator: *const VkAllocationCallbacks,
+) {
+    gfxDestroyDescriptorUpdateTemplate(device, descriptorUpdateTemplate, pAllocator)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkUpdateDescriptorSetWithTemplate(
+    device: VkDevice,
+    descriptorSet: VkDescriptorSet,
+    descriptorUpdateTemplate: VkDescriptorUpdateTemplateKHR,
+    pData: *const ::std::os::raw::c_void,
+) {
+    gfxUpdateDescriptorSetWithTemplate(device, descriptorSet, descriptorUpdateTemplate, pData)
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkCmdDispatchBase(
+    commandBuffer: VkCommandBuffer,
+    baseGroupX: u32,
+    baseGroupY: u32,
+    baseGroupZ: u32,
+    groupCountX: u32,
+    groupCountY: u32,
+    groupCountZ: u32,
+) {
+    gfxCmdDispatchBase(
+        commandBuffer,
+        baseGroupX,
+        baseGroupY,
+        baseGroupZ,
+        groupCountX,
+        groupCountY,
+        groupCountZ,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn vkCmdSetDeviceMask(commandBuffer: VkCommandBuffer, deviceMask: u32) {
+    gfxCmdSetDeviceMask(commandBuffer, deviceMask)
+}
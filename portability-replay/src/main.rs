@@ -0,0 +1,70 @@
+//! Replays a trace captured with the `capture_file` setting,
+//! on the backend given by `--backend` or the configuration.
+
+use portability_gfx::replay::Player;
+
+use std::{env, process};
+
+const USAGE: &str = "Usage: portability-replay [--backend NAME] [--verbose] TRACE";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2)
+}
+
+fn main() {
+    let mut verbose = false;
+    let mut path = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--backend" => match args.next() {
+                // Read by the configuration when the instance is created.
+                Some(backend) => env::set_var("GFX_BACKEND", backend),
+                None => usage(),
+            },
+            "--verbose" => verbose = true,
+            "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg),
+            _ => usage(),
+        }
+    }
+    let path = path.unwrap_or_else(|| usage());
+
+    let player = match Player::open(&path) {
+        Ok(player) => player,
+        Err(e) => {
+            eprintln!("Unable to open the trace {}: {}", path, e);
+            process::exit(1)
+        }
+    };
+    let summary = player.run(|index, name| {
+        if verbose {
+            println!("{:6} {}", index, name);
+        }
+    });
+    let summary = match summary {
+        Ok(summary) => summary,
+        Err(e) => {
+            eprintln!("Unable to read the trace {}: {}", path, e);
+            process::exit(1)
+        }
+    };
+
+    println!("Replayed {} calls", summary.calls);
+    for (name, count) in &summary.skipped {
+        println!("Skipped {} x{}", name, count);
+    }
+    for mismatch in &summary.mismatches {
+        println!("Mismatch: {}", mismatch);
+    }
+    if summary.unknown_handles != 0 {
+        println!(
+            "{} captured handles were not created by the replay",
+            summary.unknown_handles
+        );
+    }
+}